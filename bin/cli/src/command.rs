@@ -76,9 +76,23 @@ pub enum OrgSubCommand {
     UnreserveShares(shares::SharesUnReserveCommand),
     LockShares(shares::SharesLockCommand),
     UnlockShares(shares::SharesUnLockCommand),
+    TransferShares(shares::SharesTransferCommand),
+    IssueVestingShares(shares::SharesIssueVestingCommand),
+    ReleaseVestedShares(shares::SharesReleaseVestedCommand),
     // full org stuff
     RegisterFlatOrg(org::OrgRegisterFlatCommand),
     RegisterWeightedOrg(org::OrgRegisterWeightedCommand),
+    ProposeSupervisor(org::OrgProposeSupervisorCommand),
+    PollSupervisorProposal(org::OrgPollSupervisorProposalCommand),
+    RequestMembership(org::OrgRequestMembershipCommand),
+    ApproveMembershipRequest(org::OrgApproveMembershipRequestCommand),
+    TriggerMembershipVote(org::OrgTriggerMembershipVoteCommand),
+    PollMembershipRequest(org::OrgPollMembershipRequestCommand),
+    InviteMember(org::OrgInviteMemberCommand),
+    AcceptInvitation(org::OrgAcceptInvitationCommand),
+    ProposeConstitutionAmendment(org::OrgProposeConstitutionAmendmentCommand),
+    PollConstitutionAmendment(org::OrgPollConstitutionAmendmentCommand),
+    ConstitutionHistory(org::OrgConstitutionHistoryCommand),
 }
 
 #[derive(Clone, Debug, Clap)]
@@ -130,6 +144,9 @@ pub enum BountySubCommand {
     ContributeToBounty(bounty::BountyContributeCommand),
     SubmitForBounty(bounty::BountySubmitCommand),
     ApproveApplication(bounty::BountyApproveCommand),
+    TriggerApplicationReview(bounty::BountyTriggerReviewCommand),
+    RejectApplication(bounty::BountyRejectCommand),
+    WithdrawApplication(bounty::BountyWithdrawSubmissionCommand),
     // storage helpers
     GetBounty(bounty::GetBountyCommand),
     GetSubmission(bounty::GetSubmissionCommand),
@@ -53,12 +53,50 @@ async fn main() -> Result<()> {
                 }
                 OrgSubCommand::LockShares(cmd) => cmd.exec(&client).await?,
                 OrgSubCommand::UnlockShares(cmd) => cmd.exec(&client).await?,
+                OrgSubCommand::TransferShares(cmd) => cmd.exec(&client).await?,
+                OrgSubCommand::IssueVestingShares(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::ReleaseVestedShares(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 OrgSubCommand::RegisterFlatOrg(cmd) => {
                     cmd.exec(&client).await?
                 }
                 OrgSubCommand::RegisterWeightedOrg(cmd) => {
                     cmd.exec(&client).await?
                 }
+                OrgSubCommand::ProposeSupervisor(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::PollSupervisorProposal(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::RequestMembership(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::ApproveMembershipRequest(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::TriggerMembershipVote(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::PollMembershipRequest(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::InviteMember(cmd) => cmd.exec(&client).await?,
+                OrgSubCommand::AcceptInvitation(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::ProposeConstitutionAmendment(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::PollConstitutionAmendment(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                OrgSubCommand::ConstitutionHistory(cmd) => {
+                    cmd.exec(&client).await?
+                }
             }
         }
         SubCommand::Vote(VoteCommand { cmd }) => {
@@ -96,6 +134,15 @@ async fn main() -> Result<()> {
                 BountySubCommand::ApproveApplication(cmd) => {
                     cmd.exec(&client).await?
                 }
+                BountySubCommand::TriggerApplicationReview(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::RejectApplication(cmd) => {
+                    cmd.exec(&client).await?
+                }
+                BountySubCommand::WithdrawApplication(cmd) => {
+                    cmd.exec(&client).await?
+                }
                 BountySubCommand::GetBounty(cmd) => cmd.exec(&client).await?,
                 BountySubCommand::GetSubmission(cmd) => {
                     cmd.exec(&client).await?
@@ -22,8 +22,10 @@ use substrate_subxt::{
 use sunshine_bounty_client::{
     bank::Bank,
     bounty::Bounty,
+    bounty2::Bounty2,
     donate::Donate,
     org::Org,
+    reputation::Reputation,
     vote::Vote,
 };
 use sunshine_client_utils::{
@@ -77,6 +79,7 @@ impl Org for Runtime {
     type IpfsReference = CidBytes;
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
     type Constitution = TextBlock;
 }
 
@@ -91,13 +94,26 @@ impl Vote for Runtime {
 
 impl Donate for Runtime {}
 
+impl Reputation for Runtime {}
+
 impl Bank for Runtime {
     type BankId = u64;
     type SpendId = u64;
 }
 
 impl Bounty for Runtime {
-    type IpfsReference = CidBytes;
+    type BountyId = u64;
+    type AssetId = u32;
+    type DisputeId = u64;
+    type BountyPost = BountyBody;
+    type SubmissionId = u64;
+    type BountySubmission = BountyBody;
+    type RejectionReason = TextBlock;
+    type WorkPlan = TextBlock;
+}
+
+impl Bounty2 for Runtime {
+    type VoteId = u64;
     type BountyId = u64;
     type BountyPost = BountyBody;
     type SubmissionId = u64;
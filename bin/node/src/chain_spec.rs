@@ -17,12 +17,15 @@ use test_runtime::{
     Balance,
     BalancesConfig,
     BlockNumber,
+    Bounty2Config,
+    BountyConfig,
     GenesisConfig,
     GrandpaConfig,
     GrantConfig,
     OrgConfig,
     Signature,
     SystemConfig,
+    TokensConfig,
     TreasuryConfig,
     WASM_BINARY,
 };
@@ -161,6 +164,13 @@ pub fn testnet_genesis(
             code: WASM_BINARY.to_vec(),
             changes_trie_config: Default::default(),
         }),
+        bounty: Some(BountyConfig {
+            submission_poll_frequency: 10,
+        }),
+        bounty2: Some(Bounty2Config {
+            bounty_poll_frequency: 10,
+            submission_poll_frequency: 10,
+        }),
         grant: Some(GrantConfig {
             application_poll_frequency: 10,
             milestone_poll_frequency: 10,
@@ -189,6 +199,9 @@ pub fn testnet_genesis(
                 .map(|x| (x.1.clone(), 1))
                 .collect(),
         }),
+        tokens: Some(TokensConfig {
+            endowed_accounts: vec![],
+        }),
         treasury: Some(TreasuryConfig {
             minting_interval: treasury_mint_rate.0,
             mint_amount: treasury_mint_rate.1,
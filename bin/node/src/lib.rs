@@ -1,4 +1,5 @@
 pub mod chain_spec;
+pub mod rpc;
 pub mod service;
 
 pub const IMPL_NAME: &str = "Sunshine Node";
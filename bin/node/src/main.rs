@@ -3,7 +3,6 @@
 use sc_cli::{
     RunCmd,
     RuntimeVersion,
-    Subcommand,
     SubstrateCli,
 };
 use sc_service::{
@@ -16,6 +15,17 @@ use test_node::{
     chain_spec,
     service,
 };
+use test_runtime::opaque::Block;
+
+#[derive(Debug, StructOpt)]
+pub enum Subcommand {
+    #[structopt(flatten)]
+    Base(sc_cli::Subcommand),
+
+    /// The custom benchmark subcommand benchmarking runtime pallets.
+    #[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
+    Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+}
 
 #[derive(Debug, StructOpt)]
 pub struct Cli {
@@ -81,7 +91,19 @@ fn main() -> sc_cli::Result<()> {
     let cli = <Cli as SubstrateCli>::from_args();
 
     match &cli.subcommand {
-        Some(subcommand) => {
+        Some(Subcommand::Benchmark(cmd)) => {
+            if cfg!(feature = "runtime-benchmarks") {
+                let runner = cli.create_runner(cmd)?;
+                runner.sync_run(|config| {
+                    cmd.run::<Block, service::Executor>(config)
+                })
+            } else {
+                Err("Benchmarking wasn't enabled when building the node. \
+                You can enable it with `--features runtime-benchmarks`."
+                    .into())
+            }
+        }
+        Some(Subcommand::Base(subcommand)) => {
             let runner = cli.create_runner(subcommand)?;
             runner.run_subcommand(subcommand, |config| {
                 let ServiceParams {
@@ -0,0 +1,220 @@
+//! Custom RPC methods served by the node
+
+use codec::Encode;
+use jsonrpc_core::{
+    Error as RpcError,
+    ErrorCode,
+    IoHandler,
+    Result,
+};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+    generic::BlockId,
+    traits::Block as BlockT,
+};
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    sync::Arc,
+};
+use test_runtime::{
+    bounty_api::{
+        BountyApi as BountyRuntimeApi,
+        BountyId,
+    },
+    reputation_api::ReputationApi as ReputationRuntimeApi,
+    AccountId,
+    Balance,
+};
+
+/// Bounty queries, every result is returned SCALE encoded
+#[rpc]
+pub trait BountyApi<BlockHash> {
+    /// Encoded `Vec<(BountyId, Bounty)>` with at least `min` remaining which carry
+    /// every tag and which `who` is eligible to work on, `limit` per `page`
+    #[rpc(name = "bounty_openBounties")]
+    fn open_bounties(
+        &self,
+        min: Balance,
+        who: Option<AccountId>,
+        tags: Vec<Bytes>,
+        page: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+
+    /// Encoded `Vec<(SubmissionId, Submission)>` posted for the bounty
+    #[rpc(name = "bounty_submissionsFor")]
+    fn submissions_for(
+        &self,
+        bounty_id: BountyId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+
+    /// Encoded `Vec<(AccountId, Balance)>` contributed to the bounty
+    #[rpc(name = "bounty_contributions")]
+    fn contributions(
+        &self,
+        bounty_id: BountyId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+}
+
+/// Serves `BountyApi` from the runtime api of the client
+pub struct Bounty<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Bounty<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn runtime_error(e: impl Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(1),
+        message: "Runtime query failed.".into(),
+        data: Some(format!("{:?}", e).into()),
+    }
+}
+
+impl<C, Block> BountyApi<<Block as BlockT>::Hash> for Bounty<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: BountyRuntimeApi<Block>,
+{
+    fn open_bounties(
+        &self,
+        min: Balance,
+        who: Option<AccountId>,
+        tags: Vec<Bytes>,
+        page: u32,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let tags = tags.into_iter().map(|tag| tag.to_vec()).collect();
+        self.client
+            .runtime_api()
+            .open_bounties(&at, min, who, tags, page, limit)
+            .map(|bounties| bounties.encode().into())
+            .map_err(runtime_error)
+    }
+
+    fn submissions_for(
+        &self,
+        bounty_id: BountyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client
+            .runtime_api()
+            .submissions_for(&at, bounty_id)
+            .map(|submissions| submissions.encode().into())
+            .map_err(runtime_error)
+    }
+
+    fn contributions(
+        &self,
+        bounty_id: BountyId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client
+            .runtime_api()
+            .contributions(&at, bounty_id)
+            .map(|contributions| contributions.encode().into())
+            .map_err(runtime_error)
+    }
+}
+
+/// Reputation queries, every result is returned SCALE encoded
+#[rpc]
+pub trait ReputationApi<BlockHash> {
+    /// Encoded `ReputationRecord` of the account
+    #[rpc(name = "reputation_reputationOf")]
+    fn reputation(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Bytes>;
+
+    /// Encoded `Vec<(AccountId, ReputationRecord)>` on the leaderboard, at most `limit`
+    #[rpc(name = "reputation_topAccounts")]
+    fn top_accounts(&self, limit: u32, at: Option<BlockHash>) -> Result<Bytes>;
+}
+
+/// Serves `ReputationApi` from the runtime api of the client
+pub struct Reputation<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Reputation<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block> ReputationApi<<Block as BlockT>::Hash> for Reputation<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ReputationRuntimeApi<Block>,
+{
+    fn reputation(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client
+            .runtime_api()
+            .reputation(&at, who)
+            .map(|record| record.encode().into())
+            .map_err(runtime_error)
+    }
+
+    fn top_accounts(
+        &self,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Bytes> {
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        self.client
+            .runtime_api()
+            .top_accounts(&at, limit)
+            .map(|accounts| accounts.encode().into())
+            .map_err(runtime_error)
+    }
+}
+
+/// The custom RPC extensions of a full node
+pub fn create_full<C, Block>(client: Arc<C>) -> IoHandler<sc_rpc::Metadata>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: BountyRuntimeApi<Block> + ReputationRuntimeApi<Block>,
+{
+    let mut io = IoHandler::default();
+    io.extend_with(BountyApi::to_delegate(Bounty::new(client.clone())));
+    io.extend_with(ReputationApi::to_delegate(Reputation::new(client)));
+    io
+}
@@ -35,6 +35,7 @@ native_executor_instance!(
     pub Executor,
     test_runtime::api::dispatch,
     test_runtime::native_version,
+    frame_benchmarking::benchmarking::HostFunctions,
 );
 
 type FullClient = sc_service::TFullClient<Block, RuntimeApi, Executor>;
@@ -50,7 +51,7 @@ pub fn new_full_params(
             FullClient,
             sc_consensus_aura::AuraImportQueue<Block, FullClient>,
             sc_transaction_pool::FullPool<Block, FullClient>,
-            (),
+            jsonrpc_core::IoHandler<sc_rpc::Metadata>,
             FullBackend,
         >,
         FullSelectChain,
@@ -113,6 +114,11 @@ pub fn new_full_params(
     let finality_proof_provider =
         Arc::new(GrandpaFinalityProofProvider::new(backend.clone(), provider));
 
+    let rpc_extensions_builder = {
+        let client = client.clone();
+        Box::new(move |_| crate::rpc::create_full(client.clone()))
+    };
+
     let params = sc_service::ServiceParams {
         backend,
         client,
@@ -126,7 +132,7 @@ pub fn new_full_params(
         finality_proof_provider: Some(finality_proof_provider),
         on_demand: None,
         remote_blockchain: None,
-        rpc_extensions_builder: Box::new(|_| ()),
+        rpc_extensions_builder,
     };
 
     Ok((
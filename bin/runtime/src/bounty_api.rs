@@ -0,0 +1,35 @@
+//! Runtime api for querying the bounty module without decoding raw storage
+
+use crate::{
+    AccountId,
+    Balance,
+    Runtime,
+};
+use sp_std::prelude::*;
+
+/// Bounty identifier in this runtime
+pub type BountyId = u64;
+/// Submission identifier in this runtime
+pub type SubmissionId = u64;
+/// Posted bounty in this runtime
+pub type BountyOf = bounty::Bounty<Runtime>;
+/// Posted submission in this runtime
+pub type SubmissionOf = bounty::BountySub<Runtime>;
+
+sp_api::decl_runtime_apis! {
+    pub trait BountyApi {
+        /// Bounties with at least `min` remaining which carry every tag and which
+        /// `who` is eligible to work on, `limit` per `page`
+        fn open_bounties(
+            min: Balance,
+            who: Option<AccountId>,
+            tags: Vec<Vec<u8>>,
+            page: u32,
+            limit: u32,
+        ) -> Vec<(BountyId, BountyOf)>;
+        /// Submissions posted for the bounty
+        fn submissions_for(bounty_id: BountyId) -> Vec<(SubmissionId, SubmissionOf)>;
+        /// Total contribution of every contributor to the bounty
+        fn contributions(bounty_id: BountyId) -> Vec<(AccountId, Balance)>;
+    }
+}
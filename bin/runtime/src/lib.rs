@@ -25,6 +25,7 @@ use sp_runtime::{
     traits::{
         BlakeTwo256,
         Block as BlockT,
+        ConvertInto,
         IdentifyAccount,
         NumberFor,
         Saturating,
@@ -41,6 +42,10 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use sunshine_pallet_utils::cid::CidBytes;
 
+pub mod bounty_api;
+pub mod reputation_api;
+mod weights;
+
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
     construct_runtime,
@@ -90,6 +95,9 @@ pub type AccountIndex = u32;
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Identifier of an asset, the native currency is `NativeAssetId`.
+pub type AssetId = u32;
+
 /// Index of a transaction in the chain.
 pub type Index = u32;
 
@@ -301,16 +309,41 @@ impl pallet_transaction_payment::Trait for Runtime {
     type WeightToFee = IdentityFee<Balance>;
     type FeeMultiplierUpdate = ();
 }
+parameter_types! {
+    pub const NativeAssetId: AssetId = 0;
+}
+impl tokens::Trait for Runtime {
+    type Event = Event;
+    type CurrencyId = AssetId;
+    type NativeCurrency = Balances;
+    type NativeCurrencyId = NativeAssetId;
+    type WeightInfo = weights::tokens::WeightInfo;
+}
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: BlockNumber = 7 * DAYS;
+}
 impl org::Trait for Runtime {
     type Event = Event;
     type IpfsReference = CidBytes;
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = Vote;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = weights::org::WeightInfo;
 }
 impl vote::Trait for Runtime {
     type Event = Event;
     type VoteId = u64;
     type Signal = u64;
+    type WeightInfo = weights::vote::WeightInfo;
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u128 = 10;
@@ -320,11 +353,13 @@ impl court::Trait for Runtime {
     type Currency = Balances;
     type DisputeId = u64;
     type MinimumDisputeAmount = MinimumDisputeAmount;
+    type WeightInfo = weights::court::WeightInfo;
 }
 impl drip::Trait for Runtime {
     type Event = Event;
     type DripId = u64;
     type Currency = Balances;
+    type WeightInfo = weights::drip::WeightInfo;
 }
 parameter_types! {
     pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
@@ -337,6 +372,7 @@ impl treasury::Trait for Runtime {
 impl donate::Trait for Runtime {
     type Event = Event;
     type Currency = Balances;
+    type WeightInfo = weights::donate::WeightInfo;
 }
 parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
@@ -351,21 +387,87 @@ impl bank::Trait for Runtime {
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinimumDeposit;
+    type WeightInfo = weights::bank::WeightInfo;
+}
+parameter_types! {
+    pub const ApprovalScore: u32 = 10;
+    pub const RejectionPenalty: u32 = 5;
+    pub const PayoutScore: u32 = 5;
+    pub const LeaderboardSize: u32 = 100;
+}
+impl reputation::Trait for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type ApprovalScore = ApprovalScore;
+    type RejectionPenalty = RejectionPenalty;
+    type PayoutScore = PayoutScore;
+    type LeaderboardSize = LeaderboardSize;
 }
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
     pub const MinDeposit: u128 = 10;
     pub const MinContribution: u128 = 5;
+    pub const SubmissionDeposit: u128 = 5;
+    pub const InactivityWindow: BlockNumber = 14 * DAYS;
+    pub const MaxRefundsPerBlock: u32 = 50;
+    pub const MaxMilestones: u32 = 16;
+    pub const DisputeWindow: BlockNumber = 7 * DAYS;
+    pub const MaxTags: u32 = 8;
+    pub const MaxTagLength: u32 = 32;
 }
 impl bounty::Trait for Runtime {
+    type Event = Event;
+    type AssetId = AssetId;
+    type Currency = Tokens;
+    type NativeAsset = NativeAssetId;
+    type DepositCurrency = Balances;
+    type BountyId = bounty_api::BountyId;
+    type SubmissionId = bounty_api::SubmissionId;
+    type Foundation = Foundation;
+    type MinDeposit = MinDeposit;
+    type MinContribution = MinContribution;
+    type SubmissionDeposit = SubmissionDeposit;
+    type InactivityWindow = InactivityWindow;
+    type MaxPaymentRetries = MaxPaymentRetries;
+    type MaxRefundsPerBlock = MaxRefundsPerBlock;
+    type MaxMilestones = MaxMilestones;
+    type MaxTags = MaxTags;
+    type MaxTagLength = MaxTagLength;
+    type DisputeId = u64;
+    type Court = Court;
+    type DisputeWindow = DisputeWindow;
+    type Reputation = Reputation;
+    type WeightInfo = weights::bounty::WeightInfo;
+}
+impl vote_direct::Trait for Runtime {
     type Event = Event;
     type IpfsReference = CidBytes;
+    type VoteId = u64;
+    type Signal = Balance;
+    type WeightInfo = weights::vote_direct::WeightInfo;
+}
+parameter_types! {
+    pub const ChallengePeriod: BlockNumber = 100;
+    pub const Foundation2: ModuleId = ModuleId(*b"fundaco2");
+    pub const MinVetoThreshold: Permill = Permill::from_percent(10);
+    pub const MinRefundThreshold: Permill = Permill::from_percent(10);
+    pub const MaxPollsPerBlock: u32 = 50;
+    pub const MaxPaymentRetries: u32 = 3;
+}
+impl bounty2::Trait for Runtime {
+    type Event = Event;
     type Currency = Balances;
     type BountyId = u64;
     type SubmissionId = u64;
-    type Foundation = Foundation;
+    type ChallengePeriod = ChallengePeriod;
+    type Foundation = Foundation2;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type MinVetoThreshold = MinVetoThreshold;
+    type MinRefundThreshold = MinRefundThreshold;
+    type MaxPollsPerBlock = MaxPollsPerBlock;
+    type MaxPaymentRetries = MaxPaymentRetries;
+    type WeightInfo = weights::bounty2::WeightInfo;
 }
 parameter_types! {
     pub const BigFoundation: ModuleId = ModuleId(*b"big/fund");
@@ -380,6 +482,7 @@ impl grant::Trait for Runtime {
     type BigFoundation = BigFoundation;
     type MinDeposit = MinGrantDeposit;
     type MinContribution = MinGrantContribution;
+    type WeightInfo = weights::grant::WeightInfo;
 }
 
 construct_runtime!(
@@ -396,6 +499,7 @@ construct_runtime!(
         Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Module, Storage},
         // sunshine-bounty modules
+        Tokens: tokens::{Module, Call, Config<T>, Storage, Event<T>},
         Org: org::{Module, Call, Config<T>, Storage, Event<T>},
         Vote: vote::{Module, Call, Storage, Event<T>},
         Court: court::{Module, Call, Storage, Event<T>},
@@ -403,7 +507,10 @@ construct_runtime!(
         Treasury: treasury::{Module, Call, Config<T>, Storage, Event<T>},
         Donate: donate::{Module, Call, Event<T>},
         Bank: bank::{Module, Call, Storage, Event<T>},
-        Bounty: bounty::{Module, Call, Storage, Event<T>},
+        Reputation: reputation::{Module, Storage, Event<T>},
+        Bounty: bounty::{Module, Call, Config<T>, Storage, Event<T>},
+        VoteDirect: vote_direct::{Module, Call, Storage, Event<T>},
+        Bounty2: bounty2::{Module, Call, Config<T>, Storage, Event<T>},
         Grant: grant::{Module, Call, Config<T>, Storage, Event<T>},
     }
 );
@@ -443,6 +550,10 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllModules,
+    (
+        bounty::migration::MigrateToBounty2<Runtime>,
+        org::migration::CountMembers<Runtime>,
+    ),
 >;
 
 impl_runtime_apis! {
@@ -549,4 +660,68 @@ impl_runtime_apis! {
             None
         }
     }
+
+    impl bounty_api::BountyApi<Block> for Runtime {
+        fn open_bounties(
+            min: Balance,
+            who: Option<AccountId>,
+            tags: Vec<Vec<u8>>,
+            page: u32,
+            limit: u32,
+        ) -> Vec<(bounty_api::BountyId, bounty_api::BountyOf)> {
+            Bounty::open_bounties(min, who, tags, page, limit)
+        }
+
+        fn submissions_for(
+            bounty_id: bounty_api::BountyId,
+        ) -> Vec<(bounty_api::SubmissionId, bounty_api::SubmissionOf)> {
+            Bounty::submissions_for(bounty_id)
+        }
+
+        fn contributions(bounty_id: bounty_api::BountyId) -> Vec<(AccountId, Balance)> {
+            Bounty::contributions(bounty_id)
+        }
+    }
+
+    impl reputation_api::ReputationApi<Block> for Runtime {
+        fn reputation(who: AccountId) -> reputation_api::ReputationOf {
+            Reputation::reputations(who)
+        }
+
+        fn top_accounts(limit: u32) -> Vec<(AccountId, reputation_api::ReputationOf)> {
+            Reputation::top_accounts(limit)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{Benchmarking, BenchmarkBatch, add_benchmark};
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&pallet, &benchmark, &lowest_range_values, &highest_range_values, &steps, repeat);
+
+            add_benchmark!(params, batches, b"tokens", Tokens);
+            add_benchmark!(params, batches, b"org", Org);
+            add_benchmark!(params, batches, b"vote", Vote);
+            add_benchmark!(params, batches, b"court", Court);
+            add_benchmark!(params, batches, b"drip", Drip);
+            add_benchmark!(params, batches, b"donate", Donate);
+            add_benchmark!(params, batches, b"bank", Bank);
+            add_benchmark!(params, batches, b"bounty", Bounty);
+            add_benchmark!(params, batches, b"vote_direct", VoteDirect);
+            add_benchmark!(params, batches, b"bounty2", Bounty2);
+            add_benchmark!(params, batches, b"grant", Grant);
+
+            if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
+            Ok(batches)
+        }
+    }
 }
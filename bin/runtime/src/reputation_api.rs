@@ -0,0 +1,19 @@
+//! Runtime api for querying the reputation module without decoding raw storage
+
+use crate::{
+    AccountId,
+    Balance,
+};
+use sp_std::prelude::*;
+
+/// Reputation record of an account in this runtime
+pub type ReputationOf = reputation::ReputationRecord<Balance>;
+
+sp_api::decl_runtime_apis! {
+    pub trait ReputationApi {
+        /// Outcome counters and score of the account
+        fn reputation(who: AccountId) -> ReputationOf;
+        /// Accounts on the leaderboard with the highest scores, at most `limit`
+        fn top_accounts(limit: u32) -> Vec<(AccountId, ReputationOf)>;
+    }
+}
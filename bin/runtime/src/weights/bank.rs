@@ -0,0 +1,63 @@
+//! Weights for the bank pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl bank::WeightInfo for WeightInfo {
+    fn open_org_bank_account() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn member_proposes_spend() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn member_triggers_vote_on_spend_proposal(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn member_sudo_approves_spend_proposal() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn member_polls_spend_proposal() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn close_org_bank_account(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((15_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+}
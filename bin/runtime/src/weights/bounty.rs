@@ -0,0 +1,118 @@
+//! Weights for the bounty pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl bounty::WeightInfo for WeightInfo {
+    fn post_bounty(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn contribute_to_bounty() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn submit_for_bounty(m: u32) -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add((300_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn approve_bounty_submission() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn trigger_submission_review(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn reject_bounty_submission() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn withdraw_submission() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn dispute_submission() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn apply_to_work() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(1 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn assign(m: u32) -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add((8_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((2 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn withdraw_contribution(s: u32) -> Weight {
+        (55_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(s as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((2 as Weight).saturating_mul(s as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn on_initialize(e: u32, r: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((4_000_000 as Weight).saturating_mul(e as Weight))
+            .saturating_add((45_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((3 as Weight).saturating_mul(e as Weight)),
+            )
+            .saturating_add(
+                DbWeight::get()
+                    .reads((4 as Weight).saturating_mul(r as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((2 as Weight).saturating_mul(e as Weight)),
+            )
+            .saturating_add(
+                DbWeight::get()
+                    .writes((4 as Weight).saturating_mul(r as Weight)),
+            )
+    }
+}
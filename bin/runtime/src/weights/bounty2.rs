@@ -0,0 +1,63 @@
+//! Weights for the bounty2 pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl bounty2::WeightInfo for WeightInfo {
+    fn post_bounty() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+    }
+    fn contribute_to_bounty() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn submit_for_bounty() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn approve_bounty_submission() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn reject_bounty_submission(c: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(8 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(c as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(c as Weight)),
+            )
+    }
+    fn trigger_refund_vote(c: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(c as Weight))
+            .saturating_add(DbWeight::get().reads(7 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(c as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(c as Weight)),
+            )
+    }
+}
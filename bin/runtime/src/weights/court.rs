@@ -0,0 +1,39 @@
+//! Weights for the court pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl court::WeightInfo for WeightInfo {
+    fn register_dispute_type_with_resolution_path() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn raise_dispute_to_trigger_vote(m: u32) -> Weight {
+        (100_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn poll_dispute_to_execute_outcome() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+}
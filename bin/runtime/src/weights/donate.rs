@@ -0,0 +1,43 @@
+//! Weights for the donate pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl donate::WeightInfo for WeightInfo {
+    fn make_prop_donation(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((15_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn make_equal_donation(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((15_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+}
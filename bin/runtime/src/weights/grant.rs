@@ -0,0 +1,92 @@
+//! Weights for the grant pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl grant::WeightInfo for WeightInfo {
+    fn create_foundation() -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(5 as Weight))
+    }
+    fn donate_to_foundation() -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn submit_application() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn trigger_application_review(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn approve_application() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn reject_application() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn submit_milestone() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn trigger_milestone_review(m: u32) -> Weight {
+        (70_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn approve_milestone(m: u32) -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add((15_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn reject_milestone() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+}
@@ -0,0 +1,13 @@
+//! Weights for the sunshine pallets configured in this runtime
+
+pub mod bank;
+pub mod bounty;
+pub mod bounty2;
+pub mod court;
+pub mod donate;
+pub mod drip;
+pub mod grant;
+pub mod org;
+pub mod tokens;
+pub mod vote;
+pub mod vote_direct;
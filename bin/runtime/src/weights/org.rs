@@ -0,0 +1,181 @@
+//! Weights for the org pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl org::WeightInfo for WeightInfo {
+    fn register_flat_org(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((10_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((2 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn register_weighted_org(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((10_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((2 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn issue_shares() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn burn_shares() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn batch_issue_shares(m: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((12_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((2 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn batch_burn_shares(m: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((12_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((2 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn lock_shares() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn unlock_shares() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn reserve_shares() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn unreserve_shares() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn transfer_shares() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn propose_supervisor(m: u32) -> Weight {
+        (60_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn poll_supervisor_proposal() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn request_membership() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn approve_membership_request() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn trigger_membership_vote(m: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn poll_membership_request() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(6 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn invite_member() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_invitation() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+    fn issue_vesting_shares() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn release_vested_shares() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(2 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+    fn propose_constitution_amendment(m: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((2_000_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(4 as Weight))
+    }
+    fn poll_constitution_amendment() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(5 as Weight))
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+    }
+}
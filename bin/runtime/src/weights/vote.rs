@@ -0,0 +1,48 @@
+//! Weights for the vote pallet
+//!
+//! Estimated from the storage reads and writes of each extrinsic, not yet measured:
+//! `scripts/benchmark.sh` regenerates them from the pallet benchmarks
+
+#![allow(unused_parens)]
+
+use frame_support::weights::{
+    constants::RocksDbWeight as DbWeight,
+    Weight,
+};
+
+pub struct WeightInfo;
+impl vote::WeightInfo for WeightInfo {
+    fn create_signal_vote(m: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn create_percent_vote(m: u32) -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(m as Weight))
+            .saturating_add(DbWeight::get().reads(4 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .reads((1 as Weight).saturating_mul(m as Weight)),
+            )
+            .saturating_add(DbWeight::get().writes(3 as Weight))
+            .saturating_add(
+                DbWeight::get()
+                    .writes((1 as Weight).saturating_mul(m as Weight)),
+            )
+    }
+    fn submit_vote() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(DbWeight::get().reads(3 as Weight))
+            .saturating_add(DbWeight::get().writes(2 as Weight))
+    }
+}
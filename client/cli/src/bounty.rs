@@ -13,8 +13,12 @@ use sunshine_bounty_client::{
     bounty::{
         Bounty,
         BountyClient,
+        BountyOptionsOf,
     },
+    org::Org,
+    vote::Vote,
     BountyBody,
+    TextBlock,
 };
 use sunshine_client_utils::Result;
 
@@ -24,6 +28,15 @@ pub struct BountyPostCommand {
     pub repo_name: String,
     pub issue_number: u64,
     pub amount: u128,
+    pub expiry: Option<u32>,
+    #[clap(short = "a", long = "asset")]
+    pub asset: Option<u32>,
+    #[clap(short = "r", long = "min-reputation")]
+    pub min_reputation: Option<u32>,
+    #[clap(short = "w", long = "review-window")]
+    pub review_window: Option<u32>,
+    #[clap(short = "t", long = "tag")]
+    pub tags: Vec<String>,
 }
 
 impl BountyPostCommand {
@@ -33,9 +46,11 @@ impl BountyPostCommand {
     ) -> Result<()>
     where
         <R as System>::AccountId: Ss58Codec,
+        <R as System>::BlockNumber: From<u32>,
         <R as Balances>::Balance: From<u128> + Display,
         <R as Bounty>::BountyId: Display,
         <R as Bounty>::BountyPost: From<BountyBody>,
+        <R as Bounty>::AssetId: From<u32>,
     {
         let bounty: <R as Bounty>::BountyPost = BountyBody {
             repo_owner: (*self.repo_owner).to_string(),
@@ -43,7 +58,30 @@ impl BountyPostCommand {
             issue_number: self.issue_number,
         }
         .into();
-        let event = client.post_bounty(bounty, self.amount.into()).await?;
+        let expiry: Option<<R as System>::BlockNumber> =
+            if let Some(e) = self.expiry {
+                Some(e.into())
+            } else {
+                None
+            };
+        let event = client
+            .post_bounty(
+                bounty,
+                self.amount.into(),
+                BountyOptionsOf::<R> {
+                    asset: self.asset.map(|a| a.into()),
+                    expiry,
+                    min_reputation: self.min_reputation,
+                    review_window: self.review_window.map(|w| w.into()),
+                    tags: self
+                        .tags
+                        .iter()
+                        .map(|t| t.as_bytes().to_vec())
+                        .collect(),
+                    ..Default::default()
+                },
+            )
+            .await?;
         println!(
             "Depositer with AccountId {} posted new BountyId {}, Balance {}",
             event.depositer, event.id, event.amount,
@@ -111,6 +149,7 @@ impl BountySubmitCommand {
                 self.bounty_id.into(),
                 bounty,
                 self.amount.into(),
+                None,
             )
             .await?;
         println!(
@@ -148,6 +187,97 @@ impl BountyApproveCommand {
     }
 }
 
+#[derive(Clone, Debug, Clap)]
+pub struct BountyTriggerReviewCommand {
+    pub submission_id: u64,
+}
+
+impl BountyTriggerReviewCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as Bounty>::SubmissionId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+        <R as Vote>::VoteId: Display,
+    {
+        let event = client
+            .trigger_submission_review(self.submission_id.into())
+            .await?;
+        println!(
+            "Review triggered for SubmissionId {} of BountyId {} with VoteId {}",
+            event.submission_id, event.bounty_id, event.vote_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyRejectCommand {
+    pub submission_id: u64,
+    pub reason: Option<String>,
+}
+
+impl BountyRejectCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Bounty>::SubmissionId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+        <R as Bounty>::RejectionReason: From<TextBlock>,
+    {
+        let reason: Option<<R as Bounty>::RejectionReason> =
+            if let Some(r) = &self.reason {
+                Some(
+                    TextBlock {
+                        text: (*r).to_string(),
+                    }
+                    .into(),
+                )
+            } else {
+                None
+            };
+        let event = client
+            .reject_bounty_submission(self.submission_id.into(), reason)
+            .await?;
+        println!(
+            "Rejected SubmissionId {} by AccountId {} for BountyId {}",
+            event.submission_id, event.submitter, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyWithdrawSubmissionCommand {
+    pub submission_id: u64,
+}
+
+impl BountyWithdrawSubmissionCommand {
+    pub async fn exec<R: Runtime + Bounty, C: BountyClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Bounty>::SubmissionId: From<u64> + Display,
+        <R as Bounty>::BountyId: Display,
+    {
+        let event = client
+            .withdraw_submission(self.submission_id.into())
+            .await?;
+        println!(
+            "AccountId {} withdrew SubmissionId {} for BountyId {}",
+            event.submitter, event.submission_id, event.bounty_id
+        );
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Clap)]
 pub struct GetBountyCommand {
     pub bounty_id: u64,
@@ -162,7 +292,7 @@ impl GetBountyCommand {
         <R as System>::AccountId: Ss58Codec,
         <R as Balances>::Balance: Display,
         <R as Bounty>::BountyId: Display + From<u64>,
-        <R as Bounty>::IpfsReference: Debug,
+        <R as Org>::IpfsReference: Debug,
     {
         let bounty_state = client.bounty(self.bounty_id.into()).await?;
         println!(
@@ -188,13 +318,13 @@ impl GetSubmissionCommand {
         <R as Balances>::Balance: Display,
         <R as Bounty>::BountyId: Display,
         <R as Bounty>::SubmissionId: Display + From<u64>,
-        <R as Bounty>::IpfsReference: Debug,
+        <R as Org>::IpfsReference: Debug,
     {
         let submission_state =
             client.submission(self.submission_id.into()).await?;
         println!(
-            "SUBMISSION {} INFORMATION: Bounty ID: {} | CID : {:?} | Submitter: {} | Total Balance: {} ",
-            self.submission_id, submission_state.bounty_id(), submission_state.submission(), submission_state.submitter(), submission_state.amount(),
+            "SUBMISSION {} INFORMATION: Bounty ID: {} | CID : {:?} | Submitter: {} | Total Balance: {} | Deposit: {} ",
+            self.submission_id, submission_state.bounty_id(), submission_state.submission(), submission_state.submitter(), submission_state.amount(), submission_state.deposit(),
         );
         Ok(())
     }
@@ -203,6 +333,12 @@ impl GetSubmissionCommand {
 #[derive(Clone, Debug, Clap)]
 pub struct GetOpenBountiesCommand {
     pub min: u128,
+    #[clap(short = "t", long = "tag")]
+    pub tags: Vec<String>,
+    #[clap(short = "p", long = "page", default_value = "0")]
+    pub page: u32,
+    #[clap(short = "l", long = "limit", default_value = "20")]
+    pub limit: u32,
 }
 
 impl GetOpenBountiesCommand {
@@ -215,7 +351,10 @@ impl GetOpenBountiesCommand {
         <R as Bounty>::BountyId: Display,
         <R as Bounty>::SubmissionId: Display + From<u64>,
     {
-        let open_bounties = client.open_bounties(self.min.into()).await?;
+        let tags = self.tags.iter().map(|t| t.as_bytes().to_vec()).collect();
+        let open_bounties = client
+            .open_bounties(self.min.into(), tags, self.page, self.limit)
+            .await?;
         if let Some(b) = open_bounties {
             b.into_iter().for_each(|(id, bounty)| {
                 println!(
@@ -251,9 +390,10 @@ impl GetOpenSubmissionsCommand {
         if let Some(s) = open_submissions {
             s.into_iter().for_each(|(id, sub)| {
                 println!(
-                    "Live SubmissionID {} requests total balance {}",
+                    "Live SubmissionID {} requests total balance {} with deposit {}",
                     id,
-                    sub.amount()
+                    sub.amount(),
+                    sub.deposit()
                 );
             });
         } else {
@@ -17,6 +17,7 @@ use sunshine_bounty_client::{
     TextBlock,
 };
 use sunshine_client_utils::{
+    cid::CidBytes,
     crypto::ss58::Ss58,
     Result,
 };
@@ -129,3 +130,353 @@ impl OrgRegisterWeightedCommand {
         Ok(())
     }
 }
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgProposeSupervisorCommand {
+    pub organization: u64,
+    pub new_supervisor: Option<String>,
+}
+
+impl OrgProposeSupervisorCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::OrgVoteId: Display,
+    {
+        let new_supervisor = if let Some(acc) = &self.new_supervisor {
+            let new_acc: Ss58<R> = acc.parse()?;
+            Some(new_acc.0)
+        } else {
+            None
+        };
+        let event = client
+            .propose_supervisor(self.organization.into(), new_supervisor)
+            .await?;
+        println!(
+            "Account {} proposed supervisor {:?} for Org {}, decided by VoteId {}",
+            event.proposer,
+            event.new_supervisor,
+            event.organization,
+            event.vote_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgPollSupervisorProposalCommand {
+    pub organization: u64,
+}
+
+impl OrgPollSupervisorProposalCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+    {
+        if let Some(event) = client
+            .poll_supervisor_proposal(self.organization.into())
+            .await?
+        {
+            println!(
+                "Supervisor of Org {} replaced from {:?} to {:?}",
+                event.organization, event.old_supervisor, event.new_supervisor
+            );
+        } else {
+            println!(
+                "Supervisor of Org {} not replaced, the proposal is still being voted on or was rejected",
+                self.organization
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgRequestMembershipCommand {
+    pub organization: u64,
+    pub application: String,
+}
+
+impl OrgRequestMembershipCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::IpfsReference: Debug,
+        <R as Org>::Constitution: From<TextBlock>,
+    {
+        let application = TextBlock {
+            text: (*self.application).to_string(),
+        };
+        let event = client
+            .request_membership(self.organization.into(), application.into())
+            .await?;
+        println!(
+            "Account {} requested to join Org {} with application {:?}",
+            event.applicant, event.organization, event.application
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgApproveMembershipRequestCommand {
+    pub organization: u64,
+    pub applicant: String,
+}
+
+impl OrgApproveMembershipRequestCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+    {
+        let applicant: Ss58<R> = self.applicant.parse()?;
+        let event = client
+            .approve_membership_request(self.organization.into(), &applicant.0)
+            .await?;
+        println!(
+            "Account {} admitted to Org {}",
+            event.applicant, event.organization
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgTriggerMembershipVoteCommand {
+    pub organization: u64,
+    pub applicant: String,
+}
+
+impl OrgTriggerMembershipVoteCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::OrgVoteId: Display,
+    {
+        let applicant: Ss58<R> = self.applicant.parse()?;
+        let event = client
+            .trigger_membership_vote(self.organization.into(), &applicant.0)
+            .await?;
+        println!(
+            "Account {} opened VoteId {} on admitting Account {} to Org {}",
+            event.trigger, event.vote_id, event.applicant, event.organization
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgPollMembershipRequestCommand {
+    pub organization: u64,
+    pub applicant: String,
+}
+
+impl OrgPollMembershipRequestCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+    {
+        let applicant: Ss58<R> = self.applicant.parse()?;
+        if let Some(event) = client
+            .poll_membership_request(self.organization.into(), &applicant.0)
+            .await?
+        {
+            println!(
+                "Account {} admitted to Org {}",
+                event.applicant, event.organization
+            );
+        } else {
+            println!(
+                "Account {} not admitted to Org {}, the request is still being voted on or was rejected",
+                self.applicant, self.organization
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgInviteMemberCommand {
+    pub organization: u64,
+    pub who: String,
+    pub shares: u64,
+}
+
+impl OrgInviteMemberCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::Shares: From<u64> + Display,
+    {
+        let who: Ss58<R> = self.who.parse()?;
+        let event = client
+            .invite_member(self.organization.into(), &who.0, self.shares.into())
+            .await?;
+        println!(
+            "Account {} invited to Org {} for {} shares",
+            event.who, event.organization, event.shares
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgAcceptInvitationCommand {
+    pub organization: u64,
+}
+
+impl OrgAcceptInvitationCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::Shares: Display,
+    {
+        let event = client.accept_invitation(self.organization.into()).await?;
+        println!(
+            "Account {} joined Org {} with {} shares",
+            event.who, event.organization, event.shares
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgProposeConstitutionAmendmentCommand {
+    pub organization: u64,
+    pub constitution: String,
+}
+
+impl OrgProposeConstitutionAmendmentCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::OrgVoteId: Display,
+        <R as Org>::IpfsReference: Debug,
+        <R as Org>::Constitution: From<TextBlock>,
+    {
+        let constitution = TextBlock {
+            text: (*self.constitution).to_string(),
+        };
+        let event = client
+            .propose_constitution_amendment(
+                self.organization.into(),
+                constitution.into(),
+            )
+            .await?;
+        println!(
+            "Account {} proposed constitution {:?} for Org {}, decided by VoteId {}",
+            event.proposer,
+            event.new_constitution,
+            event.organization,
+            event.vote_id
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgPollConstitutionAmendmentCommand {
+    pub organization: u64,
+}
+
+impl OrgPollConstitutionAmendmentCommand {
+    pub async fn exec<R: Runtime + Org, C: OrgClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::IpfsReference: Debug,
+    {
+        if let Some(event) = client
+            .poll_constitution_amendment(self.organization.into())
+            .await?
+        {
+            println!(
+                "Constitution of Org {} amended from {:?} to {:?}",
+                event.organization,
+                event.old_constitution,
+                event.new_constitution
+            );
+        } else {
+            println!(
+                "Constitution of Org {} not amended, the amendment is still being voted on or was rejected",
+                self.organization
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgConstitutionHistoryCommand {
+    pub organization: u64,
+}
+
+impl OrgConstitutionHistoryCommand {
+    pub async fn exec<
+        R: Runtime + Org<IpfsReference = CidBytes, Constitution = TextBlock>,
+        C: OrgClient<R>,
+    >(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as Org>::OrgId: From<u64>,
+    {
+        let history = client
+            .constitution_history(self.organization.into())
+            .await?;
+        if history.is_empty() {
+            println!(
+                "Constitution of Org {} was never amended",
+                self.organization
+            );
+        }
+        for (i, constitution) in history.into_iter().enumerate() {
+            println!(
+                "Amendment {}, replaced constitution: {}",
+                i + 1,
+                constitution.text
+            );
+        }
+        Ok(())
+    }
+}
@@ -268,3 +268,100 @@ impl SharesUnLockCommand {
         Ok(())
     }
 }
+
+#[derive(Clone, Debug, Clap)]
+pub struct SharesTransferCommand {
+    pub organization: u64,
+    pub dest: String,
+    pub shares: u64,
+}
+
+impl SharesTransferCommand {
+    pub async fn exec<R: Runtime + Shares, C: SharesClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::Shares: From<u64> + Display,
+    {
+        let account: Ss58<R> = self.dest.parse()?;
+        let event = client
+            .transfer_shares(
+                self.organization.into(),
+                &account.0,
+                self.shares.into(),
+            )
+            .await?;
+        println!(
+            "{} shares transferred from Account {} to Account {} in the context of Org {}",
+            event.shares, event.from, event.to, event.organization
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct SharesIssueVestingCommand {
+    pub organization: u64,
+    pub dest: String,
+    pub shares: u64,
+    pub per_block: u64,
+    pub cliff: u32,
+}
+
+impl SharesIssueVestingCommand {
+    pub async fn exec<R: Runtime + Shares, C: SharesClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as System>::BlockNumber: From<u32>,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::Shares: From<u64> + Display,
+    {
+        let account: Ss58<R> = self.dest.parse()?;
+        let event = client
+            .issue_vesting_shares(
+                self.organization.into(),
+                &account.0,
+                self.shares.into(),
+                self.per_block.into(),
+                self.cliff.into(),
+            )
+            .await?;
+        println!(
+            "{} shares minted for Account {} in the context of Org {}, vesting {} per block after {} blocks",
+            event.shares, event.who, event.organization, self.per_block, self.cliff
+        );
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct SharesReleaseVestedCommand {
+    pub organization: u64,
+}
+
+impl SharesReleaseVestedCommand {
+    pub async fn exec<R: Runtime + Shares, C: SharesClient<R>>(
+        &self,
+        client: &C,
+    ) -> Result<()>
+    where
+        <R as System>::AccountId: Ss58Codec,
+        <R as Org>::OrgId: From<u64> + Display,
+        <R as Org>::Shares: Display,
+    {
+        let event = client
+            .release_vested_shares(self.organization.into())
+            .await?;
+        println!(
+            "{} vested shares released for Account {} in the context of Org {}",
+            event.shares, event.who, event.organization
+        );
+        Ok(())
+    }
+}
@@ -2,9 +2,13 @@ mod subxt;
 
 pub use subxt::*;
 
-use crate::error::Error;
-use codec::Decode;
+use crate::{
+    error::Error,
+    org::Org,
+};
 use substrate_subxt::{
+    sp_core::Bytes,
+    system::System,
     Runtime,
     SignedExtension,
     SignedExtra,
@@ -21,22 +25,57 @@ pub trait BountyClient<T: Runtime + Bounty>: Client<T> {
         &self,
         bounty: T::BountyPost,
         amount: BalanceOf<T>,
+        options: BountyOptionsOf<T>,
     ) -> Result<BountyPostedEvent<T>>;
     async fn contribute_to_bounty(
         &self,
         bounty_id: T::BountyId,
         amount: BalanceOf<T>,
     ) -> Result<BountyRaiseContributionEvent<T>>;
+    async fn withdraw_contribution(
+        &self,
+        bounty_id: T::BountyId,
+        amount: BalanceOf<T>,
+    ) -> Result<ContributionWithdrawnEvent<T>>;
     async fn submit_for_bounty(
         &self,
         bounty_id: T::BountyId,
         submission: T::BountySubmission,
         amount: BalanceOf<T>,
+        recipient: Option<RecipientOf<T>>,
     ) -> Result<BountySubmissionPostedEvent<T>>;
     async fn approve_bounty_submission(
         &self,
         submission_id: T::SubmissionId,
     ) -> Result<BountyPaymentExecutedEvent<T>>;
+    async fn trigger_submission_review(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<BountySubmissionReviewTriggeredEvent<T>>;
+    async fn reject_bounty_submission(
+        &self,
+        submission_id: T::SubmissionId,
+        reason: Option<T::RejectionReason>,
+    ) -> Result<BountySubmissionRejectedEvent<T>>;
+    async fn withdraw_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<BountySubmissionWithdrawnEvent<T>>;
+    async fn dispute_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<BountySubmissionDisputedEvent<T>>;
+    async fn apply_to_work(
+        &self,
+        bounty_id: T::BountyId,
+        plan: T::WorkPlan,
+    ) -> Result<BountyWorkAppliedEvent<T>>;
+    async fn assign(
+        &self,
+        bounty_id: T::BountyId,
+        assignees: Vec<<T as System>::AccountId>,
+        deadline: T::BlockNumber,
+    ) -> Result<BountyAssignedEvent<T>>;
     async fn bounty(&self, bounty_id: T::BountyId) -> Result<BountyState<T>>;
     async fn submission(
         &self,
@@ -45,11 +84,18 @@ pub trait BountyClient<T: Runtime + Bounty>: Client<T> {
     async fn open_bounties(
         &self,
         min: BalanceOf<T>,
+        tags: Vec<Vec<u8>>,
+        page: u32,
+        limit: u32,
     ) -> Result<Option<Vec<(T::BountyId, BountyState<T>)>>>;
     async fn open_submissions(
         &self,
         bounty_id: T::BountyId,
     ) -> Result<Option<Vec<(T::SubmissionId, SubState<T>)>>>;
+    async fn contributions(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<Vec<(<T as System>::AccountId, BalanceOf<T>)>>;
 }
 
 #[async_trait]
@@ -58,7 +104,7 @@ where
     T: Runtime + Bounty,
     <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
         Send + Sync,
-    <T as Bounty>::IpfsReference: From<libipld::cid::Cid>,
+    <T as Org>::IpfsReference: From<libipld::cid::Cid>,
     C: Client<T>,
     C::OffchainClient: ipld_block_builder::Cache<
             ipld_block_builder::Codec,
@@ -66,17 +112,24 @@ where
         > + ipld_block_builder::Cache<
             ipld_block_builder::Codec,
             <T as Bounty>::BountySubmission,
+        > + ipld_block_builder::Cache<
+            ipld_block_builder::Codec,
+            <T as Bounty>::RejectionReason,
+        > + ipld_block_builder::Cache<
+            ipld_block_builder::Codec,
+            <T as Bounty>::WorkPlan,
         >,
 {
     async fn post_bounty(
         &self,
         bounty: T::BountyPost,
         amount: BalanceOf<T>,
+        options: BountyOptionsOf<T>,
     ) -> Result<BountyPostedEvent<T>> {
         let signer = self.chain_signer()?;
         let info = crate::post(self, bounty).await?;
         self.chain_client()
-            .post_bounty_and_watch(&signer, info.into(), amount)
+            .post_bounty_and_watch(&signer, info.into(), amount, options)
             .await?
             .bounty_posted()?
             .ok_or_else(|| Error::EventNotFound.into())
@@ -93,11 +146,24 @@ where
             .bounty_raise_contribution()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn withdraw_contribution(
+        &self,
+        bounty_id: T::BountyId,
+        amount: BalanceOf<T>,
+    ) -> Result<ContributionWithdrawnEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .withdraw_contribution_and_watch(&signer, bounty_id, amount)
+            .await?
+            .contribution_withdrawn()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn submit_for_bounty(
         &self,
         bounty_id: T::BountyId,
         submission: T::BountySubmission,
         amount: BalanceOf<T>,
+        recipient: Option<RecipientOf<T>>,
     ) -> Result<BountySubmissionPostedEvent<T>> {
         let signer = self.chain_signer()?;
         let submission_ref = crate::post(self, submission).await?;
@@ -107,6 +173,7 @@ where
                 bounty_id,
                 submission_ref.into(),
                 amount,
+                recipient,
             )
             .await?
             .bounty_submission_posted()?
@@ -123,6 +190,84 @@ where
             .bounty_payment_executed()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn trigger_submission_review(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<BountySubmissionReviewTriggeredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .trigger_submission_review_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_review_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn reject_bounty_submission(
+        &self,
+        submission_id: T::SubmissionId,
+        reason: Option<T::RejectionReason>,
+    ) -> Result<BountySubmissionRejectedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let reason = if let Some(r) = reason {
+            let iref: <T as Org>::IpfsReference =
+                crate::post(self, r).await?.into();
+            Some(iref)
+        } else {
+            None
+        };
+        self.chain_client()
+            .reject_bounty_submission_and_watch(&signer, submission_id, reason)
+            .await?
+            .bounty_submission_rejected()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn withdraw_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<BountySubmissionWithdrawnEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .withdraw_submission_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_withdrawn()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn dispute_submission(
+        &self,
+        submission_id: T::SubmissionId,
+    ) -> Result<BountySubmissionDisputedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .dispute_submission_and_watch(&signer, submission_id)
+            .await?
+            .bounty_submission_disputed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn apply_to_work(
+        &self,
+        bounty_id: T::BountyId,
+        plan: T::WorkPlan,
+    ) -> Result<BountyWorkAppliedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let plan_ref = crate::post(self, plan).await?;
+        self.chain_client()
+            .apply_to_work_and_watch(&signer, bounty_id, plan_ref.into())
+            .await?
+            .bounty_work_applied()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn assign(
+        &self,
+        bounty_id: T::BountyId,
+        assignees: Vec<<T as System>::AccountId>,
+        deadline: T::BlockNumber,
+    ) -> Result<BountyAssignedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .assign_and_watch(&signer, bounty_id, assignees, deadline)
+            .await?
+            .bounty_assigned()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
     async fn bounty(&self, bounty_id: T::BountyId) -> Result<BountyState<T>> {
         Ok(self.chain_client().bounties(bounty_id, None).await?)
     }
@@ -135,42 +280,65 @@ where
     async fn open_bounties(
         &self,
         min: BalanceOf<T>,
+        tags: Vec<Vec<u8>>,
+        page: u32,
+        limit: u32,
     ) -> Result<Option<Vec<(T::BountyId, BountyState<T>)>>> {
-        let mut bounties = self.chain_client().bounties_iter(None).await?;
-        let mut bounties_above_min =
-            Vec::<(T::BountyId, BountyState<T>)>::new();
-        while let Some((id, bounty)) = bounties.next().await? {
-            if bounty.total() >= min {
-                let decoded_key = Decode::decode(&mut &id.0[..])?;
-                bounties_above_min.push((decoded_key, bounty));
-            }
-        }
-        if bounties_above_min.is_empty() {
+        // member restricted bounties are only listed for members of their org
+        let who = self
+            .chain_signer()
+            .ok()
+            .map(|signer| signer.account_id().clone());
+        let tags = tags.into_iter().map(Bytes).collect::<Vec<_>>();
+        let bounties = crate::rpc::<T, Vec<(T::BountyId, BountyState<T>)>>(
+            self.chain_client(),
+            "bounty_openBounties",
+            vec![
+                serde_json::to_value(min)?,
+                serde_json::to_value(who)?,
+                serde_json::to_value(tags)?,
+                page.into(),
+                limit.into(),
+            ],
+        )
+        .await?;
+        if bounties.is_empty() {
             Ok(None)
         } else {
-            Ok(Some(bounties_above_min))
+            Ok(Some(bounties))
         }
     }
     async fn open_submissions(
         &self,
         bounty_id: T::BountyId,
     ) -> Result<Option<Vec<(T::SubmissionId, SubState<T>)>>> {
-        let mut submissions =
-            self.chain_client().submissions_iter(None).await?;
-        let mut submissions_for_bounty =
-            Vec::<(T::SubmissionId, SubState<T>)>::new();
-        while let Some((id, submission)) = submissions.next().await? {
-            if submission.bounty_id() == bounty_id {
-                let decoded_key = Decode::decode(&mut &id.0[..])?;
-                submissions_for_bounty.push((decoded_key, submission));
-            }
-        }
+        let submissions = crate::rpc::<T, Vec<(T::SubmissionId, SubState<T>)>>(
+            self.chain_client(),
+            "bounty_submissionsFor",
+            vec![serde_json::to_value(bounty_id)?],
+        )
+        .await?;
+        let submissions_for_bounty = submissions
+            .into_iter()
+            .filter(|(_, submission)| submission.state().awaiting_review())
+            .collect::<Vec<_>>();
         if submissions_for_bounty.is_empty() {
             Ok(None)
         } else {
             Ok(Some(submissions_for_bounty))
         }
     }
+    async fn contributions(
+        &self,
+        bounty_id: T::BountyId,
+    ) -> Result<Vec<(<T as System>::AccountId, BalanceOf<T>)>> {
+        crate::rpc::<T, Vec<(<T as System>::AccountId, BalanceOf<T>)>>(
+            self.chain_client(),
+            "bounty_contributions",
+            vec![serde_json::to_value(bounty_id)?],
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -190,7 +358,10 @@ mod tests {
             AccountKeyring,
             Client,
         },
-        utils::bounty::BountyInformation,
+        utils::bounty::{
+            BountyInformation,
+            BountyOptions,
+        },
         BountyBody,
     };
 
@@ -228,7 +399,10 @@ mod tests {
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 124,
         };
-        let event = client.post_bounty(bounty, 10u128).await.unwrap();
+        let event = client
+            .post_bounty(bounty, 10u128, BountyOptions::default())
+            .await
+            .unwrap();
         let expected_event = BountyPostedEvent {
             depositer: alice_account_id,
             amount: 10,
@@ -248,22 +422,41 @@ mod tests {
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 124,
         };
-        let event1 = client.post_bounty(bounty1, 10u128).await.unwrap();
+        let event1 = client
+            .post_bounty(bounty1, 10u128, BountyOptions::default())
+            .await
+            .unwrap();
         let bounty2 = BountyBody {
             repo_owner: "sunshine-protocol".to_string(),
             repo_name: "sunshine-bounty".to_string(),
             issue_number: 124,
         };
-        let event2 = client.post_bounty(bounty2, 10u128).await.unwrap();
-        let bounties = client.open_bounties(9u128).await.unwrap().unwrap();
+        let event2 = client
+            .post_bounty(bounty2, 10u128, BountyOptions::default())
+            .await
+            .unwrap();
+        let bounties = client
+            .open_bounties(9u128, vec![], 0, 10)
+            .await
+            .unwrap()
+            .unwrap();
         assert_eq!(bounties.len(), 2);
         let expected_bounty1 = BountyInformation::new(
             event1.description,
             alice_account_id.clone(),
+            None,
+            0,
+            10,
+            None,
+        );
+        let expected_bounty2 = BountyInformation::new(
+            event2.description,
+            alice_account_id,
+            None,
+            0,
             10,
+            None,
         );
-        let expected_bounty2 =
-            BountyInformation::new(event2.description, alice_account_id, 10);
         assert_eq!(bounties.get(0).unwrap().1, expected_bounty1);
         assert_eq!(bounties.get(1).unwrap().1, expected_bounty2);
     }
@@ -1,3 +1,13 @@
+use crate::{
+    org::{
+        Org,
+        OrgEventsDecoder,
+    },
+    vote::{
+        Vote,
+        VoteEventsDecoder,
+    },
+};
 use codec::{
     Codec,
     Decode,
@@ -11,11 +21,14 @@ use libipld::{
         Encode as DagDecode,
     },
 };
-use sp_runtime::traits::{
-    AtLeast32Bit,
-    MaybeSerializeDeserialize,
-    Member,
-    Zero,
+use sp_runtime::{
+    traits::{
+        AtLeast32Bit,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    Permill,
 };
 use std::fmt::Debug;
 use substrate_subxt::{
@@ -33,19 +46,50 @@ use substrate_subxt::{
     Event,
     Store,
 };
-use sunshine_bounty_utils::bounty::{
-    BountyInformation,
-    BountySubmission,
-    SubmissionState,
+use sunshine_bounty_utils::{
+    bounty::{
+        BountyInformation,
+        BountyOptions,
+        BountySubmission,
+        SubmissionState,
+    },
+    grant::Recipient,
+    meta::{
+        ResolutionMetadata,
+        VoteMetadata,
+    },
+    organization::OrgRep,
 };
 
 pub type BalanceOf<T> = <T as Balances>::Balance;
+pub type GovernanceOf<T> = ResolutionMetadata<
+    <T as System>::AccountId,
+    VoteMetadata<
+        OrgRep<<T as Org>::OrgId>,
+        <T as Vote>::Signal,
+        Permill,
+        <T as System>::BlockNumber,
+    >,
+>;
+pub type RecipientOf<T> =
+    Recipient<<T as System>::AccountId, OrgRep<<T as Org>::OrgId>>;
+pub type ArbitrationOf<T> = VoteMetadata<
+    OrgRep<<T as Org>::OrgId>,
+    <T as Vote>::Signal,
+    Permill,
+    <T as System>::BlockNumber,
+>;
+pub type BountyOptionsOf<T> = BountyOptions<
+    <T as Bounty>::AssetId,
+    <T as System>::BlockNumber,
+    GovernanceOf<T>,
+    ArbitrationOf<T>,
+    BalanceOf<T>,
+    <T as Org>::OrgId,
+>;
 
 #[module]
-pub trait Bounty: System + Balances {
-    /// Cid type
-    type IpfsReference: Parameter + Member + Default;
-
+pub trait Bounty: System + Balances + Org + Vote {
     type BountyId: Parameter
         + Member
         + AtLeast32Bit
@@ -58,6 +102,24 @@ pub trait Bounty: System + Balances {
         + PartialEq
         + Zero;
 
+    /// The asset identifier, bounties are funded and paid out in one asset
+    type AssetId: Parameter
+        + Member
+        + Copy
+        + Default
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// The identifier for court disputes raised against reviews
+    type DisputeId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
     /// The shape of bounty postings
     type BountyPost: 'static
         + Codec
@@ -89,21 +151,44 @@ pub trait Bounty: System + Balances {
         + DagDecode<DagCborCodec>
         + Send
         + Sync;
+
+    /// The shape of submission rejection reasons
+    type RejectionReason: 'static
+        + Codec
+        + Default
+        + Clone
+        + DagEncode<DagCborCodec>
+        + DagDecode<DagCborCodec>
+        + Send
+        + Sync;
+
+    /// The shape of work plans in applications to work on bounties
+    type WorkPlan: 'static
+        + Codec
+        + Default
+        + Clone
+        + DagEncode<DagCborCodec>
+        + DagDecode<DagCborCodec>
+        + Send
+        + Sync;
 }
 
 // ~~ Storage ~~
 
 pub type BountyState<T> = BountyInformation<
-    <T as Bounty>::IpfsReference,
+    <T as Org>::IpfsReference,
     <T as System>::AccountId,
+    GovernanceOf<T>,
+    <T as Bounty>::AssetId,
     BalanceOf<T>,
+    <T as System>::BlockNumber,
 >;
 pub type SubState<T> = BountySubmission<
     <T as Bounty>::BountyId,
-    <T as Bounty>::IpfsReference,
+    <T as Org>::IpfsReference,
     <T as System>::AccountId,
     BalanceOf<T>,
-    SubmissionState,
+    SubmissionState<<T as Vote>::VoteId>,
 >;
 
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
@@ -118,12 +203,102 @@ pub struct SubmissionsStore<T: Bounty> {
     pub id: T::SubmissionId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyTipsStore<'a, T: Bounty> {
+    #[store(returns = BalanceOf<T>)]
+    pub bounty_id: T::BountyId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct LastPayoutStore<T: Bounty> {
+    #[store(returns = <T as System>::BlockNumber)]
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountySubmissionsStore<T: Bounty> {
+    #[store(returns = ())]
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyEligibilityStore<T: Bounty> {
+    #[store(returns = <T as Org>::OrgId)]
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyMinReputationStore<T: Bounty> {
+    #[store(returns = u32)]
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyTagsStore<T: Bounty> {
+    #[store(returns = Vec<Vec<u8>>)]
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct TaggedBountiesStore<T: Bounty> {
+    #[store(returns = ())]
+    pub tag: Vec<u8>,
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyReviewWindowsStore<T: Bounty> {
+    #[store(returns = <T as System>::BlockNumber)]
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ReviewDeadlinesStore<T: Bounty> {
+    #[store(returns = <T as System>::BlockNumber)]
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyMilestonesStore<T: Bounty> {
+    #[store(returns = Vec<BalanceOf<T>>)]
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct MilestonesPaidStore<T: Bounty> {
+    #[store(returns = u32)]
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ApplicationsStore<'a, T: Bounty> {
+    #[store(returns = <T as Org>::IpfsReference)]
+    pub bounty_id: T::BountyId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct AssigneesStore<'a, T: Bounty> {
+    #[store(returns = ())]
+    pub bounty_id: T::BountyId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct AssignmentDeadlinesStore<T: Bounty> {
+    #[store(returns = <T as System>::BlockNumber)]
+    pub bounty_id: T::BountyId,
+}
+
 // ~~ (Calls, Events) ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct PostBountyCall<T: Bounty> {
-    pub info: T::IpfsReference,
+    pub info: <T as Org>::IpfsReference,
     pub amount: BalanceOf<T>,
+    pub options: BountyOptionsOf<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -131,7 +306,7 @@ pub struct BountyPostedEvent<T: Bounty> {
     pub depositer: <T as System>::AccountId,
     pub amount: BalanceOf<T>,
     pub id: T::BountyId,
-    pub description: T::IpfsReference,
+    pub description: <T as Org>::IpfsReference,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
@@ -146,14 +321,29 @@ pub struct BountyRaiseContributionEvent<T: Bounty> {
     pub amount: BalanceOf<T>,
     pub bounty_id: T::BountyId,
     pub total: BalanceOf<T>,
-    pub bounty_ref: T::IpfsReference,
+    pub bounty_ref: <T as Org>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct WithdrawContributionCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub amount: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ContributionWithdrawnEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub contributor: <T as System>::AccountId,
+    pub amount: BalanceOf<T>,
+    pub total: BalanceOf<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct SubmitForBountyCall<T: Bounty> {
     pub bounty_id: T::BountyId,
-    pub submission_ref: T::IpfsReference,
+    pub submission_ref: <T as Org>::IpfsReference,
     pub amount: BalanceOf<T>,
+    pub recipient: Option<RecipientOf<T>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -162,8 +352,8 @@ pub struct BountySubmissionPostedEvent<T: Bounty> {
     pub bounty_id: T::BountyId,
     pub amount: BalanceOf<T>,
     pub id: T::SubmissionId,
-    pub bounty_ref: T::IpfsReference,
-    pub submission_ref: T::IpfsReference,
+    pub bounty_ref: <T as Org>::IpfsReference,
+    pub submission_ref: <T as Org>::IpfsReference,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
@@ -178,6 +368,119 @@ pub struct BountyPaymentExecutedEvent<T: Bounty> {
     pub submission_id: T::SubmissionId,
     pub amount: BalanceOf<T>,
     pub submitter: <T as System>::AccountId,
-    pub bounty_ref: T::IpfsReference,
-    pub submission_ref: T::IpfsReference,
+    pub bounty_ref: <T as Org>::IpfsReference,
+    pub submission_ref: <T as Org>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyTeamPaymentExecutedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub transfers: Vec<(<T as System>::AccountId, BalanceOf<T>)>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyExpiredAndRefundedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub refunded: BalanceOf<T>,
+    pub bounty_ref: <T as Org>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RejectBountySubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+    pub reason: Option<<T as Org>::IpfsReference>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionRejectedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submitter: <T as System>::AccountId,
+    pub reason: Option<<T as Org>::IpfsReference>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct WithdrawSubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionWithdrawnEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submitter: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TriggerSubmissionReviewCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionReviewTriggeredEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct DisputeSubmissionCall<T: Bounty> {
+    pub submission_id: T::SubmissionId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountySubmissionDisputedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submitter: <T as System>::AccountId,
+    pub dispute_id: T::DisputeId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApplyToWorkCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub plan_ref: <T as Org>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyWorkAppliedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub applicant: <T as System>::AccountId,
+    pub plan_ref: <T as Org>::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct AssignCall<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub assignees: Vec<<T as System>::AccountId>,
+    pub deadline: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyAssignedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub assignees: Vec<<T as System>::AccountId>,
+    pub deadline: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyReopenedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct BountyMilestonePaidEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub milestone: u32,
+    pub amount: BalanceOf<T>,
+    pub remaining: BalanceOf<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SubmissionAutoApprovedEvent<T: Bounty> {
+    pub bounty_id: T::BountyId,
+    pub submission_id: T::SubmissionId,
+    pub submitter: <T as System>::AccountId,
 }
@@ -3,9 +3,11 @@ mod error;
 pub use error::Error;
 pub mod bank;
 pub mod bounty;
+pub mod bounty2;
 pub mod court;
 pub mod donate;
 pub mod org;
+pub mod reputation;
 pub mod vote;
 pub use sunshine_bounty_utils as utils;
 
@@ -17,6 +19,7 @@ use ipld_block_builder::{
     Cache,
     Codec,
 };
+use jsonrpsee::common::Params;
 use libipld::{
     cbor::DagCborCodec,
     codec::{
@@ -26,6 +29,7 @@ use libipld::{
     DagCbor,
 };
 use substrate_subxt::{
+    sp_core::Bytes,
     sp_runtime::traits::SignedExtension,
     Runtime,
     SignedExtra,
@@ -63,3 +67,16 @@ where
     client.offchain_client().flush().await?;
     Ok(cid)
 }
+
+/// Calls an rpc method of the node and decodes the SCALE encoded result
+pub(crate) async fn rpc<T: Runtime, R: Decode>(
+    client: &substrate_subxt::Client<T>,
+    method: &str,
+    params: Vec<serde_json::Value>,
+) -> Result<R> {
+    let bytes: Bytes = client
+        .rpc_client()
+        .request(method, Params::Array(params))
+        .await?;
+    Ok(Decode::decode(&mut &bytes[..])?)
+}
@@ -5,6 +5,7 @@ pub use subxt::*;
 pub use utils::AccountShare;
 
 use crate::error::Error;
+use ipld_block_builder::Cache;
 use substrate_subxt::{
     system::System,
     Runtime,
@@ -13,6 +14,7 @@ use substrate_subxt::{
 };
 use sunshine_client_utils::{
     async_trait,
+    cid::CidBytes,
     Client,
     Result,
 };
@@ -75,6 +77,83 @@ pub trait OrgClient<T: Runtime + Org>: Client<T> {
         org: <T as Org>::OrgId,
         who: &<T as System>::AccountId,
     ) -> Result<SharesUnlockedEvent<T>>;
+    async fn transfer_shares(
+        &self,
+        org: <T as Org>::OrgId,
+        to: &<T as System>::AccountId,
+        shares: <T as Org>::Shares,
+    ) -> Result<SharesTransferredEvent<T>>;
+    async fn propose_supervisor(
+        &self,
+        org: <T as Org>::OrgId,
+        new_supervisor: Option<<T as System>::AccountId>,
+    ) -> Result<SupervisorProposedEvent<T>>;
+    /// Returns the replacement if the vote on the open proposal was approved
+    async fn poll_supervisor_proposal(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<Option<SupervisorReplacedEvent<T>>>;
+    /// The application is a text block, posted like a constitution
+    async fn request_membership(
+        &self,
+        org: <T as Org>::OrgId,
+        application: <T as Org>::Constitution,
+    ) -> Result<MembershipRequestedEvent<T>>;
+    async fn approve_membership_request(
+        &self,
+        org: <T as Org>::OrgId,
+        applicant: &<T as System>::AccountId,
+    ) -> Result<MembershipRequestApprovedEvent<T>>;
+    async fn trigger_membership_vote(
+        &self,
+        org: <T as Org>::OrgId,
+        applicant: &<T as System>::AccountId,
+    ) -> Result<MembershipVoteTriggeredEvent<T>>;
+    /// Returns the admission if the vote on the request was approved
+    async fn poll_membership_request(
+        &self,
+        org: <T as Org>::OrgId,
+        applicant: &<T as System>::AccountId,
+    ) -> Result<Option<MembershipRequestApprovedEvent<T>>>;
+    async fn invite_member(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+        shares: <T as Org>::Shares,
+    ) -> Result<MemberInvitedEvent<T>>;
+    async fn accept_invitation(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<InvitationAcceptedEvent<T>>;
+    async fn issue_vesting_shares(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        per_block: <T as Org>::Shares,
+        cliff: <T as System>::BlockNumber,
+    ) -> Result<VestingSharesIssuedEvent<T>>;
+    async fn release_vested_shares(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<VestedSharesReleasedEvent<T>>;
+    async fn propose_constitution_amendment(
+        &self,
+        org: <T as Org>::OrgId,
+        new_constitution: <T as Org>::Constitution,
+    ) -> Result<ConstitutionAmendmentProposedEvent<T>>;
+    /// Returns the amendment if the vote on it was approved
+    async fn poll_constitution_amendment(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<Option<ConstitutionAmendedEvent<T>>>;
+    /// Constitutions replaced by amendments, oldest first, resolved through the offchain client
+    async fn constitution_history(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<Vec<<T as Org>::Constitution>>
+    where
+        T: Org<IpfsReference = CidBytes>;
 }
 
 #[async_trait]
@@ -230,6 +309,188 @@ where
             .shares_unlocked()?
             .ok_or_else(|| Error::EventNotFound.into())
     }
+    async fn transfer_shares(
+        &self,
+        org: <T as Org>::OrgId,
+        to: &<T as System>::AccountId,
+        shares: <T as Org>::Shares,
+    ) -> Result<SharesTransferredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .transfer_shares_and_watch(&signer, org, to, shares)
+            .await?
+            .shares_transferred()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_supervisor(
+        &self,
+        org: <T as Org>::OrgId,
+        new_supervisor: Option<<T as System>::AccountId>,
+    ) -> Result<SupervisorProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .propose_supervisor_and_watch(&signer, org, new_supervisor)
+            .await?
+            .supervisor_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn poll_supervisor_proposal(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<Option<SupervisorReplacedEvent<T>>> {
+        let signer = self.chain_signer()?;
+        Ok(self
+            .chain_client()
+            .poll_supervisor_proposal_and_watch(&signer, org)
+            .await?
+            .supervisor_replaced()?)
+    }
+    async fn request_membership(
+        &self,
+        org: <T as Org>::OrgId,
+        application: <T as Org>::Constitution,
+    ) -> Result<MembershipRequestedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let application = crate::post(self, application).await?;
+        self.chain_client()
+            .request_membership_and_watch(&signer, org, application.into())
+            .await?
+            .membership_requested()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn approve_membership_request(
+        &self,
+        org: <T as Org>::OrgId,
+        applicant: &<T as System>::AccountId,
+    ) -> Result<MembershipRequestApprovedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .approve_membership_request_and_watch(&signer, org, applicant)
+            .await?
+            .membership_request_approved()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn trigger_membership_vote(
+        &self,
+        org: <T as Org>::OrgId,
+        applicant: &<T as System>::AccountId,
+    ) -> Result<MembershipVoteTriggeredEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .trigger_membership_vote_and_watch(&signer, org, applicant)
+            .await?
+            .membership_vote_triggered()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn poll_membership_request(
+        &self,
+        org: <T as Org>::OrgId,
+        applicant: &<T as System>::AccountId,
+    ) -> Result<Option<MembershipRequestApprovedEvent<T>>> {
+        let signer = self.chain_signer()?;
+        Ok(self
+            .chain_client()
+            .poll_membership_request_and_watch(&signer, org, applicant)
+            .await?
+            .membership_request_approved()?)
+    }
+    async fn invite_member(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+        shares: <T as Org>::Shares,
+    ) -> Result<MemberInvitedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .invite_member_and_watch(&signer, org, who, shares)
+            .await?
+            .member_invited()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn accept_invitation(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<InvitationAcceptedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .accept_invitation_and_watch(&signer, org)
+            .await?
+            .invitation_accepted()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn issue_vesting_shares(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        per_block: <T as Org>::Shares,
+        cliff: <T as System>::BlockNumber,
+    ) -> Result<VestingSharesIssuedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .issue_vesting_shares_and_watch(
+                &signer, org, who, shares, per_block, cliff,
+            )
+            .await?
+            .vesting_shares_issued()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn release_vested_shares(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<VestedSharesReleasedEvent<T>> {
+        let signer = self.chain_signer()?;
+        self.chain_client()
+            .release_vested_shares_and_watch(&signer, org)
+            .await?
+            .vested_shares_released()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn propose_constitution_amendment(
+        &self,
+        org: <T as Org>::OrgId,
+        new_constitution: <T as Org>::Constitution,
+    ) -> Result<ConstitutionAmendmentProposedEvent<T>> {
+        let signer = self.chain_signer()?;
+        let new_constitution = crate::post(self, new_constitution).await?;
+        self.chain_client()
+            .propose_constitution_amendment_and_watch(
+                &signer,
+                org,
+                new_constitution.into(),
+            )
+            .await?
+            .constitution_amendment_proposed()?
+            .ok_or_else(|| Error::EventNotFound.into())
+    }
+    async fn poll_constitution_amendment(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<Option<ConstitutionAmendedEvent<T>>> {
+        let signer = self.chain_signer()?;
+        Ok(self
+            .chain_client()
+            .poll_constitution_amendment_and_watch(&signer, org)
+            .await?
+            .constitution_amended()?)
+    }
+    async fn constitution_history(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<Vec<<T as Org>::Constitution>>
+    where
+        T: Org<IpfsReference = CidBytes>,
+    {
+        let history =
+            self.chain_client().constitution_history(org, None).await?;
+        let mut constitutions = Vec::with_capacity(history.len());
+        for reference in history {
+            let constitution =
+                self.offchain_client().get(&reference.to_cid()?).await?;
+            constitutions.push(constitution);
+        }
+        Ok(constitutions)
+    }
 }
 
 #[cfg(test)]
@@ -31,7 +31,10 @@ use substrate_subxt::{
 };
 use sunshine_bounty_utils::{
     organization::Organization,
-    share::ShareProfile,
+    share::{
+        ShareProfile,
+        VestingSchedule,
+    },
 };
 
 /// The subset of the org trait and its inherited traits that the client must inherit
@@ -62,6 +65,14 @@ pub trait Org: System {
         + Debug
         + Zero;
 
+    /// Identifier of votes of the org's members
+    type OrgVoteId: Parameter
+        + Member
+        + Copy
+        + Default
+        + MaybeSerializeDeserialize
+        + Debug;
+
     /// Constitution associated type, text block
     type Constitution: 'static
         + Codec
@@ -106,6 +117,45 @@ pub struct MembersStore<'a, T: Org> {
     pub who: &'a <T as System>::AccountId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SupervisorProposalsStore<T: Org> {
+    #[store(returns = (T::OrgVoteId, Option<<T as System>::AccountId>))]
+    pub org: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct MembershipRequestsStore<'a, T: Org> {
+    #[store(returns = (T::IpfsReference, Option<T::OrgVoteId>))]
+    pub org: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct InvitationsStore<'a, T: Org> {
+    #[store(returns = T::Shares)]
+    pub org: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct VestingSchedulesStore<'a, T: Org> {
+    #[store(returns = VestingSchedule<T::Shares, <T as System>::BlockNumber>)]
+    pub org: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ConstitutionAmendmentsStore<T: Org> {
+    #[store(returns = (T::OrgVoteId, T::IpfsReference))]
+    pub org: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ConstitutionHistoryStore<T: Org> {
+    #[store(returns = Vec<T::IpfsReference>)]
+    pub org: T::OrgId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct OrganizationSizeStore<T: Org> {
     #[store(returns = u32)]
@@ -247,3 +297,187 @@ pub struct SharesUnReservedEvent<T: Org> {
     pub who: <T as System>::AccountId,
     pub amount_unreserved: T::Shares,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TransferSharesCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub to: &'a <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SharesTransferredEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub from: <T as System>::AccountId,
+    pub to: <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeSupervisorCall<T: Org> {
+    pub organization: T::OrgId,
+    pub new_supervisor: Option<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorProposedEvent<T: Org> {
+    pub proposer: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub new_supervisor: Option<<T as System>::AccountId>,
+    pub vote_id: T::OrgVoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PollSupervisorProposalCall<T: Org> {
+    pub organization: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorReplacedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub old_supervisor: Option<<T as System>::AccountId>,
+    pub new_supervisor: Option<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SupervisorProposalRejectedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub vote_id: T::OrgVoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct RequestMembershipCall<T: Org> {
+    pub organization: T::OrgId,
+    pub application: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MembershipRequestedEvent<T: Org> {
+    pub applicant: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub application: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ApproveMembershipRequestCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub applicant: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct TriggerMembershipVoteCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub applicant: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MembershipVoteTriggeredEvent<T: Org> {
+    pub trigger: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub applicant: <T as System>::AccountId,
+    pub vote_id: T::OrgVoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PollMembershipRequestCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub applicant: &'a <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MembershipRequestApprovedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub applicant: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MembershipRequestRejectedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub applicant: <T as System>::AccountId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct InviteMemberCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MemberInvitedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct AcceptInvitationCall<T: Org> {
+    pub organization: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct InvitationAcceptedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct IssueVestingSharesCall<'a, T: Org> {
+    pub organization: T::OrgId,
+    pub who: &'a <T as System>::AccountId,
+    pub shares: T::Shares,
+    pub per_block: T::Shares,
+    pub cliff: <T as System>::BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestingSharesIssuedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ReleaseVestedSharesCall<T: Org> {
+    pub organization: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestedSharesReleasedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub shares: T::Shares,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeConstitutionAmendmentCall<T: Org> {
+    pub organization: T::OrgId,
+    pub new_constitution: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ConstitutionAmendmentProposedEvent<T: Org> {
+    pub proposer: <T as System>::AccountId,
+    pub organization: T::OrgId,
+    pub new_constitution: T::IpfsReference,
+    pub vote_id: T::OrgVoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct PollConstitutionAmendmentCall<T: Org> {
+    pub organization: T::OrgId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ConstitutionAmendedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub old_constitution: T::IpfsReference,
+    pub new_constitution: T::IpfsReference,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ConstitutionAmendmentRejectedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub vote_id: T::OrgVoteId,
+}
@@ -0,0 +1,59 @@
+mod subxt;
+
+pub use subxt::*;
+
+use substrate_subxt::{
+    system::System,
+    Runtime,
+    SignedExtension,
+    SignedExtra,
+};
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Result,
+};
+
+#[async_trait]
+pub trait ReputationClient<T: Runtime + Reputation>: Client<T> {
+    async fn reputation(
+        &self,
+        who: &<T as System>::AccountId,
+    ) -> Result<ReputationOf<T>>;
+    async fn top_accounts(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<(<T as System>::AccountId, ReputationOf<T>)>>;
+}
+
+#[async_trait]
+impl<T, C> ReputationClient<T> for C
+where
+    T: Runtime + Reputation,
+    <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
+        Send + Sync,
+    C: Client<T>,
+{
+    async fn reputation(
+        &self,
+        who: &<T as System>::AccountId,
+    ) -> Result<ReputationOf<T>> {
+        crate::rpc::<T, ReputationOf<T>>(
+            self.chain_client(),
+            "reputation_reputationOf",
+            vec![serde_json::to_value(who)?],
+        )
+        .await
+    }
+    async fn top_accounts(
+        &self,
+        limit: u32,
+    ) -> Result<Vec<(<T as System>::AccountId, ReputationOf<T>)>> {
+        crate::rpc::<T, Vec<(<T as System>::AccountId, ReputationOf<T>)>>(
+            self.chain_client(),
+            "reputation_topAccounts",
+            vec![limit.into()],
+        )
+        .await
+    }
+}
@@ -0,0 +1,40 @@
+use codec::{
+    Decode,
+    Encode,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
+    module,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Event,
+    Store,
+};
+pub use sunshine_bounty_utils::reputation::ReputationRecord;
+
+pub type ReputationOf<T> = ReputationRecord<<T as Balances>::Balance>;
+
+#[module]
+pub trait Reputation: System + Balances {}
+
+// ~~ Storage ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct ReputationsStore<'a, T: Reputation> {
+    #[store(returns = ReputationOf<T>)]
+    pub who: &'a <T as System>::AccountId,
+}
+
+// ~~ Events ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct ScoreUpdatedEvent<T: Reputation> {
+    pub who: <T as System>::AccountId,
+    pub score: u32,
+}
@@ -7,6 +7,7 @@ pub struct BountyInformation {
     pub repo_name: String,
     pub issue_number: u64,
     pub depositer: String,
+    pub asset: u32,
     pub total: u128,
 }
 
@@ -19,6 +20,9 @@ pub struct BountySubmissionInformation {
     pub bounty_id: u64,
     pub submitter: String,
     pub amount: u128,
+    pub deposit: u128,
     pub awaiting_review: bool,
     pub approved: bool,
+    pub rejected: bool,
+    pub withdrawn: bool,
 }
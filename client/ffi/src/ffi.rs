@@ -32,10 +32,13 @@ use sunshine_bounty_client::{
     bounty::{
         Bounty as BountyTrait,
         BountyClient,
+        BountyOptionsOf,
         BountyState,
         SubState,
     },
+    org::Org,
     BountyBody,
+    TextBlock,
 };
 use sunshine_client_utils::{
     cid::CidBytes,
@@ -182,11 +185,12 @@ impl<'a, C, R> Bounty<'a, C, R>
 where
     C: BountyClient<R> + Send + Sync,
     R: Runtime + BountyTrait,
-    R: BountyTrait<IpfsReference = CidBytes>,
+    R: Org<IpfsReference = CidBytes>,
     C::OffchainClient: Cache<Codec, BountyBody>,
     <R as System>::AccountId: ToString,
     <R as BountyTrait>::BountyId: From<u64> + Into<u64>,
     <R as BountyTrait>::SubmissionId: From<u64> + Into<u64>,
+    <R as BountyTrait>::AssetId: Into<u32>,
     <R as Balances>::Balance: Into<u128> + From<u64>,
 {
     pub async fn get(&self, bounty_id: u64) -> Result<Vec<u8>> {
@@ -217,7 +221,7 @@ where
             .client
             .read()
             .await
-            .post_bounty(bounty, amount.into())
+            .post_bounty(bounty, amount.into(), BountyOptionsOf::<R>::default())
             .await?;
         Ok(event.id.into())
     }
@@ -257,7 +261,7 @@ where
             .client
             .read()
             .await
-            .submit_for_bounty(bounty_id.into(), bounty, amount.into())
+            .submit_for_bounty(bounty_id.into(), bounty, amount.into(), None)
             .await?;
         Ok(event.id.into())
     }
@@ -272,6 +276,39 @@ where
         Ok(event.new_total.into())
     }
 
+    pub async fn reject(
+        &self,
+        submission_id: u64,
+        reason: Option<&str>,
+    ) -> Result<u64>
+    where
+        <R as BountyTrait>::RejectionReason: From<TextBlock>,
+    {
+        let reason = reason.map(|r| {
+            TextBlock {
+                text: r.to_string(),
+            }
+            .into()
+        });
+        let event = self
+            .client
+            .read()
+            .await
+            .reject_bounty_submission(submission_id.into(), reason)
+            .await?;
+        Ok(event.submission_id.into())
+    }
+
+    pub async fn withdraw_submission(&self, submission_id: u64) -> Result<u64> {
+        let event = self
+            .client
+            .read()
+            .await
+            .withdraw_submission(submission_id.into())
+            .await?;
+        Ok(event.submission_id.into())
+    }
+
     pub async fn get_submission(&self, submission_id: u64) -> Result<Vec<u8>> {
         let submission_state = self
             .client
@@ -285,9 +322,20 @@ where
         Ok(serde_cbor::to_vec(&info)?)
     }
 
-    pub async fn open_bounties(&self, min: u64) -> Result<Vec<u8>> {
-        let open_bounties =
-            self.client.read().await.open_bounties(min.into()).await?;
+    pub async fn open_bounties(
+        &self,
+        min: u64,
+        tag: Option<&str>,
+        page: u32,
+        limit: u32,
+    ) -> Result<Vec<u8>> {
+        let tags = tag.map(|t| t.as_bytes().to_vec()).into_iter().collect();
+        let open_bounties = self
+            .client
+            .read()
+            .await
+            .open_bounties(min.into(), tags, page, limit)
+            .await?;
         match open_bounties {
             Some(list) => {
                 let mut v = Vec::with_capacity(list.len());
@@ -348,6 +396,7 @@ where
             repo_name: bounty_body.repo_name,
             issue_number: bounty_body.issue_number,
             depositer: state.depositer().to_string(),
+            asset: state.asset().into(),
             total: state.total().into(),
         };
         Ok(info)
@@ -368,7 +417,7 @@ where
             .get(&event_cid)
             .await?;
 
-        let awaiting_review = state.state().awaiting_review();
+        let submission_state = state.state();
         let info = BountySubmissionInformation {
             id: id.into(),
             repo_owner: submission_body.repo_owner,
@@ -377,8 +426,11 @@ where
             bounty_id: state.bounty_id().into(),
             submitter: state.submitter().to_string(),
             amount: state.amount().into(),
-            awaiting_review,
-            approved: !awaiting_review,
+            deposit: state.deposit().into(),
+            awaiting_review: submission_state.awaiting_review(),
+            approved: submission_state.approved(),
+            rejected: submission_state.rejected(),
+            withdrawn: submission_state.withdrawn(),
         };
         Ok(info)
     }
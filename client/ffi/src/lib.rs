@@ -107,9 +107,23 @@ macro_rules! impl_bounty_ffi {
             /// Approve a Submission using `SubmissionId`
             /// Returns the new total amount on that bounty after this operation
             Bounty::approve => fn client_bounty_approve(submission_id: u64 = submission_id) -> u128;
-            /// Get a list of open bounties.
+            /// Reject a Submission using `SubmissionId` with an optional reason
+            /// Returns the rejected `SubmissionId` as `u64`
+            Bounty::reject => fn client_bounty_reject(
+                submission_id: u64 = submission_id,
+                reason: *const raw::c_char = cstr!(reason, allow_null)
+            ) -> u64;
+            /// Withdraw your own Submission using `SubmissionId`
+            /// Returns the withdrawn `SubmissionId` as `u64`
+            Bounty::withdraw_submission => fn client_bounty_withdraw_submission(submission_id: u64 = submission_id) -> u64;
+            /// Get a page of `limit` open bounties, only those with the tag if one is passed.
             /// Returns a Cbor encoded list of `BountyInformation` as bytes.
-            Bounty::open_bounties => fn client_bounty_open_bounties(min: u64 = min) -> Cbor<Vec<BountyInformation>>;
+            Bounty::open_bounties => fn client_bounty_open_bounties(
+                min: u64 = min,
+                tag: *const raw::c_char = cstr!(tag, allow_null),
+                page: u32 = page,
+                limit: u32 = limit
+            ) -> Cbor<Vec<BountyInformation>>;
             /// Get a list of open submissions on a bounty.
             /// Returns a Cbor encoded list of `BountySubmissionInformation` as bytes.
             Bounty::open_bounty_submissions => fn client_bounty_open_bounty_submissions(bounty_id: u64 = bounty_id) -> Cbor<Vec<BountySubmissionInformation>>;
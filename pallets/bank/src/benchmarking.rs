@@ -0,0 +1,103 @@
+//! Bank pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use org::MAX_MEMBERS;
+use sp_std::prelude::*;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+};
+
+const SEED: u32 = 0;
+
+/// Registers an org supervised by `caller` with `caller` and `n - 1` others as members
+fn org<T: Trait>(
+    caller: &T::AccountId,
+    n: u32,
+) -> Result<T::OrgId, DispatchError> {
+    let mut members = (1..n)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    members.push(caller.clone());
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(caller.clone()),
+        T::IpfsReference::default(),
+    )
+}
+
+/// Funds `caller` and opens a bank account for a new org of `n` members
+/// controlled by `caller`
+fn bank<T: Trait>(
+    caller: &T::AccountId,
+    n: u32,
+) -> Result<T::BankId, DispatchError> {
+    let deposit = T::MinDeposit::get();
+    <T as Trait>::Currency::make_free_balance_be(caller, deposit + deposit);
+    let org = org::<T>(caller, n)?;
+    <Module<T>>::open_bank_account(
+        caller.clone(),
+        org,
+        deposit,
+        Some(caller.clone()),
+    )
+}
+
+/// Opens a bank account for an org of `n` members and proposes a spend from it
+fn spend<T: Trait>(
+    caller: &T::AccountId,
+    n: u32,
+) -> Result<BankSpend<T::BankId, T::SpendId>, DispatchError> {
+    let bank_id = bank::<T>(caller, n)?;
+    <Module<T>>::propose_spend(bank_id, T::MinDeposit::get(), caller.clone())
+}
+
+benchmarks! {
+    _ { }
+
+    open_org_bank_account {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let deposit = T::MinDeposit::get();
+        <T as Trait>::Currency::make_free_balance_be(&caller, deposit + deposit);
+        let org = org::<T>(&caller, 1)?;
+    }: _(RawOrigin::Signed(caller.clone()), org, deposit, Some(caller))
+    verify {
+        assert_eq!(<OrgTreasuryCount<T>>::get(org), 1);
+    }
+
+    member_proposes_spend {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let bank_id = bank::<T>(&caller, 1)?;
+    }: _(RawOrigin::Signed(caller.clone()), bank_id, T::MinDeposit::get(), caller)
+
+    member_triggers_vote_on_spend_proposal {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let id = spend::<T>(&caller, m)?;
+    }: _(RawOrigin::Signed(caller), id.bank, id.spend)
+
+    member_sudo_approves_spend_proposal {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let id = spend::<T>(&caller, 1)?;
+    }: _(RawOrigin::Signed(caller), id.bank, id.spend)
+
+    member_polls_spend_proposal {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let id = spend::<T>(&caller, 1)?;
+        <Module<T>>::trigger_vote_on_spend_proposal(id)?;
+    }: _(RawOrigin::Signed(caller), id.bank, id.spend)
+
+    close_org_bank_account {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let bank_id = bank::<T>(&caller, m)?;
+    }: _(RawOrigin::Signed(caller), bank_id)
+    verify {
+        assert!(!<Module<T>>::is_bank(bank_id));
+    }
+}
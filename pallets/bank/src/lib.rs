@@ -7,6 +7,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -21,6 +24,7 @@ use frame_support::{
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -112,6 +116,39 @@ pub trait Trait:
 
     /// The minimum amount to open an organizational bank account and keep it open
     type MinDeposit: Get<BalanceOf<Self>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn open_org_bank_account() -> Weight;
+    fn member_proposes_spend() -> Weight;
+    fn member_triggers_vote_on_spend_proposal(m: u32) -> Weight;
+    fn member_sudo_approves_spend_proposal() -> Weight;
+    fn member_polls_spend_proposal() -> Weight;
+    fn close_org_bank_account(m: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn open_org_bank_account() -> Weight {
+        1_000_000_000
+    }
+    fn member_proposes_spend() -> Weight {
+        1_000_000_000
+    }
+    fn member_triggers_vote_on_spend_proposal(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn member_sudo_approves_spend_proposal() -> Weight {
+        1_000_000_000
+    }
+    fn member_polls_spend_proposal() -> Weight {
+        1_000_000_000
+    }
+    fn close_org_bank_account(_m: u32) -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -201,7 +238,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::open_org_bank_account()]
         fn open_org_bank_account(
             origin,
             org: T::OrgId,
@@ -215,7 +252,7 @@ decl_module! {
             Self::deposit_event(RawEvent::BankAccountOpened(opener, bank_id, deposit, org, controller));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::member_proposes_spend()]
         fn member_proposes_spend(
             origin,
             bank_id: T::BankId,
@@ -229,7 +266,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendProposedByMember(caller, bank_id, new_spend_id.spend, amount, dest));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::member_triggers_vote_on_spend_proposal(Self::bank_member_count(*bank_id))]
         fn member_triggers_vote_on_spend_proposal(
             origin,
             bank_id: T::BankId,
@@ -243,7 +280,7 @@ decl_module! {
             Self::deposit_event(RawEvent::VoteTriggeredOnSpendProposal(caller, bank_id, spend_id, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::member_sudo_approves_spend_proposal()]
         fn member_sudo_approves_spend_proposal(
             origin,
             bank_id: T::BankId,
@@ -257,7 +294,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SudoApprovedSpendProposal(caller, bank_id, spend_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::member_polls_spend_proposal()]
         fn member_polls_spend_proposal(
             origin,
             bank_id: T::BankId,
@@ -271,7 +308,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendProposalPolled(caller, bank_id, spend_id, state));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::close_org_bank_account(Self::bank_member_count(*bank_id))]
         fn close_org_bank_account(
             origin,
             bank_id: T::BankId,
@@ -312,6 +349,12 @@ impl<T: Trait> Module<T> {
     pub fn is_bank(id: T::BankId) -> bool {
         <BankStores<T>>::get(id).is_some()
     }
+    /// Number of members of the org of the bank, 0 if the bank does not exist
+    pub fn bank_member_count(id: T::BankId) -> u32 {
+        <BankStores<T>>::get(id)
+            .map(|bank| <org::Module<T>>::member_count(bank.org()))
+            .unwrap_or_default()
+    }
     pub fn is_spend(bank: T::BankId, spend: T::SpendId) -> bool {
         <SpendProposals<T>>::get(bank, spend).is_some()
     }
@@ -11,8 +11,12 @@ use frame_system::{self as system,};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::IdentityLookup,
+    traits::{
+        ConvertInto,
+        IdentityLookup,
+    },
     Perbill,
+    Permill,
 };
 use util::{
     organization::Organization,
@@ -89,20 +93,36 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: u64 = 10;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32;
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = vote::Module<Test>;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = ();
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type WeightInfo = ();
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
+    type WeightInfo = ();
 }
 parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
@@ -117,6 +137,7 @@ impl Trait for Test {
     type SpendId = u64;
     type MaxTreasuryPerOrg = MaxTreasuryPerOrg;
     type MinDeposit = MinDeposit;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
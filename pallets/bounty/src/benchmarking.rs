@@ -0,0 +1,278 @@
+//! Bounty pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_support::traits::{
+    Currency,
+    OnInitialize,
+};
+use frame_system::RawOrigin;
+use org::MAX_MEMBERS;
+use sp_std::prelude::*;
+use util::{
+    meta::VoteCall,
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::Threshold,
+};
+
+const SEED: u32 = 0;
+const MAX_ASSIGNEES: u32 = 100;
+const MAX_SUBMISSIONS: u32 = 100;
+const MAX_EXPIRIES: u32 = 100;
+
+/// Governance with `sudo` as the sudo and a flat review committee of `n`
+/// members as the vote, which expires so it may also serve as arbitration
+fn governance<T: Trait>(
+    sudo: &T::AccountId,
+    n: u32,
+) -> Result<GovernanceOf<T>, DispatchError> {
+    let members = (0..n)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    let org = <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        None,
+        T::IpfsReference::default(),
+    )?;
+    Ok(ResolutionMetadata::new(
+        Some(sudo.clone()),
+        Some(VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(org),
+            Threshold::new(1u32.into(), None),
+            Some(100u32.into()),
+        ))),
+    )
+    .expect("sudo and vote are both set; qed"))
+}
+
+/// Funds `depositer` and posts a bounty worth twice the minimum deposit, governed
+/// by a committee of `n` members, submitters may dispute it in a court of the same committee
+fn bounty<T: Trait>(
+    depositer: &T::AccountId,
+    n: u32,
+) -> Result<T::BountyId, DispatchError> {
+    let amount = T::MinDeposit::get() + T::MinDeposit::get();
+    <T as Trait>::Currency::deposit(
+        T::NativeAsset::get(),
+        depositer,
+        amount + amount,
+    )?;
+    let gov = governance::<T>(depositer, n)?;
+    let arbitration = gov.vote();
+    <Module<T>>::post_bounty(
+        RawOrigin::Signed(depositer.clone()).into(),
+        T::IpfsReference::default(),
+        amount,
+        BountyOptions {
+            governance: Some(gov),
+            arbitration,
+            ..Default::default()
+        },
+    )?;
+    Ok(<BountyNonce<T>>::get())
+}
+
+/// Funds `submitter` with enough to reserve the submission deposit
+fn fund_submitter<T: Trait>(submitter: &T::AccountId) {
+    let deposit = T::SubmissionDeposit::get() + T::MinDeposit::get();
+    T::DepositCurrency::make_free_balance_be(submitter, deposit + deposit);
+}
+
+/// Posts a bounty reviewed by `n` members and a submission for the minimum
+/// deposit from `submitter`
+fn submission<T: Trait>(
+    depositer: &T::AccountId,
+    submitter: &T::AccountId,
+    n: u32,
+) -> Result<T::SubmissionId, DispatchError> {
+    let bounty_id = bounty::<T>(depositer, n)?;
+    fund_submitter::<T>(submitter);
+    <Module<T>>::submit_for_bounty(
+        RawOrigin::Signed(submitter.clone()).into(),
+        bounty_id,
+        T::IpfsReference::default(),
+        T::MinDeposit::get(),
+        None,
+    )?;
+    Ok(<SubmissionNonce<T>>::get())
+}
+
+benchmarks! {
+    _ { }
+
+    post_bounty {
+        let m in 1 .. T::MaxMilestones::get();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinDeposit::get();
+        <T as Trait>::Currency::deposit(T::NativeAsset::get(), &caller, amount + amount)?;
+        let gov = governance::<T>(&caller, 1)?;
+        let options = BountyOptions {
+            governance: Some(gov),
+            milestones: Some(vec![1u32.into(); m as usize]),
+            ..Default::default()
+        };
+    }: _(RawOrigin::Signed(caller), T::IpfsReference::default(), amount, options)
+    verify {
+        assert!(<Bounties<T>>::get(<BountyNonce<T>>::get()).is_some());
+    }
+
+    contribute_to_bounty {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(&depositer, 1)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinContribution::get();
+        <T as Trait>::Currency::deposit(T::NativeAsset::get(), &caller, amount + amount)?;
+    }: _(RawOrigin::Signed(caller.clone()), bounty_id, amount)
+    verify {
+        assert!(<BountyTips<T>>::get(bounty_id, &caller).is_some());
+    }
+
+    withdraw_contribution {
+        let s in 0 .. MAX_SUBMISSIONS;
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(&depositer, 1)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinContribution::get();
+        // also funds the submissions pending review, which the withdrawal may not underfund
+        let contribution = amount + s.into();
+        <T as Trait>::Currency::deposit(T::NativeAsset::get(), &caller, contribution + contribution)?;
+        <Module<T>>::contribute_to_bounty(
+            RawOrigin::Signed(caller.clone()).into(),
+            bounty_id,
+            contribution,
+        )?;
+        for i in 0..s {
+            let submitter: T::AccountId = account("submitter", i, SEED);
+            fund_submitter::<T>(&submitter);
+            <Module<T>>::submit_for_bounty(
+                RawOrigin::Signed(submitter).into(),
+                bounty_id,
+                T::IpfsReference::default(),
+                1u32.into(),
+                None,
+            )?;
+        }
+    }: _(RawOrigin::Signed(caller.clone()), bounty_id, amount)
+    verify {
+        assert_eq!(<BountyTips<T>>::get(bounty_id, &caller).unwrap_or_default(), s.into());
+    }
+
+    submit_for_bounty {
+        let m in 1 .. T::MaxMilestones::get();
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        // the submission requests the total of `m` milestones of 1 each
+        let amount = T::MinDeposit::get() + m.into();
+        <T as Trait>::Currency::deposit(T::NativeAsset::get(), &depositer, amount + amount)?;
+        <Module<T>>::post_bounty(
+            RawOrigin::Signed(depositer).into(),
+            T::IpfsReference::default(),
+            amount,
+            BountyOptions {
+                milestones: Some(vec![1u32.into(); m as usize]),
+                ..Default::default()
+            },
+        )?;
+        let bounty_id = <BountyNonce<T>>::get();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        fund_submitter::<T>(&caller);
+    }: _(RawOrigin::Signed(caller), bounty_id, T::IpfsReference::default(), m.into(), None)
+
+    approve_bounty_submission {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let submitter: T::AccountId = account("submitter", 0, SEED);
+        let submission_id = submission::<T>(&caller, &submitter, 1)?;
+    }: _(RawOrigin::Signed(caller), submission_id)
+    verify {
+        assert!(<Submissions<T>>::get(submission_id).is_none());
+    }
+
+    trigger_submission_review {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let submitter: T::AccountId = account("submitter", 0, SEED);
+        let submission_id = submission::<T>(&caller, &submitter, m)?;
+    }: _(RawOrigin::Signed(caller), submission_id)
+
+    reject_bounty_submission {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let submitter: T::AccountId = account("submitter", 0, SEED);
+        let submission_id = submission::<T>(&caller, &submitter, 1)?;
+    }: _(RawOrigin::Signed(caller), submission_id, Some(T::IpfsReference::default()))
+
+    withdraw_submission {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let submission_id = submission::<T>(&depositer, &caller, 1)?;
+    }: _(RawOrigin::Signed(caller), submission_id)
+
+    dispute_submission {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let submission_id = submission::<T>(&depositer, &caller, 1)?;
+    }: _(RawOrigin::Signed(caller), submission_id)
+    verify {
+        assert!(<SubmissionDisputes<T>>::get(submission_id).is_some());
+    }
+
+    apply_to_work {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(&depositer, 1)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+    }: _(RawOrigin::Signed(caller.clone()), bounty_id, T::IpfsReference::default())
+    verify {
+        assert!(<Applications<T>>::get(bounty_id, &caller).is_some());
+    }
+
+    assign {
+        let m in 1 .. MAX_ASSIGNEES;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let bounty_id = bounty::<T>(&caller, 1)?;
+        let assignees = (0..m)
+            .map(|i| account("applicant", i, SEED))
+            .collect::<Vec<T::AccountId>>();
+        for a in assignees.iter() {
+            <Module<T>>::apply_to_work(
+                RawOrigin::Signed(a.clone()).into(),
+                bounty_id,
+                T::IpfsReference::default(),
+            )?;
+        }
+        let deadline = <frame_system::Module<T>>::block_number() + 10u32.into();
+    }: _(RawOrigin::Signed(caller), bounty_id, assignees, deadline)
+    verify {
+        assert!(<AssignmentDeadlines<T>>::get(bounty_id).is_some());
+    }
+
+    on_initialize {
+        let e in 0 .. MAX_EXPIRIES;
+        let r in 1 .. T::MaxRefundsPerBlock::get();
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        // a bounty already queued for refunds with `r` contributors, the depositer included
+        let refunded = bounty::<T>(&depositer, 1)?;
+        for i in 1..r {
+            let contributor: T::AccountId = account("contributor", i, SEED);
+            let amount = T::MinContribution::get();
+            <T as Trait>::Currency::deposit(T::NativeAsset::get(), &contributor, amount + amount)?;
+            <Module<T>>::contribute_to_bounty(
+                RawOrigin::Signed(contributor).into(),
+                refunded,
+                amount,
+            )?;
+        }
+        <ExpiryRefunds<T>>::insert(refunded, (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()));
+        <RefundQueue<T>>::append(refunded);
+        let n = <frame_system::Module<T>>::block_number() + 1u32.into();
+        for i in 0..e {
+            let depositer: T::AccountId = account("expiring", i, SEED);
+            let id = bounty::<T>(&depositer, 1)?;
+            <BountyExpiries<T>>::append(n, id);
+        }
+    }: { <Module<T> as OnInitialize<T::BlockNumber>>::on_initialize(n); }
+    verify {
+        assert!(<BountyTips<T>>::iter_prefix(refunded).next().is_none());
+    }
+}
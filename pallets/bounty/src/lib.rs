@@ -7,22 +7,32 @@
 #[cfg(test)]
 mod tests;
 
-use codec::Codec;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migration;
+
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use frame_support::{
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
-    storage::IterableStorageMap,
+    storage::{
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+    },
     traits::{
-        Currency,
-        ExistenceRequirement,
+        BalanceStatus,
         Get,
         ReservableCurrency,
-        WithdrawReason,
-        WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -32,48 +42,137 @@ use sp_runtime::{
         AtLeast32Bit,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
+    DispatchError,
     DispatchResult,
     ModuleId,
+    Perbill,
+    Permill,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
     prelude::*,
 };
-use util::bounty::{
-    BountyInformation,
-    BountySubmission,
-    SubmissionState,
+use util::{
+    bounty::{
+        BountyInformation,
+        BountyOptions,
+        BountySubmission,
+        SubmissionState,
+    },
+    court::DisputeState,
+    grant::Recipient,
+    meta::{
+        ResolutionMetadata,
+        VoteMetadata,
+    },
+    organization::OrgRep,
+    traits::{
+        CheckVoteExpiry,
+        GetReputation,
+        GetVoteOutcome,
+        GroupMembership,
+        MultiCurrency,
+        OpenVote,
+        RecordBountyOutcome,
+        RegisterDisputeType,
+        ResolveDispute,
+    },
+    vote::VoteOutcome,
 };
 
 // type aliases
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+pub type BalanceOf<T> = donate::BalanceOf<T>;
+pub type ArbitrationOf<T> = VoteMetadata<
+    OrgRep<<T as org::Trait>::OrgId>,
+    <T as vote::Trait>::Signal,
+    Permill,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+pub type GovernanceOf<T> = ResolutionMetadata<
     <T as frame_system::Trait>::AccountId,
->>::Balance;
-type Bounty<T> = BountyInformation<
-    <T as Trait>::IpfsReference,
+    VoteMetadata<
+        OrgRep<<T as org::Trait>::OrgId>,
+        <T as vote::Trait>::Signal,
+        Permill,
+        <T as frame_system::Trait>::BlockNumber,
+    >,
+>;
+pub type RecipientOf<T> = Recipient<
     <T as frame_system::Trait>::AccountId,
+    OrgRep<<T as org::Trait>::OrgId>,
+>;
+pub type BountyOptionsOf<T> = BountyOptions<
+    <T as Trait>::AssetId,
+    <T as frame_system::Trait>::BlockNumber,
+    GovernanceOf<T>,
+    ArbitrationOf<T>,
     BalanceOf<T>,
+    <T as org::Trait>::OrgId,
 >;
-type BountySub<T> = BountySubmission<
+pub type Bounty<T> = BountyInformation<
+    <T as org::Trait>::IpfsReference,
+    <T as frame_system::Trait>::AccountId,
+    GovernanceOf<T>,
+    <T as Trait>::AssetId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+pub type BountySub<T> = BountySubmission<
     <T as Trait>::BountyId,
-    <T as Trait>::IpfsReference,
+    <T as org::Trait>::IpfsReference,
     <T as frame_system::Trait>::AccountId,
     BalanceOf<T>,
-    SubmissionState,
+    SubmissionState<<T as vote::Trait>::VoteId>,
 >;
 
-pub trait Trait: frame_system::Trait {
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+/// Storage layout of the pallet, chains started before the bounty2
+/// migration are at `V0`
+pub enum Releases {
+    V0,
+    V1,
+}
+
+impl Default for Releases {
+    fn default() -> Releases {
+        Releases::V0
+    }
+}
+
+pub trait Trait:
+    frame_system::Trait + org::Trait + vote::Trait + donate::Trait
+{
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
-    /// Cid type
-    type IpfsReference: Parameter + Member + Default;
+    /// The asset identifier
+    type AssetId: Parameter
+        + Member
+        + Copy
+        + Default
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// The multi asset currency, each bounty is funded and paid out in one asset,
+    /// its balance is that of the donate currency which splits team payouts
+    type Currency: MultiCurrency<
+        Self::AccountId,
+        CurrencyId = Self::AssetId,
+        Balance = BalanceOf<Self>,
+    >;
+
+    /// The asset used for bounties posted without an asset
+    type NativeAsset: Get<Self::AssetId>;
 
-    /// The currency type
-    type Currency: Currency<Self::AccountId>
-        + ReservableCurrency<Self::AccountId>;
+    /// The currency of `NativeAsset` in which submission deposits are reserved
+    type DepositCurrency: ReservableCurrency<
+        Self::AccountId,
+        Balance = BalanceOf<Self>,
+    >;
 
     /// The bounty post identifier
     type BountyId: Parameter
@@ -109,15 +208,129 @@ pub trait Trait: frame_system::Trait {
 
     /// Minimum contribution to posted bounty
     type MinContribution: Get<BalanceOf<Self>>;
+
+    /// Deposit reserved from submitters, slashed if the submission is rejected
+    type SubmissionDeposit: Get<BalanceOf<Self>>;
+
+    /// Blocks after the last payout, including milestone payouts, from which
+    /// contributors may withdraw again
+    type InactivityWindow: Get<Self::BlockNumber>;
+
+    /// Times the payment of an approved submission is retried before it
+    /// awaits review again
+    type MaxPaymentRetries: Get<u32>;
+
+    /// Maximum number of contributors refunded and submissions removed per
+    /// block once bounties expire
+    type MaxRefundsPerBlock: Get<u32>;
+
+    /// Maximum number of milestones of a bounty
+    type MaxMilestones: Get<u32>;
+
+    /// Maximum number of tags on a bounty
+    type MaxTags: Get<u32>;
+
+    /// Maximum length of a bounty tag in bytes
+    type MaxTagLength: Get<u32>;
+
+    /// The dispute identifier of `Court`
+    type DisputeId: Parameter
+        + Member
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// The court in which submitters dispute bounties posted with arbitration,
+    /// the disputed amount is locked in the bounty account until the court
+    /// releases it to be paid out like an approved submission
+    type Court: RegisterDisputeType<
+            Self::AccountId,
+            BalanceOf<Self>,
+            ArbitrationOf<Self>,
+            Self::BlockNumber,
+            DisputeIdentifier = Self::DisputeId,
+        > + ResolveDispute<
+            Self::DisputeId,
+            Outcome = DisputeState<<Self as vote::Trait>::VoteId>,
+        >;
+
+    /// Blocks within which submitters must raise a registered dispute in
+    /// the court, after which the dispute lapses
+    type DisputeWindow: Get<Self::BlockNumber>;
+
+    /// The reputation ledger credited with bounty outcomes, bounties may
+    /// require a minimum score from submitters
+    type Reputation: RecordBountyOutcome<Self::AccountId, BalanceOf<Self>>
+        + GetReputation<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn post_bounty(m: u32) -> Weight;
+    fn contribute_to_bounty() -> Weight;
+    fn submit_for_bounty(m: u32) -> Weight;
+    fn approve_bounty_submission() -> Weight;
+    fn trigger_submission_review(m: u32) -> Weight;
+    fn reject_bounty_submission() -> Weight;
+    fn withdraw_submission() -> Weight;
+    fn dispute_submission() -> Weight;
+    fn apply_to_work() -> Weight;
+    fn assign(m: u32) -> Weight;
+    fn withdraw_contribution(s: u32) -> Weight;
+    fn on_initialize(e: u32, r: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn post_bounty(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn contribute_to_bounty() -> Weight {
+        1_000_000_000
+    }
+    fn submit_for_bounty(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn approve_bounty_submission() -> Weight {
+        1_000_000_000
+    }
+    fn trigger_submission_review(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn reject_bounty_submission() -> Weight {
+        1_000_000_000
+    }
+    fn withdraw_submission() -> Weight {
+        1_000_000_000
+    }
+    fn dispute_submission() -> Weight {
+        1_000_000_000
+    }
+    fn apply_to_work() -> Weight {
+        1_000_000_000
+    }
+    fn assign(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn withdraw_contribution(_s: u32) -> Weight {
+        1_000_000_000
+    }
+    fn on_initialize(_e: u32, _r: u32) -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
     pub enum Event<T>
     where
         <T as frame_system::Trait>::AccountId,
-        <T as Trait>::IpfsReference,
+        <T as org::Trait>::IpfsReference,
+        <T as vote::Trait>::VoteId,
         <T as Trait>::BountyId,
         <T as Trait>::SubmissionId,
+        <T as Trait>::DisputeId,
+        <T as frame_system::Trait>::BlockNumber,
         Balance = BalanceOf<T>,
     {
         /// Poster, Initial Amount, Identifier, Bounty Metadata (i.e. github issue reference)
@@ -128,6 +341,36 @@ decl_event!(
         BountySubmissionPosted(AccountId, BountyId, Balance, SubmissionId, IpfsReference, IpfsReference),
         /// Bounty Identifier, Full Amount Left After Payment, Submission Identifier, Amount Requested, Bounty Metadata, Submission Metadata
         BountyPaymentExecuted(BountyId, Balance, SubmissionId, Balance, AccountId, IpfsReference, IpfsReference),
+        /// Bounty Identifier, Amount Refunded To Contributors, Bounty Metadata
+        BountyExpiredAndRefunded(BountyId, Balance, IpfsReference),
+        /// Bounty Identifier, Submission Identifier, Submitter, Rejection Reason
+        BountySubmissionRejected(BountyId, SubmissionId, AccountId, Option<IpfsReference>),
+        /// Bounty Identifier, Submission Identifier, Submitter
+        BountySubmissionWithdrawn(BountyId, SubmissionId, AccountId),
+        /// Bounty Identifier, Submission Identifier, Vote Identifier
+        BountySubmissionReviewTriggered(BountyId, SubmissionId, VoteId),
+        /// Bounty Identifier, Submission Identifier, Submitter, Dispute Identifier
+        BountySubmissionDisputed(BountyId, SubmissionId, AccountId, DisputeId),
+        /// Bounty Identifier, Applicant, Work Plan
+        BountyWorkApplied(BountyId, AccountId, IpfsReference),
+        /// Bounty Identifier, Assignees, Work Deadline
+        BountyAssigned(BountyId, Vec<AccountId>, BlockNumber),
+        /// Bounty Identifier
+        BountyReopened(BountyId),
+        /// Bounty Identifier, Submission Identifier, Transfer To Each Team Member And The Remainder To The Recipient
+        BountyTeamPaymentExecuted(BountyId, SubmissionId, Vec<(AccountId, Balance)>),
+        /// Bounty Identifier, Contributor, Amount Withdrawn, Full Amount After Withdrawal
+        ContributionWithdrawn(BountyId, AccountId, Balance, Balance),
+        /// Bounty Identifier, Submission Identifier, Milestone Index, Amount Paid, Milestone Balance Remaining For Submission
+        BountyMilestonePaid(BountyId, SubmissionId, u32, Balance, Balance),
+        /// Bounty Identifier, Submission Identifier, Submitter
+        SubmissionAutoApproved(BountyId, SubmissionId, AccountId),
+        /// Bounty Identifier, Submission Identifier, Vote Identifier
+        BountySubmissionReviewExpired(BountyId, SubmissionId, VoteId),
+        /// Bounty Identifier, Submission Identifier, Amount Approved
+        SubmissionPaymentRetriesExhausted(BountyId, SubmissionId, Balance),
+        /// Bounty Identifier, Submission Identifier, Dispute Identifier
+        BountySubmissionDisputeLapsed(BountyId, SubmissionId, DisputeId),
     }
 );
 
@@ -137,12 +380,48 @@ decl_error! {
         BountyDNE,
         SubmissionDNE,
         BountyPostMustExceedMinDeposit,
+        BountyExpiryMustBeInTheFuture,
         ContributionMustExceedModuleMin,
         DepositerCannotSubmitForBounty,
         BountySubmissionExceedsTotalAvailableFunding,
         SubmissionNotInValidStateToApprove,
         CannotApproveSubmissionIfAmountExceedsTotalAvailable,
         NotAuthorizedToApproveBountySubmissions,
+        SubmissionNotInValidStateToReject,
+        NotAuthorizedToRejectBountySubmissions,
+        SubmissionNotInValidStateToWithdraw,
+        NotAuthorizedToWithdrawSubmission,
+        SubmissionNotInValidStateToTriggerReview,
+        NotAuthorizedToTriggerSubmissionReview,
+        ArbitrationRequiresNativeAsset,
+        ArbitrationRequiresVoteDuration,
+        BountyHasNoArbitration,
+        NotAuthorizedToDisputeSubmission,
+        SubmissionNotInValidStateToDispute,
+        SubmissionUnderDispute,
+        DepositerCannotApplyToWork,
+        NotAuthorizedToAssignBounty,
+        BountyAlreadyAssigned,
+        MustAssignAtLeastOneApplicant,
+        AssigneeMustHaveApplied,
+        AssignmentDeadlineMustBeInTheFuture,
+        BountyAssignedToOtherHunters,
+        BountyMilestonesMustBeNonEmpty,
+        TooManyBountyMilestones,
+        MilestoneAmountMustBeNonZero,
+        SubmissionMustRequestMilestoneTotal,
+        NotEligibleToSubmit,
+        TeamPayoutRequiresNativeAsset,
+        NotAContributor,
+        WithdrawalExceedsContribution,
+        CannotWithdrawContributionUntilInactivityWindowPasses,
+        WithdrawalWouldUnderfundPendingSubmissions,
+        ReputationBelowBountyMinimum,
+        ReviewWindowMustBeNonZero,
+        TooManyBountyTags,
+        BountyTagTooLong,
+        BountyTagMustBeNonEmpty,
+        BountyHasExpired,
     }
 }
 
@@ -161,10 +440,111 @@ decl_storage! {
         pub BountyTips get(fn bounty_tips): double_map
             hasher(blake2_128_concat) T::BountyId,
             hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+        /// Sum of the tips for each bounty
+        pub TotalTipped get(fn total_tipped): map
+            hasher(blake2_128_concat) T::BountyId => BalanceOf<T>;
+
+        /// Block of the last payout from each bounty
+        pub LastPayout get(fn last_payout): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::BlockNumber>;
+
+        /// Bounties scheduled to expire at the given block
+        pub BountyExpiries get(fn bounty_expiries): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::BountyId>;
+        /// Expired bounties whose contributors are refunded over the next blocks
+        pub RefundQueue get(fn refund_queue): Vec<T::BountyId>;
+        /// Amounts distributed to and received by the contributors of expired
+        /// bounties so far, the depositer receives whatever was not received
+        pub ExpiryRefunds get(fn expiry_refunds): map
+            hasher(blake2_128_concat) T::BountyId => Option<(BalanceOf<T>, BalanceOf<T>)>;
 
         /// Posted Submissions
         pub Submissions get(fn submissions): map
             hasher(blake2_128_concat) T::SubmissionId => Option<BountySub<T>>;
+        /// Recipients named by submissions paid to another account or split across an org
+        pub SubmissionRecipients get(fn submission_recipients): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<RecipientOf<T>>;
+        /// Index of the submissions for each bounty
+        pub BountySubmissions get(fn bounty_submissions): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::SubmissionId => Option<()>;
+        /// Number of submissions in the index for each bounty
+        pub SubmissionCount get(fn submission_count): map
+            hasher(blake2_128_concat) T::BountyId => u32;
+
+        /// Arbitration path of bounties posted with one
+        pub BountyArbitration get(fn bounty_arbitration): map
+            hasher(blake2_128_concat) T::BountyId => Option<ArbitrationOf<T>>;
+        /// Court disputes raised by submitters
+        pub SubmissionDisputes get(fn submission_disputes): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::DisputeId>;
+        /// Number of unresolved disputes raised by each bounty's submitters
+        pub DisputeCount get(fn dispute_count): map
+            hasher(blake2_128_concat) T::BountyId => u32;
+        /// Disputed submissions to poll at the given block
+        pub DisputeQueue get(fn dispute_queue): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::SubmissionId>;
+
+        /// Org whose members are the only ones eligible to work on the bounty
+        pub BountyEligibility get(fn bounty_eligibility): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::OrgId>;
+
+        /// Minimum reputation score required to submit for the bounty
+        pub BountyMinReputation get(fn bounty_min_reputation): map
+            hasher(blake2_128_concat) T::BountyId => Option<u32>;
+
+        /// Tags of tagged bounties, sorted and without duplicates
+        pub BountyTags get(fn bounty_tags): map
+            hasher(blake2_128_concat) T::BountyId => Vec<Vec<u8>>;
+        /// Index of the bounties carrying each tag
+        pub TaggedBounties get(fn tagged_bounties): double_map
+            hasher(blake2_128_concat) Vec<u8>,
+            hasher(blake2_128_concat) T::BountyId => Option<()>;
+
+        /// Ordered milestone amounts of bounties paid out milestone by milestone
+        pub BountyMilestones get(fn bounty_milestones): map
+            hasher(blake2_128_concat) T::BountyId => Option<Vec<BalanceOf<T>>>;
+        /// Number of milestones already paid for each submission
+        pub MilestonesPaid get(fn milestones_paid): map
+            hasher(blake2_128_concat) T::SubmissionId => u32;
+
+        /// Work plans of hunters applying to work on a bounty
+        pub Applications get(fn applications): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::IpfsReference>;
+        /// Hunters assigned to a bounty, only they may submit until the work deadline
+        pub Assignees get(fn assignees): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::AccountId => Option<()>;
+        /// Work deadline of assigned bounties
+        pub AssignmentDeadlines get(fn assignment_deadlines): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::BlockNumber>;
+        /// Assigned bounties which reopen at the given block
+        pub AssignmentExpiries get(fn assignment_expiries): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::BountyId>;
+
+        /// Blocks after submission within which the bounty's submissions must
+        /// be reviewed, they are approved automatically after that
+        pub BountyReviewWindows get(fn bounty_review_windows): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::BlockNumber>;
+        /// Review deadline of submissions to bounties with a review window
+        pub ReviewDeadlines get(fn review_deadlines): map
+            hasher(blake2_128_concat) T::SubmissionId => Option<T::BlockNumber>;
+        /// Submissions which are approved at the given block if still unreviewed
+        pub ReviewExpiries get(fn review_expiries): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::SubmissionId>;
+        /// Submissions under review by vote to poll at the given block
+        pub ReviewQueue get(fn review_queue): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::SubmissionId>;
+        /// Failed payments of approved submissions, retried when next polled
+        pub PaymentRetries get(fn payment_retries): map
+            hasher(blake2_128_concat) T::SubmissionId => u32;
+
+        /// Storage layout version, bumped by each migration
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| Releases::V1): Releases;
+
+        /// Frequency with which submissions under review are polled and updated
+        pub SubmissionPollFrequency get(fn submission_poll_frequency) config(): T::BlockNumber;
     }
 }
 
@@ -173,29 +553,119 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            // queue refunds for bounties which expire at this block
+            let freq = Self::submission_poll_frequency();
+            let expiring = <BountyExpiries<T>>::take(n);
+            let e = expiring.len() as u32;
+            expiring.into_iter().for_each(|id| {
+                if !<Bounties<T>>::contains_key(id) || <ExpiryRefunds<T>>::contains_key(id) {
+                    return
+                }
+                if <DisputeCount<T>>::get(id) > 0 {
+                    // funds locked by the court are settled before the refund, disputes
+                    // lapse after the `DisputeWindow` and court votes expire
+                    <BountyExpiries<T>>::append(n + freq.max(1u32.into()), id);
+                    return
+                }
+                <ExpiryRefunds<T>>::insert(id, (BalanceOf::<T>::zero(), BalanceOf::<T>::zero()));
+                <RefundQueue<T>>::append(id);
+            });
+            let r = Self::process_refunds(T::MaxRefundsPerBlock::get());
+            <T as Trait>::WeightInfo::on_initialize(e, r)
+        }
+
+        fn on_finalize(n: T::BlockNumber) {
+            // approve submissions whose review deadline lapses at this block
+            <ReviewExpiries<T>>::take(n).into_iter().for_each(|id| {
+                if <ReviewDeadlines<T>>::get(id) == Some(n) {
+                    <ReviewDeadlines<T>>::remove(id);
+                    Self::auto_approve(id);
+                }
+            });
+            // reopen assigned bounties whose work deadline lapses at this block
+            <AssignmentExpiries<T>>::take(n).into_iter().for_each(|id| {
+                if <AssignmentDeadlines<T>>::get(id) == Some(n) {
+                    Self::reopen_bounty(id);
+                }
+            });
+            // poll disputes and submissions under review queued for this block
+            Self::poll_disputes(n);
+            Self::poll_reviews(n);
+        }
+
+        #[weight = <T as Trait>::WeightInfo::post_bounty(options.milestones.as_ref().map_or(0, |m| m.len() as u32))]
         fn post_bounty(
             origin,
             info: T::IpfsReference,
             amount: BalanceOf<T>,
+            options: BountyOptionsOf<T>,
         ) -> DispatchResult {
             let depositer = ensure_signed(origin)?;
+            let BountyOptions {
+                asset,
+                expiry,
+                governance,
+                arbitration,
+                milestones,
+                eligible,
+                min_reputation,
+                review_window,
+                tags,
+            } = options;
             ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
-            let imb = T::Currency::withdraw(
-                &depositer,
-                amount,
-                WithdrawReasons::from(WithdrawReason::Transfer),
-                ExistenceRequirement::AllowDeath,
-            )?;
-            let bounty = Bounty::<T>::new(info.clone(), depositer.clone(), amount);
-            let id = Self::bounty_generate_uid();
-            T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
+            let tags = Self::check_tags(tags)?;
+            if let Some(w) = review_window {
+                ensure!(!w.is_zero(), Error::<T>::ReviewWindowMustBeNonZero);
+            }
+            if let Some(m) = &milestones {
+                ensure!(!m.is_empty(), Error::<T>::BountyMilestonesMustBeNonEmpty);
+                ensure!(m.len() as u32 <= T::MaxMilestones::get(), Error::<T>::TooManyBountyMilestones);
+                ensure!(m.iter().all(|a| !a.is_zero()), Error::<T>::MilestoneAmountMustBeNonZero);
+            }
+            if let Some(e) = expiry {
+                ensure!(e > <frame_system::Module<T>>::block_number(), Error::<T>::BountyExpiryMustBeInTheFuture);
+            }
+            let asset = asset.unwrap_or_else(T::NativeAsset::get);
+            if let Some(a) = &arbitration {
+                ensure!(asset == T::NativeAsset::get(), Error::<T>::ArbitrationRequiresNativeAsset);
+                // court votes must expire so that disputes are always resolved
+                ensure!(a.duration().is_some(), Error::<T>::ArbitrationRequiresVoteDuration);
+            }
+            let id = Self::next_bounty_id();
+            <T as Trait>::Currency::transfer(asset, &depositer, &Self::bounty_account_id(id), amount)?;
+            <BountyNonce<T>>::put(id);
+            let bounty = Bounty::<T>::new(info.clone(), depositer.clone(), governance, asset, amount, expiry);
             <Bounties<T>>::insert(id, bounty);
             <BountyTips<T>>::insert(id, &depositer, amount);
+            <TotalTipped<T>>::insert(id, amount);
+            if let Some(a) = arbitration {
+                <BountyArbitration<T>>::insert(id, a);
+            }
+            if let Some(m) = milestones {
+                <BountyMilestones<T>>::insert(id, m);
+            }
+            if let Some(org) = eligible {
+                <BountyEligibility<T>>::insert(id, org);
+            }
+            if let Some(r) = min_reputation {
+                <BountyMinReputation<T>>::insert(id, r);
+            }
+            if let Some(w) = review_window {
+                <BountyReviewWindows<T>>::insert(id, w);
+            }
+            if !tags.is_empty() {
+                tags.iter().for_each(|tag| <TaggedBounties<T>>::insert(tag, id, ()));
+                <BountyTags<T>>::insert(id, tags);
+            }
+            if let Some(e) = expiry {
+                <BountyExpiries<T>>::append(e, id);
+            }
+            T::Reputation::bounty_posted(&depositer);
             Self::deposit_event(RawEvent::BountyPosted(depositer, amount, id, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::contribute_to_bounty()]
         fn contribute_to_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -204,11 +674,12 @@ decl_module! {
             let contributor = ensure_signed(origin)?;
             ensure!(amount >= T::MinContribution::get(), Error::<T>::ContributionMustExceedModuleMin);
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
-            T::Currency::transfer(
+            ensure!(!<ExpiryRefunds<T>>::contains_key(bounty_id), Error::<T>::BountyHasExpired);
+            <T as Trait>::Currency::transfer(
+                bounty.asset(),
                 &contributor,
                 &Self::bounty_account_id(bounty_id),
                 amount,
-                ExistenceRequirement::KeepAlive,
             )?;
             let new_amount = if let Some(a) = <BountyTips<T>>::get(bounty_id, &contributor) {
                 amount + a
@@ -218,28 +689,100 @@ decl_module! {
             let new_bounty = bounty.add_total(amount);
             let total = new_bounty.total();
             <BountyTips<T>>::insert(bounty_id, &contributor, new_amount);
+            <TotalTipped<T>>::mutate(bounty_id, |t| *t += amount);
             <Bounties<T>>::insert(bounty_id, new_bounty);
             Self::deposit_event(RawEvent::BountyRaiseContribution(contributor, amount, bounty_id, total, bounty.info()));
             Ok(())
         }
-        #[weight = 0]
+        /// Withdraws `amount` of the caller's tip, paid out pro rata to the
+        /// remaining total so payouts and slashed deposits are shared by all
+        /// contributors
+        #[weight = <T as Trait>::WeightInfo::withdraw_contribution(<SubmissionCount<T>>::get(bounty_id))]
+        fn withdraw_contribution(
+            origin,
+            bounty_id: T::BountyId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let contributor = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(!<ExpiryRefunds<T>>::contains_key(bounty_id), Error::<T>::BountyHasExpired);
+            let tip = <BountyTips<T>>::get(bounty_id, &contributor).ok_or(Error::<T>::NotAContributor)?;
+            ensure!(amount <= tip, Error::<T>::WithdrawalExceedsContribution);
+            // contributions are locked once a submission or milestone is paid, until the bounty is inactive
+            if let Some(n) = <LastPayout<T>>::get(bounty_id) {
+                ensure!(
+                    <frame_system::Module<T>>::block_number() >= n + T::InactivityWindow::get(),
+                    Error::<T>::CannotWithdrawContributionUntilInactivityWindowPasses
+                );
+            }
+            let total_tipped = <TotalTipped<T>>::get(bounty_id);
+            let payout = Perbill::from_rational_approximation(amount, total_tipped).mul_floor(bounty.total());
+            ensure!(
+                bounty.total() >= Self::pending_amount(bounty_id) + payout,
+                Error::<T>::WithdrawalWouldUnderfundPendingSubmissions
+            );
+            <T as Trait>::Currency::transfer(
+                bounty.asset(),
+                &Self::bounty_account_id(bounty_id),
+                &contributor,
+                payout,
+            )?;
+            let new_tip = tip - amount;
+            if new_tip.is_zero() {
+                <BountyTips<T>>::remove(bounty_id, &contributor);
+            } else {
+                <BountyTips<T>>::insert(bounty_id, &contributor, new_tip);
+            }
+            <TotalTipped<T>>::insert(bounty_id, total_tipped - amount);
+            let new_bounty = bounty.subtract_total(payout);
+            let total = new_bounty.total();
+            <Bounties<T>>::insert(bounty_id, new_bounty);
+            Self::deposit_event(RawEvent::ContributionWithdrawn(bounty_id, contributor, payout, total));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::submit_for_bounty(Self::milestone_count(*bounty_id))]
         fn submit_for_bounty(
             origin,
             bounty_id: T::BountyId,
             submission_ref: T::IpfsReference,
             amount: BalanceOf<T>,
+            recipient: Option<RecipientOf<T>>,
         ) -> DispatchResult {
             let submitter = ensure_signed(origin)?;
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
             ensure!(submitter != bounty.depositer(), Error::<T>::DepositerCannotSubmitForBounty);
+            ensure!(!<ExpiryRefunds<T>>::contains_key(bounty_id), Error::<T>::BountyHasExpired);
+            ensure!(Self::is_eligible(bounty_id, &submitter), Error::<T>::NotEligibleToSubmit);
+            if let Some(r) = <BountyMinReputation<T>>::get(bounty_id) {
+                ensure!(T::Reputation::score(&submitter) >= r, Error::<T>::ReputationBelowBountyMinimum);
+            }
             ensure!(amount <= bounty.total(), Error::<T>::BountySubmissionExceedsTotalAvailableFunding);
-            let submission = BountySub::<T>::new(bounty_id, submission_ref.clone(), submitter.clone(), amount);
+            if let Some(m) = <BountyMilestones<T>>::get(bounty_id) {
+                let milestone_total = m.into_iter().fold(BalanceOf::<T>::zero(), |acc, a| acc + a);
+                ensure!(amount == milestone_total, Error::<T>::SubmissionMustRequestMilestoneTotal);
+            }
+            if <AssignmentDeadlines<T>>::contains_key(bounty_id) {
+                ensure!(<Assignees<T>>::contains_key(bounty_id, &submitter), Error::<T>::BountyAssignedToOtherHunters);
+            }
+            if recipient.as_ref().and_then(|r| r.org()).is_some() {
+                // donate splits payouts in the native currency only
+                ensure!(bounty.asset() == T::NativeAsset::get(), Error::<T>::TeamPayoutRequiresNativeAsset);
+            }
+            let deposit = T::SubmissionDeposit::get();
+            T::DepositCurrency::reserve(&submitter, deposit)?;
+            let submission = BountySub::<T>::new(bounty_id, submission_ref.clone(), submitter.clone(), amount, deposit);
             let id = Self::submission_generate_uid();
+            if let Some(r) = recipient {
+                <SubmissionRecipients<T>>::insert(id, r);
+            }
             <Submissions<T>>::insert(id, submission);
+            <BountySubmissions<T>>::insert(bounty_id, id, ());
+            <SubmissionCount<T>>::mutate(bounty_id, |n| *n = n.saturating_add(1));
+            Self::schedule_review(bounty_id, id);
             Self::deposit_event(RawEvent::BountySubmissionPosted(submitter, bounty_id, amount, id, bounty.info(), submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_bounty_submission()]
         fn approve_bounty_submission(
             origin,
             submission_id: T::SubmissionId,
@@ -247,23 +790,143 @@ decl_module! {
             let approver = ensure_signed(origin)?;
             let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
             ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
+            ensure!(!<SubmissionDisputes<T>>::contains_key(submission_id), Error::<T>::SubmissionUnderDispute);
+            let bounty = <Bounties<T>>::get(submission.bounty_id()).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &approver), Error::<T>::NotAuthorizedToApproveBountySubmissions);
+            Self::execute_payment(submission_id, submission, bounty)
+        }
+        #[weight = <T as Trait>::WeightInfo::trigger_submission_review(Self::review_member_count(*submission_id))]
+        fn trigger_submission_review(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let trigger_er = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToTriggerReview);
+            ensure!(!<SubmissionDisputes<T>>::contains_key(submission_id), Error::<T>::SubmissionUnderDispute);
             let bounty_id = submission.bounty_id();
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
-            ensure!(bounty.total() >= submission.amount(), Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
-            ensure!(bounty.depositer() == approver, Error::<T>::NotAuthorizedToApproveBountySubmissions);
-            // execute payment
-            T::Currency::transfer(
-                &Self::bounty_account_id(bounty_id),
-                &submission.submitter(),
+            let gov = bounty.gov().ok_or(Error::<T>::NotAuthorizedToTriggerSubmissionReview)?;
+            let auth = if let Some(v) = gov.vote() {
+                <org::Module<T>>::is_member_of_group(v.org().org(), &trigger_er) || gov.is_sudo(&trigger_er)
+            } else { false };
+            ensure!(auth, Error::<T>::NotAuthorizedToTriggerSubmissionReview);
+            let new_vote_id = match gov.vote().ok_or(Error::<T>::NotAuthorizedToTriggerSubmissionReview)? {
+                VoteMetadata::Signal(v) => <vote::Module<T>>::open_vote(Some(submission.submission()), v.org, v.threshold, v.duration)?,
+                VoteMetadata::Percentage(v) => <vote::Module<T>>::open_percent_vote(Some(submission.submission()), v.org, v.threshold, v.duration)?,
+            };
+            let new_submission = submission.set_state(SubmissionState::UnderReviewByAcceptanceCommittee(new_vote_id));
+            <Submissions<T>>::insert(submission_id, new_submission);
+            <ReviewQueue<T>>::append(Self::next_poll(<frame_system::Module<T>>::block_number()), submission_id);
+            Self::deposit_event(RawEvent::BountySubmissionReviewTriggered(bounty_id, submission_id, new_vote_id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::reject_bounty_submission()]
+        fn reject_bounty_submission(
+            origin,
+            submission_id: T::SubmissionId,
+            reason: Option<T::IpfsReference>,
+        ) -> DispatchResult {
+            let rejecter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToReject);
+            ensure!(!<SubmissionDisputes<T>>::contains_key(submission_id), Error::<T>::SubmissionUnderDispute);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &rejecter), Error::<T>::NotAuthorizedToRejectBountySubmissions);
+            Self::slash_deposit(bounty_id, bounty, &submission);
+            T::Reputation::submission_rejected(&submission.submitter());
+            // submission is kept in storage with its end state
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState::Rejected));
+            Self::deposit_event(RawEvent::BountySubmissionRejected(bounty_id, submission_id, submission.submitter(), reason));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::withdraw_submission()]
+        fn withdraw_submission(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let withdrawer = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.is_submitter(&withdrawer), Error::<T>::NotAuthorizedToWithdrawSubmission);
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToWithdraw);
+            ensure!(!<SubmissionDisputes<T>>::contains_key(submission_id), Error::<T>::SubmissionUnderDispute);
+            let bounty_id = submission.bounty_id();
+            T::DepositCurrency::unreserve(&withdrawer, submission.deposit());
+            // submission is kept in storage with its end state
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState::Withdrawn));
+            Self::deposit_event(RawEvent::BountySubmissionWithdrawn(bounty_id, submission_id, withdrawer));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::dispute_submission()]
+        fn dispute_submission(
+            origin,
+            submission_id: T::SubmissionId,
+        ) -> DispatchResult {
+            let submitter = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.is_submitter(&submitter), Error::<T>::NotAuthorizedToDisputeSubmission);
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToDispute);
+            ensure!(!<SubmissionDisputes<T>>::contains_key(submission_id), Error::<T>::SubmissionUnderDispute);
+            let bounty_id = submission.bounty_id();
+            ensure!(!<ExpiryRefunds<T>>::contains_key(bounty_id), Error::<T>::BountyHasExpired);
+            let arbitration = <BountyArbitration<T>>::get(bounty_id).ok_or(Error::<T>::BountyHasNoArbitration)?;
+            // the court locks the requested amount in the bounty account until the submitter's
+            // dispute is resolved, this pallet then pays it out like an approved submission
+            let now = <frame_system::Module<T>>::block_number();
+            let dispute_id = T::Court::register_dispute_type(
+                Self::bounty_account_id(bounty_id),
                 submission.amount(),
-                ExistenceRequirement::KeepAlive,
+                submitter.clone(),
+                arbitration,
+                Some(now + T::DisputeWindow::get()),
             )?;
-            let new_bounty = bounty.subtract_total(submission.amount());
-            let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
-            // submission approved and executed => can be removed
-            <Submissions<T>>::remove(submission_id);
-            <Bounties<T>>::insert(bounty_id, new_bounty);
-            Self::deposit_event(RawEvent::BountyPaymentExecuted(bounty_id, new_total, submission_id, submission.amount(), submission.submitter(), bounty_info, submission.submission()));
+            T::Court::resolve_by_locker(dispute_id);
+            <SubmissionDisputes<T>>::insert(submission_id, dispute_id);
+            <DisputeCount<T>>::mutate(bounty_id, |n| *n = n.saturating_add(1));
+            <DisputeQueue<T>>::append(Self::next_poll(now), submission_id);
+            Self::deposit_event(RawEvent::BountySubmissionDisputed(bounty_id, submission_id, submitter, dispute_id));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::apply_to_work()]
+        fn apply_to_work(
+            origin,
+            bounty_id: T::BountyId,
+            plan_ref: T::IpfsReference,
+        ) -> DispatchResult {
+            let applicant = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(applicant != bounty.depositer(), Error::<T>::DepositerCannotApplyToWork);
+            ensure!(Self::is_eligible(bounty_id, &applicant), Error::<T>::NotEligibleToSubmit);
+            // applying again replaces the previous work plan
+            <Applications<T>>::insert(bounty_id, &applicant, plan_ref.clone());
+            Self::deposit_event(RawEvent::BountyWorkApplied(bounty_id, applicant, plan_ref));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::assign(assignees.len() as u32)]
+        fn assign(
+            origin,
+            bounty_id: T::BountyId,
+            assignees: Vec<T::AccountId>,
+            deadline: T::BlockNumber,
+        ) -> DispatchResult {
+            let assigner = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(Self::is_reviewer(&bounty, &assigner), Error::<T>::NotAuthorizedToAssignBounty);
+            ensure!(!<AssignmentDeadlines<T>>::contains_key(bounty_id), Error::<T>::BountyAlreadyAssigned);
+            ensure!(!assignees.is_empty(), Error::<T>::MustAssignAtLeastOneApplicant);
+            ensure!(deadline > <frame_system::Module<T>>::block_number(), Error::<T>::AssignmentDeadlineMustBeInTheFuture);
+            ensure!(
+                assignees.iter().all(|a| <Applications<T>>::contains_key(bounty_id, a)),
+                Error::<T>::AssigneeMustHaveApplied
+            );
+            assignees.iter().for_each(|a| {
+                <Applications<T>>::remove(bounty_id, a);
+                <Assignees<T>>::insert(bounty_id, a, ());
+            });
+            <AssignmentDeadlines<T>>::insert(bounty_id, deadline);
+            <AssignmentExpiries<T>>::append(deadline, bounty_id);
+            Self::deposit_event(RawEvent::BountyAssigned(bounty_id, assignees, deadline));
             Ok(())
         }
     }
@@ -277,12 +940,13 @@ impl<T: Trait> Module<T> {
     fn bounty_id_is_available(id: T::BountyId) -> bool {
         <Bounties<T>>::get(id).is_none()
     }
-    fn bounty_generate_uid() -> T::BountyId {
+    /// Next available BountyId, which `post_bounty` stores as the nonce once
+    /// the bounty is funded
+    fn next_bounty_id() -> T::BountyId {
         let mut id_counter = <BountyNonce<T>>::get() + 1u32.into();
         while !Self::bounty_id_is_available(id_counter) {
             id_counter += 1u32.into();
         }
-        <BountyNonce<T>>::put(id_counter);
         id_counter
     }
     fn submission_id_is_available(id: T::SubmissionId) -> bool {
@@ -296,10 +960,679 @@ impl<T: Trait> Module<T> {
         <SubmissionNonce<T>>::put(id_counter);
         id_counter
     }
-    fn _recursive_remove_bounty(id: T::BountyId) {
+    /// Sorts and dedups the tags of a new bounty within the configured bounds
+    fn check_tags(
+        mut tags: Vec<Vec<u8>>,
+    ) -> Result<Vec<Vec<u8>>, DispatchError> {
+        tags.sort();
+        tags.dedup();
+        ensure!(
+            tags.len() as u32 <= T::MaxTags::get(),
+            Error::<T>::TooManyBountyTags
+        );
+        ensure!(
+            tags.iter().all(|t| !t.is_empty()),
+            Error::<T>::BountyTagMustBeNonEmpty
+        );
+        ensure!(
+            tags.iter()
+                .all(|t| t.len() as u32 <= T::MaxTagLength::get()),
+            Error::<T>::BountyTagTooLong
+        );
+        Ok(tags)
+    }
+    pub fn recursive_remove_bounty(id: T::BountyId) {
         <Bounties<T>>::remove(id);
-        <Submissions<T>>::iter()
-            .filter(|(_, app)| app.bounty_id() == id)
-            .for_each(|(app_id, _)| <Submissions<T>>::remove(app_id));
+        <BountyTips<T>>::remove_prefix(id);
+        <TotalTipped<T>>::remove(id);
+        <BountyArbitration<T>>::remove(id);
+        <BountyMilestones<T>>::remove(id);
+        <BountyEligibility<T>>::remove(id);
+        <BountyMinReputation<T>>::remove(id);
+        <BountyReviewWindows<T>>::remove(id);
+        <BountyTags<T>>::take(id)
+            .iter()
+            .for_each(|tag| <TaggedBounties<T>>::remove(tag, id));
+        <LastPayout<T>>::remove(id);
+        <Applications<T>>::remove_prefix(id);
+        <Assignees<T>>::remove_prefix(id);
+        <AssignmentDeadlines<T>>::remove(id);
+        <DisputeCount<T>>::remove(id);
+        <ExpiryRefunds<T>>::remove(id);
+        <BountySubmissions<T>>::iter_prefix(id)
+            .for_each(|(sub_id, _)| Self::remove_submission(sub_id));
+        <BountySubmissions<T>>::remove_prefix(id);
+        <SubmissionCount<T>>::remove(id);
+    }
+    /// Removes the submission, returning its deposit if still pending review,
+    /// without removing it from the index of its bounty
+    fn remove_submission(sub_id: T::SubmissionId) {
+        <MilestonesPaid<T>>::remove(sub_id);
+        <PaymentRetries<T>>::remove(sub_id);
+        <SubmissionRecipients<T>>::remove(sub_id);
+        <ReviewDeadlines<T>>::remove(sub_id);
+        if let Some(sub) = <Submissions<T>>::take(sub_id) {
+            // deposits of submissions still pending review are returned
+            let state = sub.state();
+            if state.awaiting_review() || state.under_review().is_some() {
+                T::DepositCurrency::unreserve(&sub.submitter(), sub.deposit());
+            }
+        }
+    }
+}
+
+// Query helpers
+impl<T: Trait> Module<T> {
+    /// Bounties with at least `min` remaining which carry every tag and which
+    /// `who` is eligible to work on, `limit` per `page`, only those open to
+    /// anyone if `who` is `None`
+    pub fn open_bounties(
+        min: BalanceOf<T>,
+        who: Option<T::AccountId>,
+        tags: Vec<Vec<u8>>,
+        page: u32,
+        limit: u32,
+    ) -> Vec<(T::BountyId, Bounty<T>)> {
+        let is_open = |(id, bounty): &(T::BountyId, Bounty<T>)| {
+            bounty.total() >= min
+                && !<ExpiryRefunds<T>>::contains_key(id)
+                && tags
+                    .iter()
+                    .all(|tag| <TaggedBounties<T>>::contains_key(tag, id))
+                && who.as_ref().map_or_else(
+                    || !<BountyEligibility<T>>::contains_key(id),
+                    |who| Self::is_eligible(*id, who),
+                )
+        };
+        let skip = page.saturating_mul(limit) as usize;
+        // tagged bounties are found through the index of their first tag
+        if let Some(tag) = tags.first() {
+            <TaggedBounties<T>>::iter_prefix(tag)
+                .filter_map(|(id, _)| <Bounties<T>>::get(id).map(|b| (id, b)))
+                .filter(&is_open)
+                .skip(skip)
+                .take(limit as usize)
+                .collect()
+        } else {
+            <Bounties<T>>::iter()
+                .filter(&is_open)
+                .skip(skip)
+                .take(limit as usize)
+                .collect()
+        }
+    }
+    /// Bounties carrying the tag
+    pub fn bounties_tagged(tag: Vec<u8>) -> Vec<T::BountyId> {
+        <TaggedBounties<T>>::iter_prefix(tag)
+            .map(|(id, _)| id)
+            .collect()
+    }
+    /// Submissions posted for the bounty, regardless of their state
+    pub fn submissions_for(
+        bounty_id: T::BountyId,
+    ) -> Vec<(T::SubmissionId, BountySub<T>)> {
+        <BountySubmissions<T>>::iter_prefix(bounty_id)
+            .filter_map(|(id, _)| <Submissions<T>>::get(id).map(|s| (id, s)))
+            .collect()
+    }
+    /// Sum of the amounts requested by submissions pending review
+    pub fn pending_amount(bounty_id: T::BountyId) -> BalanceOf<T> {
+        Self::submissions_for(bounty_id)
+            .into_iter()
+            .filter(|(_, s)| {
+                s.state().awaiting_review()
+                    || s.state().under_review().is_some()
+            })
+            .fold(BalanceOf::<T>::zero(), |acc, (_, s)| acc + s.amount())
+    }
+    /// Number of milestones of the bounty, 0 if it is paid out at once
+    pub fn milestone_count(bounty_id: T::BountyId) -> u32 {
+        <BountyMilestones<T>>::get(bounty_id)
+            .map(|m| m.len() as u32)
+            .unwrap_or_default()
+    }
+    /// Number of members of the committee reviewing the submission, 0 if its
+    /// bounty is not governed by a vote
+    pub fn review_member_count(submission_id: T::SubmissionId) -> u32 {
+        <Submissions<T>>::get(submission_id)
+            .and_then(|s| <Bounties<T>>::get(s.bounty_id()))
+            .and_then(|b| b.gov())
+            .and_then(|gov| gov.vote())
+            .map(|v| <org::Module<T>>::member_count(v.org().org()))
+            .unwrap_or_default()
+    }
+    /// Total contribution of every contributor to the bounty
+    pub fn contributions(
+        bounty_id: T::BountyId,
+    ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+        <BountyTips<T>>::iter_prefix(bounty_id).collect()
+    }
+}
+
+// Review helpers
+impl<T: Trait> Module<T> {
+    /// First block after `now` at which submissions are polled, the next
+    /// block if the poll frequency is zero
+    fn next_poll(now: T::BlockNumber) -> T::BlockNumber {
+        let freq = Self::submission_poll_frequency();
+        if freq.is_zero() {
+            now + 1u32.into()
+        } else {
+            now - now % freq + freq
+        }
+    }
+    /// Executes payment for the approved submission, a failed payment is
+    /// retried up to `MaxPaymentRetries` times before the submission awaits
+    /// review again, returns false if the payment must be retried
+    fn pay_or_retry(
+        id: T::SubmissionId,
+        sub: BountySub<T>,
+        bounty: Bounty<T>,
+    ) -> bool {
+        if Self::execute_payment(id, sub.clone(), bounty).is_ok() {
+            <PaymentRetries<T>>::remove(id);
+            return true
+        }
+        let retries = <PaymentRetries<T>>::get(id) + 1;
+        if retries > T::MaxPaymentRetries::get() {
+            <PaymentRetries<T>>::remove(id);
+            <Submissions<T>>::insert(
+                id,
+                sub.set_state(SubmissionState::SubmittedAwaitingResponse),
+            );
+            Self::deposit_event(RawEvent::SubmissionPaymentRetriesExhausted(
+                sub.bounty_id(),
+                id,
+                sub.amount(),
+            ));
+            true
+        } else {
+            <PaymentRetries<T>>::insert(id, retries);
+            false
+        }
+    }
+    /// Executes payment for queued submissions whose review vote passed and
+    /// rejects those whose vote failed, submissions whose vote expired await
+    /// review again and the others are polled again
+    fn poll_reviews(n: T::BlockNumber) {
+        <ReviewQueue<T>>::take(n).into_iter().for_each(|id| {
+            if let Some(sub) = <Submissions<T>>::get(id) {
+                if let Some(vid) = sub.state().under_review() {
+                    let expired = <vote::Module<T> as CheckVoteExpiry<
+                        T::VoteId,
+                    >>::vote_expired(vid);
+                    match <vote::Module<T>>::get_vote_outcome(vid) {
+                        Ok(VoteOutcome::Approved) => {
+                            if let Some(bounty) =
+                                <Bounties<T>>::get(sub.bounty_id())
+                            {
+                                if !Self::pay_or_retry(id, sub, bounty) {
+                                    <ReviewQueue<T>>::append(
+                                        Self::next_poll(n),
+                                        id,
+                                    );
+                                }
+                            }
+                        }
+                        Ok(VoteOutcome::Rejected) => {
+                            if let Some(bounty) =
+                                <Bounties<T>>::get(sub.bounty_id())
+                            {
+                                Self::slash_deposit(
+                                    sub.bounty_id(),
+                                    bounty,
+                                    &sub,
+                                );
+                            }
+                            T::Reputation::submission_rejected(
+                                &sub.submitter(),
+                            );
+                            <Submissions<T>>::insert(
+                                id,
+                                sub.set_state(SubmissionState::Rejected),
+                            );
+                            Self::deposit_event(
+                                RawEvent::BountySubmissionRejected(
+                                    sub.bounty_id(),
+                                    id,
+                                    sub.submitter(),
+                                    None,
+                                ),
+                            );
+                        }
+                        _ if expired => {
+                            // the reviewers may trigger another vote or review it
+                            <Submissions<T>>::insert(
+                                id,
+                                sub.set_state(
+                                    SubmissionState::SubmittedAwaitingResponse,
+                                ),
+                            );
+                            Self::schedule_review(sub.bounty_id(), id);
+                            Self::deposit_event(
+                                RawEvent::BountySubmissionReviewExpired(
+                                    sub.bounty_id(),
+                                    id,
+                                    vid,
+                                ),
+                            );
+                        }
+                        _ => <ReviewQueue<T>>::append(Self::next_poll(n), id),
+                    }
+                }
+            }
+        });
+    }
+    /// Only members of the eligible org may work on a member restricted bounty
+    fn is_eligible(bounty_id: T::BountyId, who: &T::AccountId) -> bool {
+        if let Some(org) = <BountyEligibility<T>>::get(bounty_id) {
+            <org::Module<T>>::is_member_of_group(org, who)
+        } else {
+            true
+        }
+    }
+    /// The governance sudo reviews submissions if the bounty has governance, else the depositer
+    fn is_reviewer(bounty: &Bounty<T>, who: &T::AccountId) -> bool {
+        if let Some(gov) = bounty.gov() {
+            gov.is_sudo(who)
+        } else {
+            &bounty.depositer() == who
+        }
+    }
+    /// Pays the next milestone of the submission if the bounty has milestones,
+    /// else the full amount requested
+    fn execute_payment(
+        submission_id: T::SubmissionId,
+        submission: BountySub<T>,
+        bounty: Bounty<T>,
+    ) -> DispatchResult {
+        let bounty_id = submission.bounty_id();
+        ensure!(
+            !<ExpiryRefunds<T>>::contains_key(bounty_id),
+            Error::<T>::BountyHasExpired
+        );
+        let milestone = <MilestonesPaid<T>>::get(submission_id);
+        let payment = <BountyMilestones<T>>::get(bounty_id)
+            .and_then(|m| m.get(milestone as usize).copied())
+            .map(|a| a.min(submission.amount()));
+        let amount = payment.unwrap_or_else(|| submission.amount());
+        ensure!(
+            bounty.total() >= amount,
+            Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
+        );
+        Self::transfer_payment(
+            bounty_id,
+            &bounty,
+            submission_id,
+            &submission,
+            amount,
+        )?;
+        if payment.is_some() {
+            let remaining = submission.amount() - amount;
+            Self::deposit_event(RawEvent::BountyMilestonePaid(
+                bounty_id,
+                submission_id,
+                milestone,
+                amount,
+                remaining,
+            ));
+            if !remaining.is_zero() {
+                // the submission awaits review of its next milestone
+                <MilestonesPaid<T>>::insert(submission_id, milestone + 1);
+                <Submissions<T>>::insert(
+                    submission_id,
+                    submission
+                        .pay_out_amount(amount)
+                        .set_state(SubmissionState::SubmittedAwaitingResponse),
+                );
+                Self::credit_payment(bounty_id, &bounty, &submission, amount);
+                <Bounties<T>>::insert(bounty_id, bounty.subtract_total(amount));
+                <LastPayout<T>>::insert(
+                    bounty_id,
+                    <frame_system::Module<T>>::block_number(),
+                );
+                Self::schedule_review(bounty_id, submission_id);
+                return Ok(())
+            }
+        }
+        Self::record_payment(submission_id, submission, bounty);
+        Ok(())
+    }
+    /// Transfers `amount` to the recipient named by the submission, else to the submitter.
+    /// Recipients with an org have it split across the members through donate
+    fn transfer_payment(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        submission_id: T::SubmissionId,
+        submission: &BountySub<T>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let bounty_account = Self::bounty_account_id(bounty_id);
+        let recipient = <SubmissionRecipients<T>>::get(submission_id);
+        if let Some(r) = &recipient {
+            if let Some(org) = r.org() {
+                // the payout may drain the bounty account
+                let (mut transfers, remainder) =
+                    <donate::Module<T>>::donate_allow_death(
+                        &bounty_account,
+                        org,
+                        &r.account(),
+                        amount,
+                    )?;
+                transfers.push((r.account(), remainder));
+                Self::deposit_event(RawEvent::BountyTeamPaymentExecuted(
+                    bounty_id,
+                    submission_id,
+                    transfers,
+                ));
+                return Ok(())
+            }
+        }
+        let payee = recipient
+            .map(|r| r.account())
+            .unwrap_or_else(|| submission.submitter());
+        <T as Trait>::Currency::transfer(
+            bounty.asset(),
+            &bounty_account,
+            &payee,
+            amount,
+        )
+    }
+    /// Updates storage for a submission paid out from the bounty account
+    fn record_payment(
+        submission_id: T::SubmissionId,
+        submission: BountySub<T>,
+        bounty: Bounty<T>,
+    ) {
+        let bounty_id = submission.bounty_id();
+        T::DepositCurrency::unreserve(
+            &submission.submitter(),
+            submission.deposit(),
+        );
+        Self::credit_payment(
+            bounty_id,
+            &bounty,
+            &submission,
+            submission.amount(),
+        );
+        T::Reputation::submission_approved(&submission.submitter());
+        let new_bounty = bounty.subtract_total(submission.amount());
+        let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
+        // submission approved and executed => can be removed
+        <Submissions<T>>::remove(submission_id);
+        <SubmissionRecipients<T>>::remove(submission_id);
+        <MilestonesPaid<T>>::remove(submission_id);
+        <ReviewDeadlines<T>>::remove(submission_id);
+        <BountySubmissions<T>>::remove(bounty_id, submission_id);
+        <SubmissionCount<T>>::mutate(bounty_id, |n| *n = n.saturating_sub(1));
+        <Bounties<T>>::insert(bounty_id, new_bounty);
+        <LastPayout<T>>::insert(
+            bounty_id,
+            <frame_system::Module<T>>::block_number(),
+        );
+        Self::deposit_event(RawEvent::BountyPaymentExecuted(
+            bounty_id,
+            new_total,
+            submission_id,
+            submission.amount(),
+            submission.submitter(),
+            bounty_info,
+            submission.submission(),
+        ));
+    }
+    /// Credits a payment of `amount` out of the bounty to the reputation of its
+    /// depositer and the submitter, before `LastPayout` records it, amounts are
+    /// only credited in the native asset
+    fn credit_payment(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        submission: &BountySub<T>,
+        amount: BalanceOf<T>,
+    ) {
+        let native_amount = if bounty.asset() == T::NativeAsset::get() {
+            amount
+        } else {
+            BalanceOf::<T>::zero()
+        };
+        T::Reputation::payment_executed(
+            &bounty.depositer(),
+            &submission.submitter(),
+            native_amount,
+            !<LastPayout<T>>::contains_key(bounty_id),
+        );
+    }
+    /// Slashes the rejected submitter's deposit into the bounty, the depositer
+    /// receives it instead if the bounty is not funded in the native asset
+    fn slash_deposit(
+        bounty_id: T::BountyId,
+        bounty: Bounty<T>,
+        submission: &BountySub<T>,
+    ) {
+        let into_bounty = bounty.asset() == T::NativeAsset::get();
+        let beneficiary = if into_bounty {
+            Self::bounty_account_id(bounty_id)
+        } else {
+            bounty.depositer()
+        };
+        if let Ok(remainder) = T::DepositCurrency::repatriate_reserved(
+            &submission.submitter(),
+            &beneficiary,
+            submission.deposit(),
+            BalanceStatus::Free,
+        ) {
+            let slashed = submission.deposit() - remainder;
+            if into_bounty && !slashed.is_zero() {
+                <Bounties<T>>::insert(bounty_id, bounty.add_total(slashed));
+            }
+        }
+    }
+}
+
+// Dispute helpers
+impl<T: Trait> Module<T> {
+    /// Resolves queued disputes in the court, submissions whose dispute was
+    /// accepted are paid out like approved submissions and those whose dispute
+    /// was rejected are rejected, unresolved disputes and failed payments are
+    /// polled again
+    fn poll_disputes(n: T::BlockNumber) {
+        <DisputeQueue<T>>::take(n).into_iter().for_each(|id| {
+            let dispute_id = match <SubmissionDisputes<T>>::get(id) {
+                Some(dispute_id) => dispute_id,
+                None => return,
+            };
+            let sub = match <Submissions<T>>::get(id) {
+                Some(sub) => sub,
+                None => {
+                    <SubmissionDisputes<T>>::remove(id);
+                    return
+                }
+            };
+            match T::Court::resolve_dispute(dispute_id) {
+                Ok(DisputeState::DisputeRaisedAndAccepted(_)) => {
+                    if let Some(bounty) = <Bounties<T>>::get(sub.bounty_id()) {
+                        if !Self::pay_or_retry(id, sub, bounty) {
+                            <DisputeQueue<T>>::append(Self::next_poll(n), id);
+                            return
+                        }
+                    }
+                }
+                Ok(DisputeState::DisputeRaisedAndRejected(_)) => {
+                    if let Some(bounty) = <Bounties<T>>::get(sub.bounty_id()) {
+                        Self::slash_deposit(sub.bounty_id(), bounty, &sub);
+                    }
+                    T::Reputation::submission_rejected(&sub.submitter());
+                    <Submissions<T>>::insert(
+                        id,
+                        sub.set_state(SubmissionState::Rejected),
+                    );
+                    Self::deposit_event(RawEvent::BountySubmissionRejected(
+                        sub.bounty_id(),
+                        id,
+                        sub.submitter(),
+                        None,
+                    ));
+                }
+                Ok(DisputeState::DisputeExpired) => {
+                    // never raised, the submission awaits review again
+                    Self::deposit_event(
+                        RawEvent::BountySubmissionDisputeLapsed(
+                            sub.bounty_id(),
+                            id,
+                            dispute_id,
+                        ),
+                    );
+                }
+                Ok(_) => {
+                    <DisputeQueue<T>>::append(Self::next_poll(n), id);
+                    return
+                }
+                Err(_) => (),
+            }
+            <SubmissionDisputes<T>>::remove(id);
+            <DisputeCount<T>>::mutate(sub.bounty_id(), |n| {
+                *n = n.saturating_sub(1)
+            });
+        });
+    }
+}
+
+// Expiry helpers
+impl<T: Trait> Module<T> {
+    /// Lets anyone submit for the bounty again once the work deadline lapses
+    fn reopen_bounty(id: T::BountyId) {
+        <AssignmentDeadlines<T>>::remove(id);
+        <Assignees<T>>::remove_prefix(id);
+        Self::deposit_event(RawEvent::BountyReopened(id));
+    }
+    /// Queues the submission for approval once the review window of its
+    /// bounty lapses, if the bounty has one
+    fn schedule_review(bounty_id: T::BountyId, id: T::SubmissionId) {
+        if let Some(window) = <BountyReviewWindows<T>>::get(bounty_id) {
+            let deadline = <frame_system::Module<T>>::block_number() + window;
+            <ReviewDeadlines<T>>::insert(id, deadline);
+            <ReviewExpiries<T>>::append(deadline, id);
+        }
+    }
+    /// Pays out the submission if it still awaits review, undisputed
+    fn auto_approve(id: T::SubmissionId) {
+        if <SubmissionDisputes<T>>::contains_key(id) {
+            return
+        }
+        if let Some(sub) = <Submissions<T>>::get(id) {
+            if !sub.state().awaiting_review() {
+                return
+            }
+            let (bounty_id, submitter) = (sub.bounty_id(), sub.submitter());
+            if let Some(bounty) = <Bounties<T>>::get(bounty_id) {
+                if Self::execute_payment(id, sub, bounty).is_ok() {
+                    Self::deposit_event(RawEvent::SubmissionAutoApproved(
+                        bounty_id, id, submitter,
+                    ));
+                }
+            }
+        }
+    }
+    /// Refunds the contributors of queued bounties and removes their
+    /// submissions, at most `limit` of them, then removes each bounty once
+    /// all of them are processed, returns the number processed
+    fn process_refunds(limit: u32) -> u32 {
+        let mut queue = <RefundQueue<T>>::get();
+        let mut processed = 0u32;
+        while let Some(&id) = queue.first() {
+            let bounty = match <Bounties<T>>::get(id) {
+                Some(bounty) => bounty,
+                None => {
+                    queue.remove(0);
+                    continue
+                }
+            };
+            let budget = limit.saturating_sub(processed) as usize;
+            let mut tips = <BountyTips<T>>::iter_prefix(id)
+                .take(budget + 1)
+                .collect::<Vec<_>>();
+            let includes_last = tips.len() <= budget;
+            tips.truncate(budget);
+            processed += tips.len() as u32;
+            Self::refund_contributors(id, &bounty, tips, includes_last);
+            if !includes_last {
+                break
+            }
+            let budget = limit.saturating_sub(processed) as usize;
+            let mut subs = <BountySubmissions<T>>::iter_prefix(id)
+                .map(|(sub_id, _)| sub_id)
+                .take(budget + 1)
+                .collect::<Vec<_>>();
+            let all_removed = subs.len() <= budget;
+            subs.truncate(budget);
+            processed += subs.len() as u32;
+            subs.into_iter().for_each(|sub_id| {
+                Self::remove_submission(sub_id);
+                <BountySubmissions<T>>::remove(id, sub_id);
+            });
+            if !all_removed || processed >= limit {
+                break
+            }
+            processed += 1;
+            let refunded = Self::settle_refunds(id, &bounty);
+            Self::recursive_remove_bounty(id);
+            Self::deposit_event(RawEvent::BountyExpiredAndRefunded(
+                id,
+                refunded,
+                bounty.info(),
+            ));
+            queue.remove(0);
+        }
+        <RefundQueue<T>>::put(queue);
+        processed
+    }
+    /// Refunds the contributors in proportion to their tips of the remaining
+    /// total, the last contributor receives any remainder lost to rounding
+    fn refund_contributors(
+        id: T::BountyId,
+        bounty: &Bounty<T>,
+        tips: Vec<(T::AccountId, BalanceOf<T>)>,
+        includes_last: bool,
+    ) {
+        let bounty_account = Self::bounty_account_id(id);
+        let remaining = bounty.total();
+        let total_tipped = <TotalTipped<T>>::get(id);
+        let (mut distributed, mut refunded) =
+            <ExpiryRefunds<T>>::get(id).unwrap_or_default();
+        let last = tips.len().saturating_sub(1);
+        for (i, (contributor, tip)) in tips.into_iter().enumerate() {
+            let share = if includes_last && i == last {
+                remaining.saturating_sub(distributed)
+            } else {
+                Perbill::from_rational_approximation(tip, total_tipped)
+                    .mul_floor(remaining)
+            };
+            distributed += share;
+            if <T as Trait>::Currency::transfer(
+                bounty.asset(),
+                &bounty_account,
+                &contributor,
+                share,
+            )
+            .is_ok()
+            {
+                refunded += share;
+            }
+            <BountyTips<T>>::remove(id, &contributor);
+        }
+        <ExpiryRefunds<T>>::insert(id, (distributed, refunded));
+    }
+    /// Sends the depositer whatever the contributors failed to receive,
+    /// returns the amount refunded to the contributors
+    fn settle_refunds(id: T::BountyId, bounty: &Bounty<T>) -> BalanceOf<T> {
+        let (_, refunded) = <ExpiryRefunds<T>>::get(id).unwrap_or_default();
+        let unrefunded = bounty.total().saturating_sub(refunded);
+        // a failed transfer leaves the remainder in the bounty account
+        let _ = <T as Trait>::Currency::transfer(
+            bounty.asset(),
+            &Self::bounty_account_id(id),
+            &bounty.depositer(),
+            unrefunded,
+        );
+        refunded
     }
 }
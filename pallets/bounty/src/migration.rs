@@ -0,0 +1,236 @@
+//! Migration of bounties to the bounty2 data model
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+use util::grant::ChallengeNorms;
+
+/// Storage layout of the pallet before the bounty2 migration
+pub mod v0 {
+    use super::*;
+    use frame_support::storage::migration::StorageIterator;
+
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+    pub struct BountyInformation<IpfsReference, AccountId, Currency> {
+        pub info: IpfsReference,
+        pub depositer: AccountId,
+        pub total: Currency,
+    }
+
+    #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+    pub enum SubmissionState {
+        SubmittedAwaitingResponse,
+        ApprovedAndExecuted,
+    }
+
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+    pub struct BountySubmission<BountyId, IpfsReference, AccountId, Currency> {
+        pub bounty: BountyId,
+        pub submission_ref: IpfsReference,
+        pub submitter: AccountId,
+        pub amount: Currency,
+        pub state: SubmissionState,
+    }
+
+    pub type Bounty<T> = BountyInformation<
+        <T as org::Trait>::IpfsReference,
+        <T as frame_system::Trait>::AccountId,
+        BalanceOf<T>,
+    >;
+    pub type BountySub<T> = BountySubmission<
+        <T as Trait>::BountyId,
+        <T as org::Trait>::IpfsReference,
+        <T as frame_system::Trait>::AccountId,
+        BalanceOf<T>,
+    >;
+
+    /// Key of a `blake2_128_concat` map entry, which follows its 16 byte hash
+    fn decode_key<K: Decode>(hashed_key: &[u8]) -> Option<K> {
+        hashed_key
+            .get(16..)
+            .and_then(|mut key| K::decode(&mut key).ok())
+    }
+
+    /// Rewrites the bounties and submissions in the current layout, in the
+    /// native asset and without submission deposits, and indexes the
+    /// submissions by bounty and sums the tips of each bounty. Returns the
+    /// number of rewritten entries and summed tips.
+    pub fn translate<T: Trait>() -> Weight {
+        let bounties =
+            StorageIterator::<Bounty<T>>::new(b"Bounty", b"Bounties")
+                .filter_map(|(key, b)| decode_key(&key).map(|id| (id, b)))
+                .collect::<Vec<(T::BountyId, Bounty<T>)>>();
+        let submissions =
+            StorageIterator::<BountySub<T>>::new(b"Bounty", b"Submissions")
+                .filter_map(|(key, s)| decode_key(&key).map(|id| (id, s)))
+                .collect::<Vec<(T::SubmissionId, BountySub<T>)>>();
+        let mut translated = (bounties.len() + submissions.len()) as Weight;
+        bounties.into_iter().for_each(|(id, b)| {
+            <TotalTipped<T>>::insert(
+                id,
+                <BountyTips<T>>::iter_prefix_values(id).fold(
+                    BalanceOf::<T>::zero(),
+                    |acc, tip| {
+                        translated += 1;
+                        acc + tip
+                    },
+                ),
+            );
+            <super::Bounties<T>>::insert(
+                id,
+                super::Bounty::<T>::new(
+                    b.info,
+                    b.depositer,
+                    None,
+                    T::NativeAsset::get(),
+                    b.total,
+                    None,
+                ),
+            );
+        });
+        submissions.into_iter().for_each(|(id, s)| {
+            let state = match s.state {
+                SubmissionState::SubmittedAwaitingResponse => {
+                    super::SubmissionState::SubmittedAwaitingResponse
+                }
+                SubmissionState::ApprovedAndExecuted => {
+                    super::SubmissionState::ApprovedAndExecuted
+                }
+            };
+            let sub = super::BountySub::<T>::new(
+                s.bounty,
+                s.submission_ref,
+                s.submitter,
+                s.amount,
+                Zero::zero(),
+            );
+            <super::Submissions<T>>::insert(id, sub.set_state(state));
+            <BountySubmissions<T>>::insert(s.bounty, id, ());
+            <SubmissionCount<T>>::mutate(s.bounty, |n| {
+                *n = n.saturating_add(1)
+            });
+        });
+        translated
+    }
+}
+
+/// Rewrites the bounties stored before this release in the current layout
+/// and moves each of them into bounty2 under a new identifier, governed by
+/// its depositer with the minimum challenge thresholds. Tips and
+/// submissions pending review move with it, their deposits are returned
+/// because bounty2 does not hold them, and submissions already paid out are
+/// not moved. The funds stay at the `bounty_account_id` of this pallet,
+/// which bounty2 records as the account of the bounty.
+///
+/// Bounties with state bounty2 cannot hold (another asset, governance,
+/// expiry, disputes, eligibility, a reputation floor, tags, milestones,
+/// applications, assignees, review windows or team recipients) stay in
+/// this pallet. Runs once, for chains at `Releases::V0`.
+pub struct MigrateToBounty2<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for MigrateToBounty2<T>
+where
+    T: Trait
+        + bounty2::Trait<Currency = <T as donate::Trait>::Currency>
+        + vote_direct::Trait<IpfsReference = <T as org::Trait>::IpfsReference>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        if <StorageVersion>::get() != Releases::V0 {
+            return <T as frame_system::Trait>::DbWeight::get().reads(1)
+        }
+        let translated = v0::translate::<T>();
+        let (mut reads, mut writes) = (1 + 2 * translated, 1 + 3 * translated);
+        let bounties = <Bounties<T>>::iter().collect::<Vec<_>>();
+        reads += bounties.len() as Weight;
+        for (id, bounty) in bounties {
+            if !fits_bounty2::<T>(id, &bounty) {
+                continue
+            }
+            let (r, w) = migrate_bounty::<T>(id, bounty);
+            reads += r;
+            writes += w;
+        }
+        <StorageVersion>::put(Releases::V1);
+        <T as frame_system::Trait>::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+/// True if bounty2 holds all state of the bounty
+fn fits_bounty2<T: Trait>(
+    id: <T as Trait>::BountyId,
+    bounty: &Bounty<T>,
+) -> bool {
+    bounty.asset() == <T as Trait>::NativeAsset::get()
+        && bounty.gov().is_none()
+        && bounty.expiry().is_none()
+        && <BountyArbitration<T>>::get(id).is_none()
+        && <BountyEligibility<T>>::get(id).is_none()
+        && <BountyMinReputation<T>>::get(id).is_none()
+        && <BountyTags<T>>::get(id).is_empty()
+        && <BountyMilestones<T>>::get(id).is_none()
+        && <BountyReviewWindows<T>>::get(id).is_none()
+        && <Applications<T>>::iter_prefix(id).next().is_none()
+        && <Assignees<T>>::iter_prefix(id).next().is_none()
+        && <BountySubmissions<T>>::iter_prefix(id).all(|(sub_id, _)| {
+            !<SubmissionDisputes<T>>::contains_key(sub_id)
+                && !<SubmissionRecipients<T>>::contains_key(sub_id)
+        })
+}
+
+/// Returns the number of storage reads and writes
+fn migrate_bounty<T>(
+    id: <T as Trait>::BountyId,
+    bounty: Bounty<T>,
+) -> (Weight, Weight)
+where
+    T: Trait
+        + bounty2::Trait<Currency = <T as donate::Trait>::Currency>
+        + vote_direct::Trait<IpfsReference = <T as org::Trait>::IpfsReference>,
+{
+    let new_id = <bounty2::Module<T>>::bounty_generate_uid();
+    let gov = ChallengeNorms::new(
+        bounty.depositer(),
+        <T as bounty2::Trait>::MinVetoThreshold::get(),
+        <T as bounty2::Trait>::MinRefundThreshold::get(),
+    );
+    <bounty2::Bounties<T>>::insert(
+        new_id,
+        bounty2::Bounty::<T>::new(bounty.info(), gov, bounty.total()),
+    );
+    <bounty2::BountyAccounts<T>>::insert(
+        new_id,
+        <Module<T>>::bounty_account_id(id),
+    );
+    let (mut reads, mut writes) = (12 as Weight, 4 as Weight);
+    let mut contributors = 0u32;
+    <BountyTips<T>>::iter_prefix(id).for_each(|(who, tip)| {
+        <bounty2::BountyTips<T>>::insert(new_id, who, tip);
+        contributors += 1;
+        reads += 1;
+        writes += 1;
+    });
+    <bounty2::ContributorCount<T>>::insert(new_id, contributors);
+    <Module<T>>::submissions_for(id)
+        .into_iter()
+        .filter(|(_, s)| {
+            s.state().awaiting_review() || s.state().under_review().is_some()
+        })
+        .for_each(|(_, s)| {
+            let sub_id = <bounty2::Module<T>>::submission_generate_uid();
+            <bounty2::Submissions<T>>::insert(
+                sub_id,
+                bounty2::BountySub::<T>::new(
+                    new_id,
+                    s.submission(),
+                    s.submitter(),
+                    s.amount(),
+                    Zero::zero(),
+                ),
+            );
+            <bounty2::BountySubmissions<T>>::insert(new_id, sub_id, ());
+            reads += 3;
+            writes += 3;
+        });
+    <Module<T>>::recursive_remove_bounty(id);
+    (reads, writes + 14)
+}
@@ -1,18 +1,53 @@
-use super::*;
+use super::{
+    migration::{
+        v0,
+        MigrateToBounty2,
+    },
+    *,
+};
 use frame_support::{
     assert_noop,
     assert_ok,
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    storage::migration::put_storage_value,
+    traits::{
+        OnFinalize,
+        OnInitialize,
+        OnRuntimeUpgrade,
+    },
     weights::Weight,
+    Blake2_128Concat,
+    StorageHasher,
 };
 use frame_system::{self as system,};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::IdentityLookup,
+    traits::{
+        ConvertInto,
+        IdentityLookup,
+    },
     Perbill,
+    Permill,
+};
+use util::{
+    grant::Recipient,
+    meta::{
+        ResolutionMetadata,
+        VoteCall,
+        VoteMetadata,
+    },
+    organization::{
+        OrgRep,
+        OrganizationSource,
+    },
+    traits::RegisterOrganization,
+    vote::{
+        Threshold,
+        VoterView,
+    },
 };
 
 // type aliases
@@ -31,7 +66,15 @@ impl_outer_event! {
     pub enum TestEvent for Test {
         system<T>,
         pallet_balances<T>,
+        tokens<T>,
+        org<T>,
+        vote<T>,
+        donate<T>,
+        court<T>,
+        reputation<T>,
         bounty<T>,
+        vote_direct<T>,
+        bounty2<T>,
     }
 }
 
@@ -81,26 +124,147 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const NativeAsset: u32 = 0;
+}
+impl tokens::Trait for Test {
+    type Event = TestEvent;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type NativeCurrencyId = NativeAsset;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: u64 = 10;
+}
+impl org::Trait for Test {
+    type Event = TestEvent;
+    type IpfsReference = u32;
+    type OrgId = u64;
+    type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = vote::Module<Test>;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = ();
+}
+impl donate::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type WeightInfo = ();
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type VoteId = u64;
+    type Signal = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const MinimumDisputeAmount: u64 = 5;
+}
+impl court::Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type DisputeId = u64;
+    type MinimumDisputeAmount = MinimumDisputeAmount;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ApprovalScore: u32 = 10;
+    pub const RejectionPenalty: u32 = 5;
+    pub const PayoutScore: u32 = 3;
+    pub const LeaderboardSize: u32 = 2;
+}
+impl reputation::Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type ApprovalScore = ApprovalScore;
+    type RejectionPenalty = RejectionPenalty;
+    type PayoutScore = PayoutScore;
+    type LeaderboardSize = LeaderboardSize;
+}
 parameter_types! {
     pub const Foundation: ModuleId = ModuleId(*b"fundacon");
     pub const MinDeposit: u64 = 10;
     pub const MinContribution: u64 = 5;
+    pub const SubmissionDeposit: u64 = 2;
+    pub const InactivityWindow: u64 = 10;
+    pub const MaxRefundsPerBlock: u32 = 2;
+    pub const MaxMilestones: u32 = 6;
+    pub const DisputeWindow: u64 = 5;
+    pub const MaxTags: u32 = 3;
+    pub const MaxTagLength: u32 = 16;
 }
 impl Trait for Test {
+    type Event = TestEvent;
+    type AssetId = u32;
+    type Currency = Tokens;
+    type NativeAsset = NativeAsset;
+    type DepositCurrency = Balances;
+    type BountyId = u64;
+    type SubmissionId = u64;
+    type Foundation = Foundation;
+    type MinDeposit = MinDeposit;
+    type MinContribution = MinContribution;
+    type SubmissionDeposit = SubmissionDeposit;
+    type InactivityWindow = InactivityWindow;
+    type MaxPaymentRetries = MaxPaymentRetries;
+    type MaxRefundsPerBlock = MaxRefundsPerBlock;
+    type MaxMilestones = MaxMilestones;
+    type MaxTags = MaxTags;
+    type MaxTagLength = MaxTagLength;
+    type DisputeId = u64;
+    type Court = Court;
+    type DisputeWindow = DisputeWindow;
+    type Reputation = Reputation;
+    type WeightInfo = ();
+}
+impl vote_direct::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32;
+    type VoteId = u64;
+    type Signal = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ChallengePeriod: u64 = 5;
+    pub const Foundation2: ModuleId = ModuleId(*b"fundaco2");
+    pub const MinVetoThreshold: Permill = Permill::from_percent(10);
+    pub const MinRefundThreshold: Permill = Permill::from_percent(10);
+    pub const MaxPollsPerBlock: u32 = 2;
+    pub const MaxPaymentRetries: u32 = 2;
+}
+impl bounty2::Trait for Test {
+    type Event = TestEvent;
     type Currency = Balances;
     type BountyId = u64;
     type SubmissionId = u64;
-    type Foundation = Foundation;
+    type ChallengePeriod = ChallengePeriod;
+    type Foundation = Foundation2;
     type MinDeposit = MinDeposit;
     type MinContribution = MinContribution;
+    type MinVetoThreshold = MinVetoThreshold;
+    type MinRefundThreshold = MinRefundThreshold;
+    type MaxPollsPerBlock = MaxPollsPerBlock;
+    type MaxPaymentRetries = MaxPaymentRetries;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = tokens::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Court = court::Module<Test>;
+pub type Reputation = reputation::Module<Test>;
 pub type Bounty = Module<Test>;
+pub type Bounty2 = bounty2::Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -124,6 +288,23 @@ fn new_test_ext() -> sp_io::TestExternalities {
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    tokens::GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, 1, 100), (2, 1, 50)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    org::GenesisConfig::<Test> {
+        first_organization_supervisor: 1,
+        first_organization_value_constitution: 1738,
+        first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        submission_poll_frequency: 10,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     let mut ext: sp_io::TestExternalities = t.into();
     ext.execute_with(|| System::set_block_number(1));
     ext
@@ -144,6 +325,7 @@ fn post_bounty_works() {
                 Origin::signed(1),
                 10u32, // cid
                 9,     // amount
+                BountyOptions::default(),
             ),
             Error::<Test>::BountyPostMustExceedMinDeposit,
         );
@@ -152,6 +334,7 @@ fn post_bounty_works() {
                 Origin::signed(1),
                 10u32, // cid
                 101,   // amount
+                BountyOptions::default(),
             ),
             sp_runtime::DispatchError::Module {
                 index: 0,
@@ -163,6 +346,7 @@ fn post_bounty_works() {
             Origin::signed(1),
             10u32, // constitution
             10,    // funding reserved
+            BountyOptions::default(),
         ));
         assert_eq!(RawEvent::BountyPosted(1, 10, 1, 10), get_last_event());
     });
@@ -175,6 +359,7 @@ fn contribution_works() {
             Origin::signed(1),
             10u32, // constitution
             10,    // funding reserved
+            BountyOptions::default(),
         ));
         assert_noop!(
             Bounty::contribute_to_bounty(Origin::signed(2), 2, 5),
@@ -204,20 +389,21 @@ fn contribution_works() {
 fn submission_works() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            Bounty::submit_for_bounty(Origin::signed(2), 1, 10u32, 15u64,),
+            Bounty::submit_for_bounty(Origin::signed(2), 1, 10u32, 15u64, None),
             Error::<Test>::BountyDNE
         );
         assert_ok!(Bounty::post_bounty(
             Origin::signed(1),
             10u32, // constitution
             21,    // funding reserved
+            BountyOptions::default(),
         ));
         assert_noop!(
-            Bounty::submit_for_bounty(Origin::signed(1), 1, 10u32, 15u64,),
+            Bounty::submit_for_bounty(Origin::signed(1), 1, 10u32, 15u64, None),
             Error::<Test>::DepositerCannotSubmitForBounty
         );
         assert_noop!(
-            Bounty::submit_for_bounty(Origin::signed(2), 1, 10u32, 22u64,),
+            Bounty::submit_for_bounty(Origin::signed(2), 1, 10u32, 22u64, None),
             Error::<Test>::BountySubmissionExceedsTotalAvailableFunding,
         );
         assert_ok!(Bounty::submit_for_bounty(
@@ -225,6 +411,7 @@ fn submission_works() {
             1,
             10u32,
             10u64,
+            None,
         ));
         assert_eq!(
             RawEvent::BountySubmissionPosted(2, 1, 10, 1, 10, 10),
@@ -244,6 +431,7 @@ fn submission_approval_works() {
             Origin::signed(1),
             10u32, // constitution
             21,    // funding reserved
+            BountyOptions::default(),
         ));
         assert_noop!(
             Bounty::approve_bounty_submission(Origin::signed(1), 1),
@@ -254,6 +442,7 @@ fn submission_approval_works() {
             1,
             10u32,
             10u64,
+            None,
         ));
         assert_noop!(
             Bounty::approve_bounty_submission(Origin::signed(2), 1),
@@ -266,3 +455,1438 @@ fn submission_approval_works() {
         assert_eq!(Balances::total_balance(&1), 79);
     });
 }
+
+#[test]
+fn bounty_expiry_refunds_contributors() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                10,
+                BountyOptions {
+                    expiry: Some(1),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::BountyExpiryMustBeInTheFuture
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            10,    // funding reserved
+            BountyOptions {
+                expiry: Some(5),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            10u32,
+            5,
+            None
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            11u32,
+            5,
+            None
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        // not expired yet
+        Bounty::on_initialize(4);
+        assert!(Bounty::bounties(1).is_some());
+        System::set_block_number(5);
+        Bounty::on_initialize(5);
+        // contributors are refunded first, two of them per block
+        assert_eq!(Bounty::refund_queue(), vec![1]);
+        assert_eq!(Bounty::expiry_refunds(1), Some((15, 15)));
+        assert!(Bounty::bounty_tips(1, 2).is_none());
+        assert!(Bounty::submissions(2).is_some());
+        assert_noop!(
+            Bounty::contribute_to_bounty(Origin::signed(2), 1, 10),
+            Error::<Test>::BountyHasExpired
+        );
+        System::set_block_number(6);
+        Bounty::on_initialize(6);
+        assert_eq!(
+            RawEvent::BountyExpiredAndRefunded(1, 15, 10),
+            get_last_event()
+        );
+        assert!(Bounty::refund_queue().is_empty());
+        assert!(Bounty::expiry_refunds(1).is_none());
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::submissions(2).is_none());
+        assert!(Bounty::bounty_tips(1, 2).is_none());
+        // 15 remaining split evenly, the last contributor receives the
+        // rounding remainder
+        assert_eq!(Balances::total_balance(&1), 97);
+        assert_eq!(Balances::total_balance(&2), 96);
+        assert_eq!(Balances::total_balance(&3), 205);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 0);
+    });
+}
+
+#[test]
+fn failed_refunds_go_to_the_depositer() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            20,
+            BountyOptions {
+                asset: Some(1),
+                expiry: Some(5),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        // the refund to 2 overflows its balance
+        tokens::Accounts::<Test>::insert(1, &2, u64::MAX);
+        System::set_block_number(5);
+        Bounty::on_initialize(5);
+        assert_eq!(Bounty::expiry_refunds(1), Some((30, 20)));
+        assert_eq!(Tokens::free_balance(1, &1), 100);
+        Bounty::on_initialize(6);
+        assert_eq!(
+            RawEvent::BountyExpiredAndRefunded(1, 20, 10),
+            get_last_event()
+        );
+        assert_eq!(Tokens::free_balance(1, &1), 110);
+        assert_eq!(Tokens::free_balance(1, &Bounty::bounty_account_id(1)), 0);
+    });
+}
+
+#[test]
+fn bounty_submissions_index_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            20,
+            BountyOptions {
+                expiry: Some(5),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            11u32,
+            20,
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            5,
+            None
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            5,
+            None
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            2,
+            12u32,
+            5,
+            None
+        ));
+        let subs_for = |id| {
+            BountySubmissions::<Test>::iter_prefix(id)
+                .map(|(sub_id, _)| sub_id)
+                .collect::<Vec<u64>>()
+        };
+        let mut first = subs_for(1);
+        first.sort();
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(Bounty::submission_count(1), 2);
+        assert_eq!(subs_for(2), vec![3]);
+        // approved and executed submissions leave the index
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(subs_for(1), vec![2]);
+        assert_eq!(Bounty::submission_count(1), 1);
+        // removing the bounty clears its index
+        System::set_block_number(5);
+        Bounty::on_initialize(5);
+        Bounty::on_initialize(6);
+        assert!(subs_for(1).is_empty());
+        assert_eq!(Bounty::submission_count(1), 0);
+        assert!(Bounty::submissions(2).is_none());
+        assert_eq!(subs_for(2), vec![3]);
+    });
+}
+
+#[test]
+fn bounty_queries_work() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            20,
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(3),
+            11u32,
+            50,
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            12u32,
+            5,
+            None
+        ));
+        let mut open = Bounty::open_bounties(25, None, vec![], 0, 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<u64>>();
+        open.sort();
+        assert_eq!(open, vec![1, 2]);
+        assert_eq!(Bounty::open_bounties(40, None, vec![], 0, 10).len(), 1);
+        assert_eq!(Bounty::open_bounties(0, None, vec![], 0, 1).len(), 1);
+        assert_eq!(Bounty::open_bounties(0, None, vec![], 1, 1).len(), 1);
+        assert!(Bounty::open_bounties(0, None, vec![], 2, 1).is_empty());
+        let subs = Bounty::submissions_for(1);
+        assert_eq!(subs.len(), 1);
+        assert_eq!(subs[0].0, 1);
+        assert!(Bounty::submissions_for(2).is_empty());
+        let mut tips = Bounty::contributions(1);
+        tips.sort();
+        assert_eq!(tips, vec![(1, 20), (2, 10)]);
+    });
+}
+
+#[test]
+fn submission_rejection_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(1), 1, None),
+            Error::<Test>::SubmissionDNE
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            21,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10u64,
+            None,
+        ));
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(2), 1, None),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(
+            Origin::signed(1),
+            1,
+            Some(11u32)
+        ));
+        assert_eq!(
+            RawEvent::BountySubmissionRejected(1, 1, 2, Some(11)),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(1).unwrap().state().rejected());
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(1), 1, None),
+            Error::<Test>::SubmissionNotInValidStateToReject
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Error::<Test>::SubmissionNotInValidStateToApprove
+        );
+        // the deposit is slashed into the bounty
+        assert_eq!(Balances::total_balance(&2), 96);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 23);
+    });
+}
+
+#[test]
+fn submission_withdrawal_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::withdraw_submission(Origin::signed(2), 1),
+            Error::<Test>::SubmissionDNE
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            21,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10u64,
+            None,
+        ));
+        assert_noop!(
+            Bounty::withdraw_submission(Origin::signed(1), 1),
+            Error::<Test>::NotAuthorizedToWithdrawSubmission
+        );
+        assert_ok!(Bounty::withdraw_submission(Origin::signed(2), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionWithdrawn(1, 1, 2),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(1).unwrap().state().withdrawn());
+        assert_noop!(
+            Bounty::withdraw_submission(Origin::signed(2), 1),
+            Error::<Test>::SubmissionNotInValidStateToWithdraw
+        );
+    });
+}
+
+fn sudo_gov() -> GovernanceOf<Test> {
+    ResolutionMetadata::new(Some(3u64), None).unwrap()
+}
+
+fn org_vote_gov() -> GovernanceOf<Test> {
+    ResolutionMetadata::new(
+        None,
+        Some(VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1u64),
+            Threshold::new(2u64, Some(2u64)),
+            None,
+        ))),
+    )
+    .unwrap()
+}
+
+#[test]
+fn governance_sudo_approval_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            21,    // funding reserved
+            BountyOptions {
+                governance: Some(sudo_gov()),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10u64,
+            None,
+        ));
+        // the depositer is no longer the reviewer
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        // no vote metadata in the governance so review cannot be triggered
+        assert_noop!(
+            Bounty::trigger_submission_review(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToTriggerSubmissionReview
+        );
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(3), 1));
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 11, 1, 10, 2, 10, 10),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&2), 108);
+    });
+}
+
+#[test]
+fn governance_vote_approval_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            21,    // funding reserved
+            BountyOptions {
+                governance: Some(org_vote_gov()),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10u64,
+            None,
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            11u32,
+            5u64,
+            None,
+        ));
+        assert_noop!(
+            Bounty::trigger_submission_review(Origin::signed(7), 1),
+            Error::<Test>::NotAuthorizedToTriggerSubmissionReview
+        );
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(3), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionReviewTriggered(1, 1, 1),
+            get_last_event()
+        );
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(3), 2));
+        assert_eq!(Bounty::review_queue(10), vec![1, 2]);
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Error::<Test>::SubmissionNotInValidStateToApprove
+        );
+        // approve the first submission, reject the second
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            2,
+            VoterView::Against,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            2,
+            VoterView::Against,
+            None
+        ));
+        // only polled every 10 blocks
+        Bounty::on_finalize(9);
+        assert_eq!(Balances::total_balance(&2), 98);
+        Bounty::on_finalize(10);
+        assert_eq!(Balances::total_balance(&2), 108);
+        assert!(Bounty::review_queue(10).is_empty());
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::submissions(2).unwrap().state().rejected());
+        // 21 - 10 paid out + 2 slashed from the rejected submission
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 13);
+    });
+}
+
+#[test]
+fn expired_review_awaits_review_again() {
+    new_test_ext().execute_with(|| {
+        let gov = ResolutionMetadata::new(
+            None,
+            Some(VoteMetadata::Signal(VoteCall::new(
+                OrgRep::Equal(1u64),
+                Threshold::new(2u64, Some(2u64)),
+                Some(5), // vote duration
+            ))),
+        )
+        .unwrap();
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            21,    // funding reserved
+            BountyOptions {
+                governance: Some(gov),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10u64,
+            None,
+        ));
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(3), 1));
+        // one vote in favor is not enough to pass before the vote expires
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        System::set_block_number(10);
+        Bounty::on_finalize(10);
+        assert!(Bounty::review_queue(20).is_empty());
+        assert!(Bounty::submissions(1).unwrap().state().awaiting_review());
+        assert_eq!(
+            RawEvent::BountySubmissionReviewExpired(1, 1, 1),
+            get_last_event()
+        );
+        // the deposit stays reserved and a new review can be triggered
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(3), 1));
+    });
+}
+
+#[test]
+fn failed_payment_is_retried_up_to_max_retries() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            21,    // funding reserved
+            BountyOptions {
+                governance: Some(org_vote_gov()),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10u64,
+            None,
+        ));
+        assert_ok!(Bounty::trigger_submission_review(Origin::signed(3), 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // the bounty account can no longer cover the payment
+        assert_ok!(Tokens::withdraw(0, &Bounty::bounty_account_id(1), 15));
+        Bounty::on_finalize(10);
+        assert_eq!(Bounty::payment_retries(1), 1);
+        assert_eq!(Bounty::review_queue(20), vec![1]);
+        Bounty::on_finalize(20);
+        assert_eq!(Bounty::payment_retries(1), 2);
+        assert_eq!(Bounty::review_queue(30), vec![1]);
+        Bounty::on_finalize(30);
+        assert_eq!(Bounty::payment_retries(1), 0);
+        assert!(Bounty::review_queue(40).is_empty());
+        assert!(Bounty::submissions(1).unwrap().state().awaiting_review());
+        assert_eq!(
+            RawEvent::SubmissionPaymentRetriesExhausted(1, 1, 10),
+            get_last_event()
+        );
+        assert_eq!(Balances::total_balance(&2), 98);
+    });
+}
+
+#[test]
+fn multi_asset_bounty_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            20,
+            BountyOptions {
+                asset: Some(1),
+                ..Default::default()
+            },
+        ));
+        assert_eq!(Bounty::bounties(1).unwrap().asset(), 1);
+        assert_eq!(Tokens::free_balance(1, &1), 80);
+        assert_eq!(Tokens::free_balance(1, &Bounty::bounty_account_id(1)), 20);
+        // the native balance is untouched
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_noop!(
+            Bounty::contribute_to_bounty(Origin::signed(3), 1, 10),
+            tokens::Error::<Test>::BalanceTooLow
+        );
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_eq!(Tokens::free_balance(1, &2), 40);
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            25,
+            None
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Tokens::free_balance(1, &3), 25);
+        assert_eq!(Tokens::free_balance(1, &Bounty::bounty_account_id(1)), 5);
+        assert_eq!(Balances::total_balance(&3), 200);
+    });
+}
+
+#[test]
+fn submission_deposit_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            21,
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            5,
+            None
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            5,
+            None
+        ));
+        assert_eq!(Bounty::submissions(1).unwrap().deposit(), 2);
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_eq!(Balances::reserved_balance(&3), 2);
+        // unreserved on withdrawal
+        assert_ok!(Bounty::withdraw_submission(Origin::signed(2), 1));
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::free_balance(&2), 98);
+        // unreserved on approval
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 2));
+        assert_eq!(Balances::reserved_balance(&3), 0);
+        assert_eq!(Balances::free_balance(&3), 205);
+        // cannot submit without the deposit
+        assert_ok!(Balances::transfer(Origin::signed(5), 6, 9));
+        assert_noop!(
+            Bounty::submit_for_bounty(Origin::signed(5), 1, 12u32, 5, None),
+            sp_runtime::DispatchError::Module {
+                index: 0,
+                error: 3,
+                message: Some("InsufficientBalance",),
+            },
+        );
+    });
+}
+
+fn court_arbitration() -> ArbitrationOf<Test> {
+    VoteMetadata::Signal(VoteCall::new(
+        OrgRep::Equal(1u64),
+        Threshold::new(2u64, Some(2u64)),
+        Some(20),
+    ))
+}
+
+#[test]
+fn submission_dispute_pays_out_from_bounty() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                20,
+                BountyOptions {
+                    asset: Some(1),
+                    arbitration: Some(court_arbitration()),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::ArbitrationRequiresNativeAsset
+        );
+        // court votes without a duration would never expire
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                20,
+                BountyOptions {
+                    arbitration: org_vote_gov().vote(),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::ArbitrationRequiresVoteDuration
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            21,
+            BountyOptions {
+                arbitration: Some(court_arbitration()),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10,
+            None
+        ));
+        assert_noop!(
+            Bounty::dispute_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToDisputeSubmission
+        );
+        assert_ok!(Bounty::dispute_submission(Origin::signed(2), 1));
+        assert_eq!(
+            RawEvent::BountySubmissionDisputed(1, 1, 2, 1),
+            get_last_event()
+        );
+        // the court locks the requested amount in the bounty account
+        assert_eq!(
+            Balances::reserved_balance(&Bounty::bounty_account_id(1)),
+            10
+        );
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(1), 1),
+            Error::<Test>::SubmissionUnderDispute
+        );
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(1), 1, None),
+            Error::<Test>::SubmissionUnderDispute
+        );
+        // unresolved disputes are polled again at the next poll
+        assert_eq!(Bounty::dispute_queue(10), vec![1]);
+        Bounty::on_finalize(10);
+        assert_eq!(Bounty::dispute_queue(20), vec![1]);
+        assert!(Bounty::submission_disputes(1).is_some());
+        // the submitter raises the dispute in the court
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(Origin::signed(3), 1),
+            court::Error::<Test>::SignerNotAuthorizedToRaiseThisDispute
+        );
+        assert_ok!(Court::raise_dispute_to_trigger_vote(Origin::signed(2), 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // the court leaves the payout to the bounty
+        assert_noop!(
+            Court::poll_dispute_to_execute_outcome(Origin::signed(3), 1),
+            court::Error::<Test>::DisputeOutcomeExecutedByLocker
+        );
+        assert_eq!(Balances::free_balance(&2), 96);
+        // the bounty resolves the dispute and pays it out like an approved submission
+        Bounty::on_finalize(20);
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 11, 1, 10, 2, 10, 10),
+            get_last_event()
+        );
+        assert_eq!(
+            Balances::reserved_balance(&Bounty::bounty_account_id(1)),
+            0
+        );
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::submission_disputes(1).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 11);
+        assert_eq!(Balances::free_balance(&2), 108);
+    });
+}
+
+#[test]
+fn unraised_dispute_lapses() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            21,
+            BountyOptions {
+                arbitration: Some(court_arbitration()),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            10u32,
+            10,
+            None
+        ));
+        assert_ok!(Bounty::dispute_submission(Origin::signed(2), 1));
+        // the dispute window of 5 blocks passes without the dispute being raised
+        System::set_block_number(7);
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(Origin::signed(2), 1),
+            court::Error::<Test>::CannotRaiseDisputeAfterExpiry
+        );
+        Bounty::on_finalize(10);
+        assert_eq!(
+            RawEvent::BountySubmissionDisputeLapsed(1, 1, 1),
+            get_last_event()
+        );
+        assert!(Bounty::submission_disputes(1).is_none());
+        assert!(Bounty::submissions(1).unwrap().state().awaiting_review());
+        assert_eq!(
+            Balances::reserved_balance(&Bounty::bounty_account_id(1)),
+            0
+        );
+        // the depositer may review the submission again
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+    });
+}
+
+#[test]
+fn bounty_assignment_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            20,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_noop!(
+            Bounty::apply_to_work(Origin::signed(1), 1, 11u32),
+            Error::<Test>::DepositerCannotApplyToWork
+        );
+        assert_ok!(Bounty::apply_to_work(Origin::signed(2), 1, 11u32));
+        assert_eq!(RawEvent::BountyWorkApplied(1, 2, 11u32), get_last_event());
+        assert_noop!(
+            Bounty::assign(Origin::signed(2), 1, vec![2], 5),
+            Error::<Test>::NotAuthorizedToAssignBounty
+        );
+        assert_noop!(
+            Bounty::assign(Origin::signed(1), 1, vec![2, 3], 5),
+            Error::<Test>::AssigneeMustHaveApplied
+        );
+        assert_noop!(
+            Bounty::assign(Origin::signed(1), 1, vec![2], 1),
+            Error::<Test>::AssignmentDeadlineMustBeInTheFuture
+        );
+        assert_ok!(Bounty::assign(Origin::signed(1), 1, vec![2], 5));
+        assert_eq!(RawEvent::BountyAssigned(1, vec![2], 5), get_last_event());
+        assert!(Bounty::applications(1, 2).is_none());
+        assert_noop!(
+            Bounty::assign(Origin::signed(1), 1, vec![2], 6),
+            Error::<Test>::BountyAlreadyAssigned
+        );
+        // only assignees may submit until the work deadline
+        assert_noop!(
+            Bounty::submit_for_bounty(Origin::signed(3), 1, 12u32, 5, None),
+            Error::<Test>::BountyAssignedToOtherHunters
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            12u32,
+            5,
+            None
+        ));
+        Bounty::on_finalize(4);
+        assert!(Bounty::assignment_deadlines(1).is_some());
+        System::set_block_number(5);
+        Bounty::on_finalize(5);
+        assert_eq!(RawEvent::BountyReopened(1), get_last_event());
+        assert!(Bounty::assignees(1, 2).is_none());
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            13u32,
+            5,
+            None
+        ));
+    });
+}
+
+#[test]
+fn milestone_bounty_pays_out_per_milestone() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                30,
+                BountyOptions {
+                    milestones: Some(vec![]),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::BountyMilestonesMustBeNonEmpty
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                30,
+                BountyOptions {
+                    milestones: Some(vec![30, 0]),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::MilestoneAmountMustBeNonZero
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                30,
+                BountyOptions {
+                    milestones: Some(vec![5; 7]),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::TooManyBountyMilestones
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            30,    // funding reserved
+            BountyOptions {
+                milestones: Some(vec![10, 20]),
+                ..Default::default()
+            },
+        ));
+        assert_noop!(
+            Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 25, None),
+            Error::<Test>::SubmissionMustRequestMilestoneTotal
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            11u32,
+            30,
+            None
+        ));
+        // first milestone paid, the submission awaits review of the second
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(
+            RawEvent::BountyMilestonePaid(1, 1, 0, 10, 20),
+            get_last_event()
+        );
+        assert_eq!(Bounty::milestones_paid(1), 1);
+        let submission = Bounty::submissions(1).unwrap();
+        assert_eq!(submission.amount(), 20);
+        assert!(submission.state().awaiting_review());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 20);
+        assert_eq!(Balances::free_balance(&2), 106);
+        // milestone payouts lock contributions
+        assert_eq!(Bounty::last_payout(1), Some(1));
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(1), 1, 0),
+            Error::<Test>::CannotWithdrawContributionUntilInactivityWindowPasses
+        );
+        // the last milestone executes the payment
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(
+            RawEvent::BountyPaymentExecuted(1, 0, 1, 20, 2, 10, 11),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Bounty::milestones_paid(1), 0);
+        assert_eq!(Balances::free_balance(&2), 128);
+    });
+}
+
+#[test]
+fn member_restricted_bounty_works() {
+    new_test_ext().execute_with(|| {
+        let org = org::Module::<Test>::register_organization(
+            OrganizationSource::Accounts(vec![2]),
+            Some(1),
+            11u32,
+        )
+        .unwrap();
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            20,    // funding reserved
+            BountyOptions {
+                eligible: Some(org), // only members may submit
+                ..Default::default()
+            },
+        ));
+        assert_eq!(Bounty::bounty_eligibility(1), Some(org));
+        // only members find the bounty among the open bounties
+        assert!(Bounty::open_bounties(0, None, vec![], 0, 10).is_empty());
+        assert!(Bounty::open_bounties(0, Some(3), vec![], 0, 10).is_empty());
+        assert_eq!(Bounty::open_bounties(0, Some(2), vec![], 0, 10).len(), 1);
+        assert_noop!(
+            Bounty::submit_for_bounty(Origin::signed(3), 1, 11u32, 10, None),
+            Error::<Test>::NotEligibleToSubmit
+        );
+        assert_noop!(
+            Bounty::apply_to_work(Origin::signed(3), 1, 11u32),
+            Error::<Test>::NotEligibleToSubmit
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            11u32,
+            10,
+            None
+        ));
+    });
+}
+
+#[test]
+fn bounty_outcomes_build_reputation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            20,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            10,
+            None
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(Reputation::reputations(3).approved(), 1);
+        assert_eq!(Reputation::reputations(3).earned(), 10);
+        assert_eq!(Reputation::reputations(1).paid_out(), 10);
+        assert_eq!(Reputation::score(&3), 10);
+        assert_eq!(Reputation::score(&1), 3);
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            12u32,
+            20,
+            BountyOptions {
+                min_reputation: Some(10), // minimum reputation of submitters
+                ..Default::default()
+            },
+        ));
+        assert_eq!(Bounty::bounty_min_reputation(2), Some(10));
+        assert_noop!(
+            Bounty::submit_for_bounty(Origin::signed(4), 2, 13u32, 10, None),
+            Error::<Test>::ReputationBelowBountyMinimum
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            2,
+            13u32,
+            10,
+            None
+        ));
+        assert_ok!(Bounty::reject_bounty_submission(
+            Origin::signed(1),
+            2,
+            None
+        ));
+        assert_eq!(Reputation::reputations(3).rejected(), 1);
+        assert_eq!(Reputation::score(&3), 5);
+        let depositer = Reputation::reputations(1);
+        assert_eq!((depositer.posted(), depositer.paid()), (2, 1));
+    });
+}
+
+#[test]
+fn unreviewed_submission_is_auto_approved() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                20,
+                BountyOptions {
+                    review_window: Some(0),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::ReviewWindowMustBeNonZero
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            20,    // funding reserved
+            BountyOptions {
+                review_window: Some(5),
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            10,
+            None
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(4),
+            1,
+            12u32,
+            5,
+            None
+        ));
+        assert_eq!(Bounty::review_deadlines(1), Some(6));
+        assert_eq!(Bounty::review_expiries(6), vec![1, 2]);
+        // reviewed submissions are not approved automatically
+        assert_ok!(Bounty::reject_bounty_submission(
+            Origin::signed(1),
+            2,
+            None
+        ));
+        Bounty::on_finalize(5);
+        assert!(Bounty::submissions(1).is_some());
+        Bounty::on_finalize(6);
+        assert_eq!(RawEvent::SubmissionAutoApproved(1, 1, 3), get_last_event());
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::review_deadlines(1).is_none());
+        assert_eq!(Balances::free_balance(&3), 210);
+        assert_eq!(Balances::free_balance(&4), 73);
+        // the slashed deposit of the rejected submission stays in the bounty
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 12);
+    });
+}
+
+#[test]
+fn team_submission_payout_splits_across_org() {
+    new_test_ext().execute_with(|| {
+        let team = org::Module::<Test>::register_organization(
+            OrganizationSource::Accounts(vec![3, 4]),
+            Some(2),
+            11u32,
+        )
+        .unwrap();
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            20,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(2),
+            1,
+            12u32,
+            11,
+            Some(Recipient::new(2, OrgRep::Equal(team))),
+        ));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        let mut transfers =
+            System::events()
+                .into_iter()
+                .find_map(|r| {
+                    if let TestEvent::bounty(
+                        RawEvent::BountyTeamPaymentExecuted(1, 1, t),
+                    ) = r.event
+                    {
+                        Some(t)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap();
+        transfers.sort();
+        // split equally, the remainder goes to the recipient account
+        assert_eq!(transfers, vec![(2, 1), (3, 5), (4, 5)]);
+        assert_eq!(Balances::free_balance(&3), 205);
+        assert_eq!(Balances::free_balance(&4), 80);
+        assert_eq!(Balances::free_balance(&2), 99);
+        assert!(Bounty::submission_recipients(1).is_none());
+    });
+}
+
+#[test]
+fn contribution_withdrawal_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            10,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(3), 1, 5),
+            Error::<Test>::NotAContributor
+        );
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(2), 1, 11),
+            Error::<Test>::WithdrawalExceedsContribution
+        );
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            15,
+            None
+        ));
+        // 5 of the 20 are not requested by pending submissions
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(2), 1, 6),
+            Error::<Test>::WithdrawalWouldUnderfundPendingSubmissions
+        );
+        assert_ok!(Bounty::withdraw_contribution(Origin::signed(2), 1, 5));
+        assert_eq!(
+            RawEvent::ContributionWithdrawn(1, 2, 5, 15),
+            get_last_event()
+        );
+        assert_eq!(Bounty::bounty_tips(1, 2), Some(5));
+        assert_eq!(Bounty::total_tipped(1), 15);
+        assert_eq!(Balances::free_balance(&2), 93);
+        // contributions are locked after a payout until the bounty is inactive
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_noop!(
+            Bounty::withdraw_contribution(Origin::signed(2), 1, 0),
+            Error::<Test>::CannotWithdrawContributionUntilInactivityWindowPasses
+        );
+        System::set_block_number(11);
+        assert_ok!(Bounty::withdraw_contribution(Origin::signed(2), 1, 0));
+    });
+}
+
+#[test]
+fn withdrawal_shares_slashed_deposits_pro_rata() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            10,    // funding reserved
+            BountyOptions::default(),
+        ));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(2), 1, 10));
+        assert_ok!(Bounty::submit_for_bounty(
+            Origin::signed(3),
+            1,
+            11u32,
+            5,
+            None
+        ));
+        // the slashed deposit of 2 is shared by both contributors
+        assert_ok!(Bounty::reject_bounty_submission(
+            Origin::signed(1),
+            1,
+            None
+        ));
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 22);
+        assert_ok!(Bounty::withdraw_contribution(Origin::signed(2), 1, 10));
+        assert_eq!(
+            RawEvent::ContributionWithdrawn(1, 2, 11, 11),
+            get_last_event()
+        );
+        assert!(Bounty::bounty_tips(1, 2).is_none());
+        assert_eq!(Bounty::total_tipped(1), 10);
+        assert_eq!(Balances::free_balance(&2), 99);
+        assert_ok!(Bounty::withdraw_contribution(Origin::signed(1), 1, 10));
+        assert_eq!(
+            RawEvent::ContributionWithdrawn(1, 1, 11, 0),
+            get_last_event()
+        );
+        assert_eq!(Balances::free_balance(&Bounty::bounty_account_id(1)), 0);
+    });
+}
+
+/// Stores the value under the `blake2_128_concat` key as a baseline release would
+fn put_v0<K: Encode, V: Encode>(item: &[u8], key: K, value: V) {
+    put_storage_value(
+        b"Bounty",
+        item,
+        &Blake2_128Concat::hash(&key.encode()),
+        value,
+    );
+}
+
+#[test]
+fn migration_to_bounty2_works() {
+    new_test_ext().execute_with(|| {
+        <StorageVersion>::put(Releases::V0);
+        // bounties, tips and submissions as stored before the release
+        put_v0(
+            b"Bounties",
+            1u64,
+            v0::BountyInformation {
+                info: 10u32,
+                depositer: 1u64,
+                total: 20u64,
+            },
+        );
+        <BountyTips<Test>>::insert(1, 1, 10);
+        <BountyTips<Test>>::insert(1, 2, 10);
+        let old_account = Bounty::bounty_account_id(1);
+        assert_ok!(Balances::transfer(Origin::signed(1), old_account, 20));
+        put_v0(
+            b"Submissions",
+            1u64,
+            v0::BountySubmission {
+                bounty: 1u64,
+                submission_ref: 11u32,
+                submitter: 3u64,
+                amount: 15u64,
+                state: v0::SubmissionState::SubmittedAwaitingResponse,
+            },
+        );
+        put_v0(
+            b"Submissions",
+            2u64,
+            v0::BountySubmission {
+                bounty: 1u64,
+                submission_ref: 12u32,
+                submitter: 4u64,
+                amount: 5u64,
+                state: v0::SubmissionState::ApprovedAndExecuted,
+            },
+        );
+        // bounty2 cannot hold the eligibility of this bounty
+        put_v0(
+            b"Bounties",
+            2u64,
+            v0::BountyInformation {
+                info: 13u32,
+                depositer: 1u64,
+                total: 10u64,
+            },
+        );
+        put_v0(
+            b"Submissions",
+            3u64,
+            v0::BountySubmission {
+                bounty: 2u64,
+                submission_ref: 14u32,
+                submitter: 3u64,
+                amount: 5u64,
+                state: v0::SubmissionState::SubmittedAwaitingResponse,
+            },
+        );
+        <BountyEligibility<Test>>::insert(2, 1);
+        MigrateToBounty2::<Test>::on_runtime_upgrade();
+        assert_eq!(Bounty::storage_version(), Releases::V1);
+        // bounty moved with its tips and default challenge norms
+        let bounty = Bounty2::bounties(1).unwrap();
+        assert_eq!(bounty.info(), 10);
+        assert_eq!(bounty.total(), 20);
+        assert_eq!(bounty.gov().leader(), 1);
+        assert_eq!(bounty.gov().veto_threshold(), MinVetoThreshold::get());
+        assert_eq!(Bounty2::bounty_tips(1, 1), Some(10));
+        assert_eq!(Bounty2::bounty_tips(1, 2), Some(10));
+        assert_eq!(Bounty2::contributor_count(1), 2);
+        // only the pending submission moved
+        let submission = Bounty2::submissions(1).unwrap();
+        assert_eq!(submission.bounty_id(), 1);
+        assert_eq!(submission.submitter(), 3);
+        assert_eq!(submission.amount(), 15);
+        assert!(Bounty2::submissions(2).is_none());
+        assert_eq!(Bounty2::bounty_submissions(1, 1), Some(()));
+        // funds stay at the bounty account, which bounty2 pays out of
+        assert_eq!(Bounty2::bounty_account_id(1), old_account);
+        assert_eq!(Balances::free_balance(&old_account), 20);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::submissions(2).is_none());
+        assert_eq!(Bounty::submission_count(1), 0);
+        assert!(Bounty::bounty_tips(1, 2).is_none());
+        // the other bounty stays in the current layout
+        let kept = Bounty::bounties(2).unwrap();
+        assert_eq!(kept.info(), 13);
+        assert_eq!(kept.asset(), NativeAsset::get());
+        assert_eq!(kept.total(), 10);
+        assert_eq!(Bounty::bounty_eligibility(2), Some(1));
+        let kept_submissions = Bounty::submissions_for(2);
+        assert_eq!(kept_submissions.len(), 1);
+        assert_eq!(Bounty::submission_count(2), 1);
+        assert_eq!(kept_submissions[0].1.submitter(), 3);
+        assert!(kept_submissions[0].1.state().awaiting_review());
+        // the migration runs only once
+        MigrateToBounty2::<Test>::on_runtime_upgrade();
+        assert!(Bounty2::bounties(2).is_none());
+        assert!(Bounty::bounties(2).is_some());
+    });
+}
+
+#[test]
+fn bounties_are_indexed_by_tag() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                10,
+                BountyOptions {
+                    tags: vec![
+                        b"a".to_vec(),
+                        b"b".to_vec(),
+                        b"c".to_vec(),
+                        b"d".to_vec()
+                    ],
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::TooManyBountyTags
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                10,
+                BountyOptions {
+                    tags: vec![vec![0u8; 17]],
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::BountyTagTooLong
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                10,
+                BountyOptions {
+                    tags: vec![vec![]],
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::BountyTagMustBeNonEmpty
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32, // constitution
+            10,    // funding reserved
+            BountyOptions {
+                expiry: Some(5),
+                // duplicates are dropped before the bound is checked
+                tags: vec![
+                    b"rust".to_vec(),
+                    b"frontend".to_vec(),
+                    b"rust".to_vec(),
+                    b"docs".to_vec()
+                ],
+                ..Default::default()
+            },
+        ));
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(2),
+            11u32,
+            10,
+            BountyOptions {
+                tags: vec![b"rust".to_vec()],
+                ..Default::default()
+            },
+        ));
+        assert_eq!(
+            Bounty::bounty_tags(1),
+            vec![b"docs".to_vec(), b"frontend".to_vec(), b"rust".to_vec()]
+        );
+        let mut rust = Bounty::bounties_tagged(b"rust".to_vec());
+        rust.sort();
+        assert_eq!(rust, vec![1, 2]);
+        assert_eq!(Bounty::bounties_tagged(b"docs".to_vec()), vec![1]);
+        // open bounties carry every tag
+        let tagged = |tags: Vec<&[u8]>| {
+            let tags = tags.into_iter().map(|t| t.to_vec()).collect();
+            Bounty::open_bounties(0, None, tags, 0, 10)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<u64>>()
+        };
+        assert_eq!(tagged(vec![b"rust", b"docs"]), vec![1]);
+        assert_eq!(tagged(vec![b"rust"]).len(), 2);
+        assert!(tagged(vec![b"docs", b"go"]).is_empty());
+        // removed bounties leave the index
+        System::set_block_number(5);
+        Bounty::on_initialize(5);
+        assert!(Bounty::bounties(1).is_none());
+        assert!(Bounty::bounty_tags(1).is_empty());
+        assert_eq!(Bounty::bounties_tagged(b"rust".to_vec()), vec![2]);
+        assert!(Bounty::bounties_tagged(b"docs".to_vec()).is_empty());
+    });
+}
@@ -0,0 +1,124 @@
+//! Bounty2 pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const MAX_CONTRIBUTORS: u32 = 100;
+
+/// Funds `depositer` and posts a bounty worth twice the minimum deposit, which
+/// `c - 1` other accounts contribute to
+fn bounty<T: Trait>(
+    depositer: &T::AccountId,
+    c: u32,
+) -> Result<T::BountyId, DispatchError> {
+    let amount = T::MinDeposit::get() + T::MinDeposit::get();
+    T::Currency::make_free_balance_be(depositer, amount + amount);
+    <Module<T>>::post_bounty(
+        RawOrigin::Signed(depositer.clone()).into(),
+        T::IpfsReference::default(),
+        amount,
+        T::MinVetoThreshold::get(),
+        T::MinRefundThreshold::get(),
+    )?;
+    let bounty_id = <BountyNonce<T>>::get();
+    for i in 1..c {
+        let contributor: T::AccountId = account("contributor", i, SEED);
+        let tip = T::MinContribution::get();
+        T::Currency::make_free_balance_be(&contributor, tip + tip);
+        <Module<T>>::contribute_to_bounty(
+            RawOrigin::Signed(contributor).into(),
+            bounty_id,
+            tip,
+        )?;
+    }
+    Ok(bounty_id)
+}
+
+/// Posts a bounty with `c` contributors and a submission for the minimum deposit
+fn submission<T: Trait>(
+    depositer: &T::AccountId,
+    c: u32,
+) -> Result<T::SubmissionId, DispatchError> {
+    let bounty_id = bounty::<T>(depositer, c)?;
+    let submitter: T::AccountId = account("submitter", 0, SEED);
+    <Module<T>>::submit_for_bounty(
+        RawOrigin::Signed(submitter).into(),
+        bounty_id,
+        T::IpfsReference::default(),
+        T::MinDeposit::get(),
+    )?;
+    Ok(<SubmissionNonce<T>>::get())
+}
+
+benchmarks! {
+    _ { }
+
+    post_bounty {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinDeposit::get();
+        T::Currency::make_free_balance_be(&caller, amount + amount);
+    }: _(RawOrigin::Signed(caller), T::IpfsReference::default(), amount, T::MinVetoThreshold::get(), T::MinRefundThreshold::get())
+    verify {
+        assert!(<Bounties<T>>::get(<BountyNonce<T>>::get()).is_some());
+    }
+
+    contribute_to_bounty {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(&depositer, 1)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinContribution::get();
+        T::Currency::make_free_balance_be(&caller, amount + amount);
+    }: _(RawOrigin::Signed(caller.clone()), bounty_id, amount)
+    verify {
+        assert!(<BountyTips<T>>::get(bounty_id, &caller).is_some());
+    }
+
+    submit_for_bounty {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(&depositer, 1)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+    }: _(RawOrigin::Signed(caller), bounty_id, T::IpfsReference::default(), T::MinDeposit::get())
+    verify {
+        assert!(<Submissions<T>>::get(<SubmissionNonce<T>>::get()).is_some());
+    }
+
+    approve_bounty_submission {
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let submission_id = submission::<T>(&depositer, 1)?;
+    }: _(RawOrigin::Signed(depositer), submission_id)
+    verify {
+        let sub = <Submissions<T>>::get(submission_id).unwrap();
+        assert!(sub.state().approved_and_scheduled().is_some());
+    }
+
+    // a contributor challenging the approval opens a vote of all contributors
+    reject_bounty_submission {
+        let c in 1 .. MAX_CONTRIBUTORS;
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let submission_id = submission::<T>(&depositer, c)?;
+        <Module<T>>::approve_bounty_submission(
+            RawOrigin::Signed(depositer.clone()).into(),
+            submission_id,
+        )?;
+    }: _(RawOrigin::Signed(depositer), submission_id)
+    verify {
+        let sub = <Submissions<T>>::get(submission_id).unwrap();
+        assert!(sub.state().under_review().is_some());
+    }
+
+    trigger_refund_vote {
+        let c in 1 .. MAX_CONTRIBUTORS;
+        let depositer: T::AccountId = account("depositer", 0, SEED);
+        let bounty_id = bounty::<T>(&depositer, c)?;
+    }: _(RawOrigin::Signed(depositer), bounty_id)
+    verify {
+        let bounty = <Bounties<T>>::get(bounty_id).unwrap();
+        assert!(bounty.state() != BountyState::NoPendingChallenges);
+    }
+}
@@ -4,6 +4,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 //! Bounty pallet with refundable contributions and more contributor voting rights
 
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -23,6 +29,7 @@ use frame_support::{
         WithdrawReason,
         WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -54,22 +61,27 @@ use util::{
     share::SimpleShareGenesis,
     traits::{
         AccessGenesis,
+        CheckVoteStatus,
         GetVoteOutcome,
+        OpenVote,
+    },
+    vote::{
+        Threshold,
+        VoteOutcome,
     },
-    vote::VoteOutcome,
 };
 
 // type aliases
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+pub type BalanceOf<T> = <<T as Trait>::Currency as Currency<
     <T as frame_system::Trait>::AccountId,
 >>::Balance;
-type Bounty<T> = BountyInfo2<
+pub type Bounty<T> = BountyInfo2<
     <T as vote::Trait>::IpfsReference,
     ChallengeNorms<<T as frame_system::Trait>::AccountId, Permill>,
     BalanceOf<T>,
     BountyState<<T as vote::Trait>::VoteId>,
 >;
-type BountySub<T> = BountySubmission<
+pub type BountySub<T> = BountySubmission<
     <T as Trait>::BountyId,
     <T as vote::Trait>::IpfsReference,
     <T as frame_system::Trait>::AccountId,
@@ -84,8 +96,8 @@ pub trait Trait: frame_system::Trait + vote::Trait {
     /// The overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
-    /// The currency type
-    type Currency: Currency<Self::AccountId>
+    /// The currency type, contributions are used as signal in contributor votes
+    type Currency: Currency<Self::AccountId, Balance = Self::Signal>
         + ReservableCurrency<Self::AccountId>;
 
     /// The bounty post identifier
@@ -131,6 +143,45 @@ pub trait Trait: frame_system::Trait + vote::Trait {
 
     /// Minimum refund threshold
     type MinRefundThreshold: Get<Permill>;
+
+    /// Maximum number of queued payouts and challenge votes processed per block
+    type MaxPollsPerBlock: Get<u32>;
+
+    /// Number of times a failed payout is rescheduled before the approval is dropped
+    type MaxPaymentRetries: Get<u32>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn post_bounty() -> Weight;
+    fn contribute_to_bounty() -> Weight;
+    fn submit_for_bounty() -> Weight;
+    fn approve_bounty_submission() -> Weight;
+    fn reject_bounty_submission(c: u32) -> Weight;
+    fn trigger_refund_vote(c: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn post_bounty() -> Weight {
+        1_000_000_000
+    }
+    fn contribute_to_bounty() -> Weight {
+        1_000_000_000
+    }
+    fn submit_for_bounty() -> Weight {
+        1_000_000_000
+    }
+    fn approve_bounty_submission() -> Weight {
+        1_000_000_000
+    }
+    fn reject_bounty_submission(_c: u32) -> Weight {
+        1_000_000_000
+    }
+    fn trigger_refund_vote(_c: u32) -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -141,6 +192,7 @@ decl_event!(
         <T as vote::Trait>::VoteId,
         <T as Trait>::BountyId,
         <T as Trait>::SubmissionId,
+        <T as frame_system::Trait>::BlockNumber,
         Balance = BalanceOf<T>,
     {
         /// Poster, Initial Amount, Identifier, Bounty Metadata (i.e. github issue reference)
@@ -152,9 +204,22 @@ decl_event!(
         BountyRefunded(BountyId, Balance, Balance),
         /// Submitter, Bounty Identifier, Amount Requested, Submission Identifier, Bounty Metadata, Submission Metadata
         BountySubmissionPosted(AccountId, BountyId, Balance, SubmissionId, IpfsReference, IpfsReference),
+        /// Submission Identifier, Bounty Identifier, Block after which payment is executed if unchallenged
+        SubmissionApprovedAndScheduled(SubmissionId, BountyId, BlockNumber),
+        /// Submission Identifier, Bounty Identifier, Submission Metadata
+        SubmissionRejected(SubmissionId, BountyId, IpfsReference),
+        /// Challenger, Submission Identifier, Bounty Identifier, Veto Vote Identifier
+        SubmissionApprovalChallenged(AccountId, SubmissionId, BountyId, VoteId),
+        /// Challenger, Bounty Identifier, Refund Vote Identifier
+        BountyRefundVoteTriggered(AccountId, BountyId, VoteId),
         /// Submission Identifier, Bounty Identifier, Requested Amount
         SubmissionApprovedButPaymentFailed(SubmissionId, BountyId, Balance),
         /// Submission Identifier, Bounty Identifier, Requested Amount
+        /// -> the submission is reset to await a new response from the leader
+        SubmissionPaymentRetriesExhausted(SubmissionId, BountyId, Balance),
+        /// Vote identifier for a challenge whose outcome could not be read, the challenge does not pass
+        ChallengeVoteOutcomeUnavailable(VoteId),
+        /// Submission Identifier, Bounty Identifier, Requested Amount
         SpendChallengePassedAndSubmissionRejected(SubmissionId, BountyId, Balance),
         /// Vote identifier for Challenge Results, Bounty Identifier, Amount Posted
         BountyRefundChallengeRejected(VoteId, BountyId, Balance),
@@ -175,11 +240,18 @@ decl_error! {
         SubmissionNotInValidStateToApprove,
         CannotApproveSubmissionIfAmountExceedsTotalAvailable,
         NotAuthorizedToApproveBountySubmissions,
+        VetoThresholdBelowModuleMin,
+        RefundThresholdBelowModuleMin,
+        NotAuthorizedToRejectBountySubmissions,
+        SubmissionNotInValidStateToReject,
+        NotAuthorizedToChallengeSubmissionApproval,
+        OnlyContributorsCanTriggerRefundVote,
+        RefundVoteAlreadyPendingForBounty,
     }
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Bounty {
+    trait Store for Module<T: Trait> as Bounty2 {
         /// Uid generation helper for BountyId
         BountyNonce get(fn bounty_nonce): T::BountyId;
 
@@ -193,14 +265,38 @@ decl_storage! {
         pub BountyTips get(fn bounty_tips): double_map
             hasher(blake2_128_concat) T::BountyId,
             hasher(blake2_128_concat) T::AccountId => Option<BalanceOf<T>>;
+        /// Number of contributors to each bounty, who vote on its challenges
+        pub ContributorCount get(fn contributor_count): map
+            hasher(blake2_128_concat) T::BountyId => u32;
+
+        /// Accounts holding the funds of bounties migrated from the bounty pallet
+        pub BountyAccounts get(fn bounty_accounts): map
+            hasher(blake2_128_concat) T::BountyId => Option<T::AccountId>;
 
         /// Posted Submissions
         pub Submissions get(fn submissions): map
             hasher(blake2_128_concat) T::SubmissionId => Option<BountySub<T>>;
+        /// Index of the submissions for each bounty
+        pub BountySubmissions get(fn bounty_submissions): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::SubmissionId => Option<()>;
+
+        /// Submissions with payouts scheduled for execution at the given block
+        pub PayoutQueue get(fn payout_queue): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::SubmissionId>;
+        /// Number of failed payout attempts for scheduled submissions
+        pub PaymentRetries get(fn payment_retries): map
+            hasher(blake2_128_concat) T::SubmissionId => u32;
+        /// Submissions with veto votes to poll at the given block
+        pub SubmissionChallengeQueue get(fn submission_challenge_queue): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::SubmissionId>;
+        /// Bounties with refund votes to poll at the given block
+        pub RefundChallengeQueue get(fn refund_challenge_queue): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::BountyId>;
 
         /// Every this period, poll the status of refund vote challenges and push results
         pub BountyPollFrequency get(fn bounty_poll_frequency) config(): T::BlockNumber;
-        /// Every this period, poll contributor veto votes against submission approvals and push results
+        /// Every this period, poll contributor veto votes against submission approvals and retry failed payouts
         pub SubmissionPollFrequency get(fn submission_poll_frequency) config(): T::BlockNumber;
     }
 }
@@ -210,7 +306,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::post_bounty()]
         fn post_bounty(
             origin,
             info: T::IpfsReference,
@@ -220,21 +316,24 @@ decl_module! {
         ) -> DispatchResult {
             let depositer = ensure_signed(origin)?;
             ensure!(amount >= T::MinDeposit::get(), Error::<T>::BountyPostMustExceedMinDeposit);
+            ensure!(veto_threshold >= T::MinVetoThreshold::get(), Error::<T>::VetoThresholdBelowModuleMin);
+            ensure!(refund_threshold >= T::MinRefundThreshold::get(), Error::<T>::RefundThresholdBelowModuleMin);
             let imb = T::Currency::withdraw(
                 &depositer,
                 amount,
                 WithdrawReasons::from(WithdrawReason::Transfer),
                 ExistenceRequirement::AllowDeath,
             )?;
-            let bounty = Bounty::<T>::new(info, ChallengeNorms::new(depositer.clone(), veto_threshold, refund_threshold), amount);
+            let bounty = Bounty::<T>::new(info.clone(), ChallengeNorms::new(depositer.clone(), veto_threshold, refund_threshold), amount);
             let id = Self::bounty_generate_uid();
             T::Currency::resolve_creating(&Self::bounty_account_id(id), imb);
             <Bounties<T>>::insert(id, bounty);
             <BountyTips<T>>::insert(id, &depositer, amount);
+            <ContributorCount<T>>::insert(id, 1);
             Self::deposit_event(RawEvent::BountyPosted(depositer, amount, id, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::contribute_to_bounty()]
         fn contribute_to_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -252,6 +351,7 @@ decl_module! {
             let new_amount = if let Some(a) = <BountyTips<T>>::get(bounty_id, &contributor) {
                 amount + a
             } else {
+                <ContributorCount<T>>::mutate(bounty_id, |n| *n = n.saturating_add(1));
                 amount
             };
             let new_bounty = bounty.add_funds(amount);
@@ -261,7 +361,7 @@ decl_module! {
             Self::deposit_event(RawEvent::BountyRaiseContribution(contributor, amount, bounty_id, total, bounty.info()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_for_bounty()]
         fn submit_for_bounty(
             origin,
             bounty_id: T::BountyId,
@@ -272,93 +372,151 @@ decl_module! {
             let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
             ensure!(submitter != bounty.gov().leader(), Error::<T>::DepositerCannotSubmitForBounty);
             ensure!(amount <= bounty.total(), Error::<T>::BountySubmissionExceedsTotalAvailableFunding);
-            let submission = BountySub::<T>::new(bounty_id, submission_ref, submitter.clone(), amount);
+            // bounty2 submissions do not take a deposit
+            let submission = BountySub::<T>::new(bounty_id, submission_ref.clone(), submitter.clone(), amount, BalanceOf::<T>::zero());
             let id = Self::submission_generate_uid();
             <Submissions<T>>::insert(id, submission);
+            <BountySubmissions<T>>::insert(bounty_id, id, ());
             Self::deposit_event(RawEvent::BountySubmissionPosted(submitter, bounty_id, amount, id, bounty.info(), submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_bounty_submission()]
         fn approve_bounty_submission(
             origin,
             submission_id: T::SubmissionId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let approver = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            ensure!(submission.state().awaiting_review(), Error::<T>::SubmissionNotInValidStateToApprove);
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(approver == bounty.gov().leader(), Error::<T>::NotAuthorizedToApproveBountySubmissions);
+            ensure!(bounty.total() >= submission.amount(), Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable);
+            // payment is scheduled for after the challenge period
+            let scheduled = <frame_system::Module<T>>::block_number() + T::ChallengePeriod::get();
+            <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState2::ApprovedAndScheduled(scheduled)));
+            <PayoutQueue<T>>::append(scheduled, submission_id);
+            Self::deposit_event(RawEvent::SubmissionApprovedAndScheduled(submission_id, bounty_id, scheduled));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reject_bounty_submission(Self::challenge_voter_count(*submission_id))]
         fn reject_bounty_submission(
             origin,
             submission_id: T::SubmissionId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let caller = ensure_signed(origin)?;
+            let submission = <Submissions<T>>::get(submission_id).ok_or(Error::<T>::SubmissionDNE)?;
+            let bounty_id = submission.bounty_id();
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            match submission.state() {
+                SubmissionState2::SubmittedAwaitingResponse => {
+                    // the leader rejects the submission outright
+                    ensure!(caller == bounty.gov().leader(), Error::<T>::NotAuthorizedToRejectBountySubmissions);
+                    <Submissions<T>>::remove(submission_id);
+                    <BountySubmissions<T>>::remove(bounty_id, submission_id);
+                    Self::deposit_event(RawEvent::SubmissionRejected(submission_id, bounty_id, submission.submission()));
+                }
+                SubmissionState2::ApprovedAndScheduled(_) => {
+                    // a contributor challenges the leader's approval with a veto vote
+                    ensure!(<BountyTips<T>>::get(bounty_id, &caller).is_some(), Error::<T>::NotAuthorizedToChallengeSubmissionApproval);
+                    let vote_id = Self::open_contributor_vote(bounty_id, submission.submission(), bounty.gov().veto_threshold())?;
+                    <Submissions<T>>::insert(submission_id, submission.set_state(SubmissionState2::ChallengedAndUnderReview(vote_id)));
+                    let now = <frame_system::Module<T>>::block_number();
+                    <SubmissionChallengeQueue<T>>::append(Self::next_poll(now, Self::submission_poll_frequency()), submission_id);
+                    Self::deposit_event(RawEvent::SubmissionApprovalChallenged(caller, submission_id, bounty_id, vote_id));
+                }
+                SubmissionState2::ChallengedAndUnderReview(_) => return Err(Error::<T>::SubmissionNotInValidStateToReject.into()),
+            }
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_refund_vote(<ContributorCount<T>>::get(bounty_id))]
         fn trigger_refund_vote(
             origin,
             bounty_id: T::BountyId,
         ) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
+            let contributor = ensure_signed(origin)?;
+            let bounty = <Bounties<T>>::get(bounty_id).ok_or(Error::<T>::BountyDNE)?;
+            ensure!(<BountyTips<T>>::get(bounty_id, &contributor).is_some(), Error::<T>::OnlyContributorsCanTriggerRefundVote);
+            ensure!(bounty.state() == BountyState::NoPendingChallenges, Error::<T>::RefundVoteAlreadyPendingForBounty);
+            let vote_id = Self::open_contributor_vote(bounty_id, bounty.info(), bounty.gov().refund_threshold())?;
+            <Bounties<T>>::insert(bounty_id, bounty.set_state(BountyState::ChallengedToClose(vote_id)));
+            let now = <frame_system::Module<T>>::block_number();
+            <RefundChallengeQueue<T>>::append(Self::next_poll(now, Self::bounty_poll_frequency()), bounty_id);
+            Self::deposit_event(RawEvent::BountyRefundVoteTriggered(contributor, bounty_id, vote_id));
             Ok(())
         }
-        fn on_finalize(_n: T::BlockNumber) {
-            let now = <frame_system::Module<T>>::block_number();
-            // poll submissions and execute approved and scheduled submissions
-            if now % Self::submission_poll_frequency() == Zero::zero() {
-                for (sub_id, sub) in <Submissions<T>>::iter() {
-                    match sub.state() {
-                        SubmissionState2::ApprovedAndScheduled(n) => {
-                            if now >= n {
-                                // TODO: make this path infallible
-                                // approve and execute submission payment
-                                if !Self::approve_and_execute_payment(sub_id).is_ok() {
-                                    Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(sub_id, sub.bounty_id(), sub.amount()));
+        fn on_finalize(n: T::BlockNumber) {
+            let mut budget = T::MaxPollsPerBlock::get();
+            // execute payouts scheduled for this block
+            let (payouts, deferred) = Self::split_for_budget(<PayoutQueue<T>>::take(n), &mut budget);
+            deferred.into_iter().for_each(|id| <PayoutQueue<T>>::append(n + 1u32.into(), id));
+            for id in payouts {
+                if let Some(sub) = <Submissions<T>>::get(id) {
+                    if matches!(sub.state().approved_and_scheduled(), Some(at) if at <= n) {
+                        Self::pay_or_retry(n, id, sub);
+                    }
+                }
+            }
+            // poll contributor veto votes against submission approvals
+            let (challenges, deferred) = Self::split_for_budget(<SubmissionChallengeQueue<T>>::take(n), &mut budget);
+            deferred.into_iter().for_each(|id| <SubmissionChallengeQueue<T>>::append(n + 1u32.into(), id));
+            for id in challenges {
+                if let Some(sub) = <Submissions<T>>::get(id) {
+                    if let Some(v) = sub.state().under_review() {
+                        match <vote::Module<T>>::get_vote_outcome(v) {
+                            Ok(VoteOutcome::Approved) => {
+                                // => the submission is rejected because this vote was a challenge to an approval by the depositer
+                                <Submissions<T>>::remove(id);
+                                <BountySubmissions<T>>::remove(sub.bounty_id(), id);
+                                Self::deposit_event(RawEvent::SpendChallengePassedAndSubmissionRejected(id, sub.bounty_id(), sub.amount()));
+                            }
+                            // => the submission is approved because this vote was a challenge to an approval by the depositer
+                            Ok(VoteOutcome::Rejected) => Self::pay_or_retry(n, id, sub),
+                            Ok(_) => {
+                                if Self::vote_expired(v) {
+                                    // => the veto did not reach threshold support before expiry so the approval stands
+                                    Self::pay_or_retry(n, id, sub);
+                                } else {
+                                    <SubmissionChallengeQueue<T>>::append(Self::next_poll(n, Self::submission_poll_frequency()), id);
                                 }
                             }
-                        }
-                        SubmissionState2::ChallengedAndUnderReview(v) => {
-                            let status = <vote::Module<T>>::get_vote_outcome(v).expect("dispatched votes are never cleared by default, qed");
-                            match status {
-                                VoteOutcome::Approved => {
-                                    // => the submission is rejected because this vote was a challenge to an approval by the depositer
-                                    <Submissions<T>>::remove(sub_id);
-                                    Self::deposit_event(RawEvent::SpendChallengePassedAndSubmissionRejected(sub_id, sub.bounty_id(), sub.amount()));
-                                },
-                                VoteOutcome::Rejected => {
-                                    // TODO: make this path infallible
-                                    // => the submission is approved because this vote was a challenge to an approval by the depositer
-                                    if !Self::approve_and_execute_payment(sub_id).is_ok() {
-                                        Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(sub_id, sub.bounty_id(), sub.amount()));
-                                    }
-                                },
-                                _ => (),
+                            Err(_) => {
+                                // => a veto that cannot be read does not pass
+                                Self::deposit_event(RawEvent::ChallengeVoteOutcomeUnavailable(v));
+                                Self::pay_or_retry(n, id, sub);
                             }
                         }
-                        _ => (),
                     }
                 }
             }
-            // poll bounties and execute refunds for bounties under contributor vote to refund
-            if now % Self::bounty_poll_frequency() == Zero::zero() {
-                for (bid, bty) in <Bounties<T>>::iter() {
+            // poll contributor votes to refund bounties
+            let (refunds, deferred) = Self::split_for_budget(<RefundChallengeQueue<T>>::take(n), &mut budget);
+            deferred.into_iter().for_each(|id| <RefundChallengeQueue<T>>::append(n + 1u32.into(), id));
+            for id in refunds {
+                if let Some(bty) = <Bounties<T>>::get(id) {
                     if let BountyState::ChallengedToClose(v) = bty.state() {
-                        let status = <vote::Module<T>>::get_vote_outcome(v).expect("dispatched votes are never cleared by default, qed");
-                        match status {
-                            VoteOutcome::Approved => {
+                        match <vote::Module<T>>::get_vote_outcome(v) {
+                            Ok(VoteOutcome::Approved) => {
                                 // => the refund is executed
-                                if let Ok((amt_to_contributors, amt_to_depositer)) = Self::execute_refund(bid, &bty.gov().leader()) {
-                                    Self::deposit_event(RawEvent::BountyRefunded(bid, amt_to_contributors, amt_to_depositer));
+                                let (amt_to_contributors, amt_to_depositer) = Self::execute_refund(id, &bty.gov().leader());
+                                Self::recursive_remove_bounty(id);
+                                Self::deposit_event(RawEvent::BountyRefunded(id, amt_to_contributors, amt_to_depositer));
+                            }
+                            // => the refund is not executed and the bty state is reset to NoPendingChallenges until next challenge
+                            Ok(VoteOutcome::Rejected) => Self::reset_refund_challenge(id, bty, v),
+                            Ok(_) => {
+                                if Self::vote_expired(v) {
+                                    // => the refund vote expired without reaching threshold support
+                                    Self::reset_refund_challenge(id, bty, v);
+                                } else {
+                                    <RefundChallengeQueue<T>>::append(Self::next_poll(n, Self::bounty_poll_frequency()), id);
                                 }
-                            },
-                            VoteOutcome::Rejected => {
-                                // => the refund is not executed and the bty state is reset to NoPendingChallenges until next challenge
-                                let new_bty = bty.set_state(BountyState::NoPendingChallenges);
-                                let total = new_bty.total();
-                                <Bounties<T>>::insert(bid, new_bty);
-                                Self::deposit_event(RawEvent::BountyRefundChallengeRejected(v, bid, total));
-                            },
-                            _ => (),
+                            }
+                            Err(_) => {
+                                // => a refund vote that cannot be read does not pass
+                                Self::deposit_event(RawEvent::ChallengeVoteOutcomeUnavailable(v));
+                                Self::reset_refund_challenge(id, bty, v);
+                            }
                         }
                     }
                 }
@@ -369,13 +527,15 @@ decl_module! {
 
 // ID helpers
 impl<T: Trait> Module<T> {
+    /// Migrated bounties keep their funds at the account of the bounty pallet
     pub fn bounty_account_id(index: T::BountyId) -> T::AccountId {
-        T::Foundation::get().into_sub_account(index)
+        <BountyAccounts<T>>::get(index)
+            .unwrap_or_else(|| T::Foundation::get().into_sub_account(index))
     }
     fn bounty_id_is_available(id: T::BountyId) -> bool {
         <Bounties<T>>::get(id).is_none()
     }
-    fn bounty_generate_uid() -> T::BountyId {
+    pub fn bounty_generate_uid() -> T::BountyId {
         let mut id_counter = <BountyNonce<T>>::get() + 1u32.into();
         while !Self::bounty_id_is_available(id_counter) {
             id_counter += 1u32.into();
@@ -386,7 +546,7 @@ impl<T: Trait> Module<T> {
     fn submission_id_is_available(id: T::SubmissionId) -> bool {
         <Submissions<T>>::get(id).is_none()
     }
-    fn submission_generate_uid() -> T::SubmissionId {
+    pub fn submission_generate_uid() -> T::SubmissionId {
         let mut id_counter = <SubmissionNonce<T>>::get() + 1u32.into();
         while !Self::submission_id_is_available(id_counter) {
             id_counter += 1u32.into();
@@ -398,11 +558,113 @@ impl<T: Trait> Module<T> {
 
 // Runtime helpers
 impl<T: Trait> Module<T> {
-    fn approve_and_execute_payment(id: T::SubmissionId) -> DispatchResult {
+    /// Number of contributors voting on a challenge to the approval of the
+    /// submission, 0 if it does not exist
+    pub fn challenge_voter_count(submission_id: T::SubmissionId) -> u32 {
+        <Submissions<T>>::get(submission_id)
+            .map(|s| <ContributorCount<T>>::get(s.bounty_id()))
+            .unwrap_or_default()
+    }
+    /// Splits due queue items into those processed with the remaining budget and those deferred
+    fn split_for_budget<Id>(
+        mut due: Vec<Id>,
+        budget: &mut u32,
+    ) -> (Vec<Id>, Vec<Id>) {
+        let deferred =
+            due.split_off(sp_std::cmp::min(*budget as usize, due.len()));
+        *budget -= due.len() as u32;
+        (due, deferred)
+    }
+    fn next_poll(
+        now: T::BlockNumber,
+        frequency: T::BlockNumber,
+    ) -> T::BlockNumber {
+        if frequency.is_zero() {
+            now + 1u32.into()
+        } else {
+            now + frequency
+        }
+    }
+    /// Executes the payment or reschedules it until the retry limit is reached
+    fn pay_or_retry(
+        now: T::BlockNumber,
+        id: T::SubmissionId,
+        sub: BountySub<T>,
+    ) {
+        if Self::execute_payment(id).is_ok() {
+            <PaymentRetries<T>>::remove(id);
+            return
+        }
+        let retries = <PaymentRetries<T>>::get(id) + 1;
+        if retries > T::MaxPaymentRetries::get() {
+            <PaymentRetries<T>>::remove(id);
+            <Submissions<T>>::insert(
+                id,
+                sub.set_state(SubmissionState2::SubmittedAwaitingResponse),
+            );
+            Self::deposit_event(RawEvent::SubmissionPaymentRetriesExhausted(
+                id,
+                sub.bounty_id(),
+                sub.amount(),
+            ));
+        } else {
+            let retry_at =
+                Self::next_poll(now, Self::submission_poll_frequency());
+            <PaymentRetries<T>>::insert(id, retries);
+            <Submissions<T>>::insert(
+                id,
+                sub.set_state(SubmissionState2::ApprovedAndScheduled(retry_at)),
+            );
+            <PayoutQueue<T>>::append(retry_at, id);
+            Self::deposit_event(RawEvent::SubmissionApprovedButPaymentFailed(
+                id,
+                sub.bounty_id(),
+                sub.amount(),
+            ));
+        }
+    }
+    fn reset_refund_challenge(
+        id: T::BountyId,
+        bounty: Bounty<T>,
+        vote_id: T::VoteId,
+    ) {
+        let new_bounty = bounty.set_state(BountyState::NoPendingChallenges);
+        let total = new_bounty.total();
+        <Bounties<T>>::insert(id, new_bounty);
+        Self::deposit_event(RawEvent::BountyRefundChallengeRejected(
+            vote_id, id, total,
+        ));
+    }
+    /// Opens a vote weighted by contributions to the bounty
+    fn open_contributor_vote(
+        id: T::BountyId,
+        topic: T::IpfsReference,
+        threshold: Permill,
+    ) -> Result<T::VoteId, DispatchError> {
+        let contributors: SimpleShareGenesis<T::AccountId, T::Signal> =
+            <BountyTips<T>>::iter_prefix(id)
+                .collect::<Vec<(T::AccountId, BalanceOf<T>)>>()
+                .into();
+        <vote::Module<T>>::open_percent_vote(
+            Some(topic),
+            contributors,
+            Threshold::new(threshold, None),
+            Some(T::ChallengePeriod::get()),
+        )
+    }
+    /// Returns true if the vote is no longer accepting votes
+    fn vote_expired(id: T::VoteId) -> bool {
+        if let Some(state) = <vote::Module<T>>::vote_states(id) {
+            <vote::Module<T>>::check_vote_expired(&state)
+        } else {
+            true
+        }
+    }
+    fn execute_payment(id: T::SubmissionId) -> DispatchResult {
         let submission =
             <Submissions<T>>::get(id).ok_or(Error::<T>::SubmissionDNE)?;
         ensure!(
-            submission.state().awaiting_review(),
+            !submission.state().awaiting_review(),
             Error::<T>::SubmissionNotInValidStateToApprove
         );
         let bounty_id = submission.bounty_id();
@@ -412,17 +674,18 @@ impl<T: Trait> Module<T> {
             bounty.total() >= submission.amount(),
             Error::<T>::CannotApproveSubmissionIfAmountExceedsTotalAvailable
         );
-        // execute payment
+        // execute payment, which may drain the bounty account
         T::Currency::transfer(
             &Self::bounty_account_id(bounty_id),
             &submission.submitter(),
             submission.amount(),
-            ExistenceRequirement::KeepAlive,
+            ExistenceRequirement::AllowDeath,
         )?;
         let new_bounty = bounty.subtract_funds(submission.amount());
         let (bounty_info, new_total) = (new_bounty.info(), new_bounty.total());
         // submission approved and executed => can be removed
         <Submissions<T>>::remove(id);
+        <BountySubmissions<T>>::remove(bounty_id, id);
         <Bounties<T>>::insert(bounty_id, new_bounty);
         Self::deposit_event(RawEvent::BountyPaymentExecuted(
             bounty_id,
@@ -435,62 +698,64 @@ impl<T: Trait> Module<T> {
         ));
         Ok(())
     }
-    // remainder recipient should be the depositer, aka bounty.gov().leader()
+    /// Returns remaining funds to contributors in proportion to their contributions,
+    /// the remainder recipient receives the dust left after rounding
     fn execute_refund(
         id: T::BountyId,
-        _remainder_recipient: &T::AccountId,
-    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        remainder_recipient: &T::AccountId,
+    ) -> (BalanceOf<T>, BalanceOf<T>) {
         let from = Self::bounty_account_id(id);
-        let total = T::Currency::total_balance(&from);
+        let total = T::Currency::free_balance(&from);
         let contributors: SimpleShareGenesis<T::AccountId, BalanceOf<T>> =
-            <BountyTips<T>>::iter()
-                .filter(|(i, _, _)| i == &id)
-                .map(|(_, ac, amt)| (ac, amt))
+            <BountyTips<T>>::iter_prefix(id)
                 .collect::<Vec<(T::AccountId, BalanceOf<T>)>>()
                 .into();
-        let num_of_accounts: u32 =
-            contributors.account_ownership().len() as u32;
-        if num_of_accounts == 1 {
-            T::Currency::transfer(
+        let mut to_contributors = BalanceOf::<T>::zero();
+        if !contributors.total().is_zero() {
+            for (acc, nom) in contributors.account_ownership() {
+                let due_amount = Permill::from_rational_approximation(
+                    nom,
+                    contributors.total(),
+                )
+                .mul_floor(total);
+                if T::Currency::transfer(
+                    &from,
+                    &acc,
+                    due_amount,
+                    ExistenceRequirement::AllowDeath,
+                )
+                .is_ok()
+                {
+                    to_contributors += due_amount;
+                }
+            }
+        }
+        // send remainder
+        let remainder = T::Currency::free_balance(&from);
+        let to_remainder_recipient = if !remainder.is_zero()
+            && T::Currency::transfer(
                 &from,
-                &contributors.account_ownership()[0].0,
-                total,
+                remainder_recipient,
+                remainder,
                 ExistenceRequirement::AllowDeath,
-            )?;
-            // Self::recursive_remove_bounty(id);
-            Ok((total, BalanceOf::<T>::zero()))
+            )
+            .is_ok()
+        {
+            remainder
         } else {
-            todo!()
-            // let mut total_to_contributors = BalanceOf::<T>::zero();
-            // let den: T::Signal = contributors.total();
-            // for (acc, nom) in contributors.account_ownership().iter() {
-            //     let due_proportion =
-            //         Permill::from_rational_approximation(nom, &den);
-            //     let due_amount: BalanceOf<T> = due_proportion * total;
-            //     T::Currency::transfer(
-            //         &from,
-            //         &acc,
-            //         due_amount,
-            //         ExistenceRequirement::AllowDeath,
-            //     )?;
-            //     total -= due_amount;
-            //     total_to_contributors += due_amount;
-            // }
-            // // send remainder
-            // T::Currency::transfer(
-            //     &from,
-            //     remainder_recipient,
-            //     total,
-            //     ExistenceRequirement::AllowDeath,
-            // )?;
-            // // Self::recursive_remove_bounty(id);
-            // Ok((total_to_contributors, total))
-        }
+            BalanceOf::<T>::zero()
+        };
+        (to_contributors, to_remainder_recipient)
     }
-    fn _recursive_remove_bounty(id: T::BountyId) {
+    fn recursive_remove_bounty(id: T::BountyId) {
         <Bounties<T>>::remove(id);
-        <Submissions<T>>::iter()
-            .filter(|(_, app)| app.bounty_id() == id)
-            .for_each(|(app_id, _)| <Submissions<T>>::remove(app_id));
+        <BountyTips<T>>::remove_prefix(id);
+        <ContributorCount<T>>::remove(id);
+        <BountyAccounts<T>>::remove(id);
+        <BountySubmissions<T>>::iter_prefix(id).for_each(|(sub_id, _)| {
+            <Submissions<T>>::remove(sub_id);
+            <PaymentRetries<T>>::remove(sub_id);
+        });
+        <BountySubmissions<T>>::remove_prefix(id);
     }
 }
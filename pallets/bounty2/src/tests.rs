@@ -0,0 +1,417 @@
+use super::*;
+use frame_support::{
+    assert_noop,
+    assert_ok,
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    traits::OnFinalize,
+    weights::Weight,
+};
+use frame_system::{self as system,};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+use util::vote::VoterView;
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod bounty2 {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        pallet_balances<T>,
+        vote<T>,
+        bounty2<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type IpfsReference = u32;
+    type VoteId = u64;
+    type Signal = u64;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const ChallengePeriod: u64 = 5;
+    pub const Foundation: ModuleId = ModuleId(*b"fundacon");
+    pub const MinDeposit: u64 = 10;
+    pub const MinContribution: u64 = 5;
+    pub const MinVetoThreshold: Permill = Permill::from_percent(10);
+    pub const MinRefundThreshold: Permill = Permill::from_percent(10);
+    pub const MaxPollsPerBlock: u32 = 2;
+    pub const MaxPaymentRetries: u32 = 2;
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type Currency = Balances;
+    type BountyId = u64;
+    type SubmissionId = u64;
+    type ChallengePeriod = ChallengePeriod;
+    type Foundation = Foundation;
+    type MinDeposit = MinDeposit;
+    type MinContribution = MinContribution;
+    type MinVetoThreshold = MinVetoThreshold;
+    type MinRefundThreshold = MinRefundThreshold;
+    type MaxPollsPerBlock = MaxPollsPerBlock;
+    type MaxPaymentRetries = MaxPaymentRetries;
+    type WeightInfo = ();
+}
+pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type Vote = vote::Module<Test>;
+pub type Bounty = Module<Test>;
+
+fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64, u64, u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::bounty2(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 98), (3, 200), (4, 75), (5, 10), (6, 69)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        bounty_poll_frequency: 1,
+        submission_poll_frequency: 1,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn post_and_contribute() {
+    assert_ok!(Bounty::post_bounty(
+        Origin::signed(1),
+        10u32, // cid
+        20,    // amount
+        Permill::from_percent(50),
+        Permill::from_percent(50),
+    ));
+    assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 20));
+}
+
+fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        Bounty::on_finalize(next);
+    }
+}
+
+#[test]
+fn genesis_config_works() {
+    new_test_ext().execute_with(|| {
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn post_bounty_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                20,
+                Permill::from_percent(5),
+                Permill::from_percent(50),
+            ),
+            Error::<Test>::VetoThresholdBelowModuleMin
+        );
+        assert_noop!(
+            Bounty::post_bounty(
+                Origin::signed(1),
+                10u32,
+                20,
+                Permill::from_percent(50),
+                Permill::from_percent(5),
+            ),
+            Error::<Test>::RefundThresholdBelowModuleMin
+        );
+        assert_ok!(Bounty::post_bounty(
+            Origin::signed(1),
+            10u32,
+            20,
+            Permill::from_percent(50),
+            Permill::from_percent(50),
+        ));
+        assert_eq!(RawEvent::BountyPosted(1, 20, 1, 10), get_last_event());
+        assert_eq!(Bounty::contributor_count(1), 1);
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 5));
+        assert_ok!(Bounty::contribute_to_bounty(Origin::signed(3), 1, 5));
+        assert_eq!(Bounty::contributor_count(1), 2);
+    });
+}
+
+#[test]
+fn unchallenged_approval_executes_after_challenge_period() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 10));
+        assert_noop!(
+            Bounty::approve_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToApproveBountySubmissions
+        );
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(
+            RawEvent::SubmissionApprovedAndScheduled(1, 1, 6),
+            get_last_event()
+        );
+        run_to_block(5);
+        assert_eq!(Balances::total_balance(&2), 98);
+        run_to_block(6);
+        assert_eq!(Balances::total_balance(&2), 108);
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::bounty_submissions(1, 1).is_none());
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 30);
+    });
+}
+
+#[test]
+fn leader_rejection_removes_submission() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 10));
+        assert_eq!(Bounty::bounty_submissions(1, 1), Some(()));
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::NotAuthorizedToRejectBountySubmissions
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(1), 1));
+        assert_eq!(RawEvent::SubmissionRejected(1, 1, 11), get_last_event());
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::bounty_submissions(1, 1).is_none());
+    });
+}
+
+#[test]
+fn contributor_veto_rejects_approved_submission() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 10));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(4), 1),
+            Error::<Test>::NotAuthorizedToChallengeSubmissionApproval
+        );
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(3), 1));
+        assert_eq!(
+            RawEvent::SubmissionApprovalChallenged(3, 1, 1, 1),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::reject_bounty_submission(Origin::signed(3), 1),
+            Error::<Test>::SubmissionNotInValidStateToReject
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(
+            RawEvent::SpendChallengePassedAndSubmissionRejected(1, 1, 10),
+            get_last_event()
+        );
+        assert!(Bounty::submissions(1).is_none());
+        assert_eq!(Balances::total_balance(&2), 98);
+    });
+}
+
+#[test]
+fn expired_veto_executes_approved_submission() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 10));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_ok!(Bounty::reject_bounty_submission(Origin::signed(3), 1));
+        run_to_block(6);
+        assert_eq!(Balances::total_balance(&2), 98);
+        run_to_block(7);
+        assert_eq!(Balances::total_balance(&2), 108);
+        assert!(Bounty::submissions(1).is_none());
+    });
+}
+
+#[test]
+fn refund_vote_returns_contributions() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 10));
+        assert_eq!(Balances::total_balance(&1), 80);
+        assert_eq!(Balances::total_balance(&3), 180);
+        assert_noop!(
+            Bounty::trigger_refund_vote(Origin::signed(4), 1),
+            Error::<Test>::OnlyContributorsCanTriggerRefundVote
+        );
+        assert_ok!(Bounty::trigger_refund_vote(Origin::signed(3), 1));
+        assert_eq!(
+            RawEvent::BountyRefundVoteTriggered(3, 1, 1),
+            get_last_event()
+        );
+        assert_noop!(
+            Bounty::trigger_refund_vote(Origin::signed(1), 1),
+            Error::<Test>::RefundVoteAlreadyPendingForBounty
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(2);
+        assert_eq!(RawEvent::BountyRefunded(1, 40, 0), get_last_event());
+        assert_eq!(Balances::total_balance(&1), 100);
+        assert_eq!(Balances::total_balance(&3), 200);
+        assert!(Bounty::bounties(1).is_none());
+        assert_eq!(Bounty::contributor_count(1), 0);
+        // pending submissions are removed with the bounty
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::bounty_submissions(1, 1).is_none());
+    });
+}
+
+#[test]
+fn payout_may_drain_bounty_account() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 40));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        run_to_block(6);
+        assert_eq!(Balances::total_balance(&2), 138);
+        assert_eq!(Balances::total_balance(&Bounty::bounty_account_id(1)), 0);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 0);
+        assert!(Bounty::submissions(1).is_none());
+    });
+}
+
+#[test]
+fn failed_payment_is_retried_until_limit() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 40));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        // the bounty account holds less than the approved amount
+        Balances::make_free_balance_be(&Bounty::bounty_account_id(1), 30);
+        run_to_block(6);
+        assert_eq!(
+            RawEvent::SubmissionApprovedButPaymentFailed(1, 1, 40),
+            get_last_event()
+        );
+        assert_eq!(Bounty::payment_retries(1), 1);
+        assert_eq!(
+            Bounty::submissions(1).unwrap().state(),
+            SubmissionState2::ApprovedAndScheduled(7)
+        );
+        run_to_block(7);
+        assert_eq!(Bounty::payment_retries(1), 2);
+        run_to_block(8);
+        assert_eq!(
+            RawEvent::SubmissionPaymentRetriesExhausted(1, 1, 40),
+            get_last_event()
+        );
+        assert_eq!(Bounty::payment_retries(1), 0);
+        assert!(Bounty::submissions(1).unwrap().state().awaiting_review());
+        assert_eq!(Balances::total_balance(&2), 98);
+    });
+}
+
+#[test]
+fn payouts_beyond_block_budget_are_deferred() {
+    new_test_ext().execute_with(|| {
+        post_and_contribute();
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(2), 1, 11u32, 5));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(4), 1, 12u32, 5));
+        assert_ok!(Bounty::submit_for_bounty(Origin::signed(6), 1, 13u32, 5));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 1));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 2));
+        assert_ok!(Bounty::approve_bounty_submission(Origin::signed(1), 3));
+        assert_eq!(Bounty::payout_queue(6).len(), 3);
+        run_to_block(6);
+        assert!(Bounty::submissions(1).is_none());
+        assert!(Bounty::submissions(2).is_none());
+        assert_eq!(Bounty::payout_queue(7), vec![3]);
+        run_to_block(7);
+        assert!(Bounty::submissions(3).is_none());
+        assert_eq!(Balances::total_balance(&6), 74);
+        assert_eq!(Bounty::bounties(1).unwrap().total(), 25);
+    });
+}
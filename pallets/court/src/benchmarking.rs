@@ -0,0 +1,90 @@
+//! Court pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use org::MAX_MEMBERS;
+use sp_std::prelude::*;
+use util::{
+    meta::VoteCall,
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::{
+        Threshold,
+        VoterView,
+    },
+};
+
+const SEED: u32 = 0;
+
+/// Registers a flat court org with `n` members and returns vote metadata requiring a single vote in favor
+fn court<T: Trait>(n: u32) -> Result<GovernanceOf<T>, DispatchError> {
+    let members = (0..n)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    let org = <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        None,
+        T::IpfsReference::default(),
+    )?;
+    Ok(VoteMetadata::Signal(VoteCall::new(
+        OrgRep::Equal(org),
+        Threshold::new(1u32.into(), None),
+        None,
+    )))
+}
+
+/// Funds a locker and registers a dispute which may be raised by `raiser`
+/// and is resolved by a court of `n` members
+fn dispute<T: Trait>(
+    raiser: &T::AccountId,
+    n: u32,
+) -> Result<T::DisputeId, DispatchError> {
+    let locker: T::AccountId = account("locker", 0, SEED);
+    let amount = T::MinimumDisputeAmount::get();
+    T::Currency::make_free_balance_be(&locker, amount + amount);
+    <Module<T>>::register_dispute_type(
+        locker,
+        amount,
+        raiser.clone(),
+        court::<T>(n)?,
+        None,
+    )
+}
+
+benchmarks! {
+    _ { }
+
+    register_dispute_type_with_resolution_path {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let raiser: T::AccountId = account("raiser", 0, SEED);
+        let amount = T::MinimumDisputeAmount::get();
+        T::Currency::make_free_balance_be(&caller, amount + amount);
+        let resolution = court::<T>(1)?;
+    }: _(RawOrigin::Signed(caller), amount, raiser, resolution, None)
+    verify {
+        assert!(<DisputeStates<T>>::get(<DisputeIdCounter<T>>::get()).is_some());
+    }
+
+    raise_dispute_to_trigger_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let raiser: T::AccountId = account("raiser", 0, SEED);
+        let dispute_id = dispute::<T>(&raiser, m)?;
+    }: _(RawOrigin::Signed(raiser), dispute_id)
+
+    poll_dispute_to_execute_outcome {
+        let raiser: T::AccountId = account("raiser", 0, SEED);
+        let dispute_id = dispute::<T>(&raiser, 1)?;
+        <Module<T>>::raise_dispute_to_trigger_vote(RawOrigin::Signed(raiser.clone()).into(), dispute_id)?;
+        let vote_id = <vote::Module<T>>::vote_id_counter();
+        <vote::Module<T>>::submit_vote(
+            RawOrigin::Signed(account("member", 0, SEED)).into(),
+            vote_id,
+            VoterView::InFavor,
+            None,
+        )?;
+    }: _(RawOrigin::Signed(raiser), dispute_id)
+}
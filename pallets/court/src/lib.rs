@@ -8,6 +8,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -21,6 +24,7 @@ use frame_support::{
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -47,11 +51,14 @@ use util::{
     meta::VoteMetadata,
     organization::OrgRep,
     traits::{
+        CheckVoteExpiry,
         GenerateUniqueID,
+        GetDisputeOutcome,
         GetVoteOutcome,
         IDIsAvailable,
         OpenVote,
         RegisterDisputeType,
+        ResolveDispute,
     },
     vote::VoteOutcome,
 };
@@ -90,6 +97,27 @@ pub trait Trait: frame_system::Trait + org::Trait + vote::Trait {
 
     /// The minimum amount for any dispute registered in this module
     type MinimumDisputeAmount: Get<BalanceOf<Self>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn register_dispute_type_with_resolution_path() -> Weight;
+    fn raise_dispute_to_trigger_vote(m: u32) -> Weight;
+    fn poll_dispute_to_execute_outcome() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn register_dispute_type_with_resolution_path() -> Weight {
+        1_000_000_000
+    }
+    fn raise_dispute_to_trigger_vote(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn poll_dispute_to_execute_outcome() -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -119,6 +147,8 @@ decl_error! {
         ActiveDisputeCannotBeRaisedFromCurrentState,
         ActiveDisputeCannotBePolledFromCurrentState,
         VoteOutcomeInconclusiveSoPollCannotExecuteOutcome,
+        CannotRaiseDisputeAfterExpiry,
+        DisputeOutcomeExecutedByLocker,
     }
 }
 
@@ -141,6 +171,10 @@ decl_storage! {
                         DisputeState<T::VoteId>,
                     >
                 >;
+
+        /// Disputes whose locker pays out the accepted outcome instead of the court
+        pub ResolvedByLocker get(fn resolved_by_locker): map
+            hasher(blake2_128_concat) T::DisputeId => Option<()>;
     }
 }
 
@@ -149,7 +183,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::register_dispute_type_with_resolution_path()]
         fn register_dispute_type_with_resolution_path(
             origin,
             amount_to_lock: BalanceOf<T>,
@@ -171,8 +205,8 @@ decl_module! {
             Self::deposit_event(RawEvent::RegisteredDisputeWithResolutionPath(new_dispute_id, locker, amount_to_lock, dispute_raiser, court_org));
             Ok(())
         }
-        #[weight = 0]
-        fn raise_dispute_to_trigger_vote(
+        #[weight = <T as Trait>::WeightInfo::raise_dispute_to_trigger_vote(Self::court_member_count(*dispute_id))]
+        pub fn raise_dispute_to_trigger_vote(
             origin,
             dispute_id: T::DisputeId,
         ) -> DispatchResult {
@@ -180,6 +214,9 @@ decl_module! {
             let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotRaiseDisputeIfDisputeStateDNE)?;
             // ensure that the signer can trigger this dispute
             ensure!(dispute.can_raise_dispute(&trigger), Error::<T>::SignerNotAuthorizedToRaiseThisDispute);
+            if let Some(expiry) = dispute.expiry() {
+                ensure!(<frame_system::Module<T>>::block_number() <= expiry, Error::<T>::CannotRaiseDisputeAfterExpiry);
+            }
             // check that it is in a valid state to trigger the dispute
             let (new_dispute, dispatched_vote_id) = match dispute.state() {
                 DisputeState::DisputeNotRaised => {
@@ -207,20 +244,21 @@ decl_module! {
             Self::deposit_event(RawEvent::DisputeRaisedAndVoteTriggered(dispute_id, locker, amt_locked, trigger, court_org, dispatched_vote_id));
             Ok(())
         }
-        #[weight = 0]
-        fn poll_dispute_to_execute_outcome(
+        #[weight = <T as Trait>::WeightInfo::poll_dispute_to_execute_outcome()]
+        pub fn poll_dispute_to_execute_outcome(
             origin,
             dispute_id: T::DisputeId,
         ) -> DispatchResult {
             let _ = ensure_signed(origin)?;
             let dispute = <DisputeStates<T>>::get(dispute_id).ok_or(Error::<T>::CannotPollDisputeIfDisputeStateDNE)?;
             // _could_ verify poller in context of dispute here
+            ensure!(!<ResolvedByLocker<T>>::contains_key(dispute_id), Error::<T>::DisputeOutcomeExecutedByLocker);
 
             // match on the dispute's current state
             let new_dispute_state = match dispute.state() {
                 DisputeState::DisputeRaisedAndVoteDispatched(live_vote_id) => {
                     // check the vote outcome
-                    let outcome = <vote::Module<T>>::get_vote_outcome(live_vote_id)?;
+                    let outcome = Self::dispute_vote_outcome(live_vote_id)?;
                     match outcome {
                         VoteOutcome::Approved => {
                             // unreserve capital from locker
@@ -249,6 +287,34 @@ decl_module! {
     }
 }
 
+impl<T: Trait> Module<T> {
+    /// Outcome of the dispute vote, votes which expired without approval
+    /// are rejected
+    fn dispute_vote_outcome(
+        vote_id: T::VoteId,
+    ) -> Result<VoteOutcome, DispatchError> {
+        let outcome = <vote::Module<T>>::get_vote_outcome(vote_id)?;
+        if outcome != VoteOutcome::Approved
+            && <vote::Module<T>>::vote_expired(vote_id)
+        {
+            Ok(VoteOutcome::Rejected)
+        } else {
+            Ok(outcome)
+        }
+    }
+    /// Number of members of the org voting on the dispute, 0 if the
+    /// dispute does not exist
+    pub fn court_member_count(id: T::DisputeId) -> u32 {
+        <DisputeStates<T>>::get(id)
+            .map(|d| {
+                <org::Module<T>>::member_count(
+                    d.resolution_metadata().org().org(),
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
 impl<T: Trait> IDIsAvailable<T::DisputeId> for Module<T> {
     fn id_is_available(id: T::DisputeId) -> bool {
         <DisputeStates<T>>::get(id).is_none()
@@ -304,3 +370,56 @@ impl<T: Trait>
         Ok(new_dispute_id)
     }
 }
+
+impl<T: Trait> GetDisputeOutcome<T::DisputeId> for Module<T> {
+    type Outcome = DisputeState<T::VoteId>;
+    fn get_dispute_outcome(
+        dispute_id: T::DisputeId,
+    ) -> Result<Self::Outcome, DispatchError> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)
+            .ok_or(Error::<T>::CannotPollDisputeIfDisputeStateDNE)?;
+        Ok(dispute.state())
+    }
+}
+
+impl<T: Trait> ResolveDispute<T::DisputeId> for Module<T> {
+    type Outcome = DisputeState<T::VoteId>;
+    fn resolve_by_locker(dispute_id: T::DisputeId) {
+        <ResolvedByLocker<T>>::insert(dispute_id, ());
+    }
+    fn resolve_dispute(
+        dispute_id: T::DisputeId,
+    ) -> Result<Self::Outcome, DispatchError> {
+        let dispute = <DisputeStates<T>>::get(dispute_id)
+            .ok_or(Error::<T>::CannotPollDisputeIfDisputeStateDNE)?;
+        let new_state = match dispute.state() {
+            DisputeState::DisputeNotRaised => {
+                let now = <frame_system::Module<T>>::block_number();
+                match dispute.expiry() {
+                    Some(expiry) if expiry < now => {
+                        DisputeState::DisputeExpired
+                    }
+                    _ => return Ok(dispute.state()),
+                }
+            }
+            DisputeState::DisputeRaisedAndVoteDispatched(live_vote_id) => {
+                match Self::dispute_vote_outcome(live_vote_id)? {
+                    VoteOutcome::Approved => {
+                        DisputeState::DisputeRaisedAndAccepted(live_vote_id)
+                    }
+                    VoteOutcome::Rejected => {
+                        DisputeState::DisputeRaisedAndRejected(live_vote_id)
+                    }
+                    _ => return Ok(dispute.state()),
+                }
+            }
+            resolved => return Ok(resolved),
+        };
+        // the locker pays out the released funds if the dispute is accepted
+        let _ =
+            T::Currency::unreserve(&dispute.locker(), dispute.locked_funds());
+        <DisputeStates<T>>::insert(dispute_id, dispute.set_state(new_state));
+        <ResolvedByLocker<T>>::remove(dispute_id);
+        Ok(new_state)
+    }
+}
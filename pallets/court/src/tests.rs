@@ -11,8 +11,12 @@ use frame_system::{self as system,};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::IdentityLookup,
+    traits::{
+        ConvertInto,
+        IdentityLookup,
+    },
     Perbill,
+    Permill,
 };
 use util::{
     meta::VoteCall,
@@ -92,16 +96,31 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: u64 = 10;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32; // TODO: replace with utils_identity::Cid
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = vote::Module<Test>;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = ();
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type WeightInfo = ();
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u64 = 10;
@@ -111,6 +130,7 @@ impl Trait for Test {
     type Currency = Balances;
     type DisputeId = u64;
     type MinimumDisputeAmount = MinimumDisputeAmount;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -298,3 +318,72 @@ fn poll_dispute_to_execute_outcome_works() {
         assert_ok!(Court::poll_dispute_to_execute_outcome(one.clone(), 1));
     });
 }
+
+#[test]
+fn dispute_resolved_by_locker_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            Some(3),
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            one.clone(),
+            10,
+            2,
+            new_resolution_metadata,
+            Some(5),
+        ));
+        Court::resolve_by_locker(1);
+        assert_eq!(
+            Court::resolve_dispute(1),
+            Ok(DisputeState::DisputeNotRaised)
+        );
+        assert_ok!(Court::raise_dispute_to_trigger_vote(two, 1));
+        // the court no longer transfers the locked funds to the raiser
+        assert_noop!(
+            Court::poll_dispute_to_execute_outcome(one, 1),
+            Error::<Test>::DisputeOutcomeExecutedByLocker
+        );
+        assert_eq!(
+            Court::resolve_dispute(1),
+            Ok(DisputeState::DisputeRaisedAndVoteDispatched(1))
+        );
+        // the vote expires without approval so the dispute is rejected
+        System::set_block_number(5);
+        assert_eq!(
+            Court::resolve_dispute(1),
+            Ok(DisputeState::DisputeRaisedAndRejected(1))
+        );
+        assert_eq!(Balances::reserved_balance(&1), 0);
+        assert_eq!(Balances::free_balance(&1), 100);
+        assert!(Court::resolved_by_locker(1).is_none());
+    });
+}
+
+#[test]
+fn unraised_dispute_expires() {
+    new_test_ext().execute_with(|| {
+        let new_resolution_metadata = VoteMetadata::Signal(VoteCall::new(
+            OrgRep::Equal(1),
+            Threshold::new(1, None),
+            None,
+        ));
+        assert_ok!(Court::register_dispute_type_with_resolution_path(
+            Origin::signed(1),
+            10,
+            2,
+            new_resolution_metadata,
+            Some(3),
+        ));
+        System::set_block_number(4);
+        assert_noop!(
+            Court::raise_dispute_to_trigger_vote(Origin::signed(2), 1),
+            Error::<Test>::CannotRaiseDisputeAfterExpiry
+        );
+        assert_eq!(Court::resolve_dispute(1), Ok(DisputeState::DisputeExpired));
+        assert_eq!(Balances::reserved_balance(&1), 0);
+    });
+}
@@ -0,0 +1,52 @@
+//! Donate pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use org::MAX_MEMBERS;
+use sp_std::prelude::*;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+};
+
+const SEED: u32 = 0;
+
+/// Registers a flat org with `n` members and funds `caller` for a donation
+/// of `amt`
+fn setup<T: Trait>(
+    caller: &T::AccountId,
+    n: u32,
+    amt: BalanceOf<T>,
+) -> Result<T::OrgId, DispatchError> {
+    let members = (0..n)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    T::Currency::make_free_balance_be(caller, amt + amt);
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        None,
+        T::IpfsReference::default(),
+    )
+}
+
+benchmarks! {
+    _ { }
+
+    make_prop_donation {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amt: BalanceOf<T> = (m * 1_000).into();
+        let org = setup::<T>(&caller, m, amt)?;
+    }: _(RawOrigin::Signed(caller.clone()), org, caller, amt)
+
+    make_equal_donation {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amt: BalanceOf<T> = (m * 1_000).into();
+        let org = setup::<T>(&caller, m, amt)?;
+    }: _(RawOrigin::Signed(caller.clone()), org, caller, amt)
+}
@@ -3,15 +3,22 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use frame_support::{
     decl_error,
     decl_event,
     decl_module,
+    ensure,
     traits::{
         Currency,
         ExistenceRequirement,
+        Imbalance,
         ReservableCurrency,
+        WithdrawReason,
     },
+    weights::Weight,
 };
 use frame_system::{
     self as system,
@@ -26,12 +33,13 @@ use sp_runtime::{
     DispatchResult,
     Permill,
 };
+use sp_std::prelude::*;
 use util::{
     organization::OrgRep,
     traits::GetGroup,
 };
 
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<
+pub type BalanceOf<T> = <<T as Trait>::Currency as Currency<
     <T as system::Trait>::AccountId,
 >>::Balance;
 
@@ -41,6 +49,23 @@ pub trait Trait: system::Trait + org::Trait {
     /// The currency type
     type Currency: Currency<Self::AccountId>
         + ReservableCurrency<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn make_prop_donation(m: u32) -> Weight;
+    fn make_equal_donation(m: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn make_prop_donation(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn make_equal_donation(_m: u32) -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -66,7 +91,7 @@ decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::make_prop_donation(<org::Module<T>>::member_count(org))]
         fn make_prop_donation(
             origin,
             org: T::OrgId,
@@ -75,9 +100,10 @@ decl_module! {
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let (
-                amt_transferred_to_org,
+                _,
                 remainder_transferred_to_acc
             ) = Self::donate(&sender, OrgRep::Weighted(org), &remainder_recipient, amt)?;
+            let amt_transferred_to_org = amt - remainder_transferred_to_acc;
             Self::deposit_event(
                 RawEvent::PropDonationExecuted(
                     sender,
@@ -89,7 +115,7 @@ decl_module! {
             );
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::make_equal_donation(<org::Module<T>>::member_count(org))]
         fn make_equal_donation(
             origin,
             org: T::OrgId,
@@ -98,9 +124,10 @@ decl_module! {
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
             let (
-                amt_transferred_to_org,
+                _,
                 remainder_transferred_to_acc
             ) = Self::donate(&sender, OrgRep::Equal(org), &remainder_recipient, amt)?;
+            let amt_transferred_to_org = amt - remainder_transferred_to_acc;
             Self::deposit_event(
                 RawEvent::EqualDonationExecuted(
                     sender,
@@ -116,46 +143,99 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
-    /// Returns the remainder NOT transferred because the amount was not perfectly divisible
+    /// Returns the transfer to each member and the remainder NOT transferred to members
+    /// because the amount was not perfectly divisible
     pub fn donate(
         sender: &T::AccountId,
         recipient: OrgRep<T::OrgId>,
         remainder_recipient: &T::AccountId,
         amt: BalanceOf<T>,
-    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+    ) -> Result<(Vec<(T::AccountId, BalanceOf<T>)>, BalanceOf<T>), DispatchError>
+    {
         let free = T::Currency::free_balance(sender);
         let _ = free
             .checked_sub(&amt)
             .ok_or(Error::<T>::NotEnoughFundsInFreeToMakeTransfer)?;
-        // match on recipient type to distribute the donation either in proportion
-        // to org ownership or equally among all members
-        let remainder = match recipient {
+        let transfers = Self::donation_shares(recipient, amt)?;
+        // iterate through and pay the transfer
+        for (acc, amt_due) in transfers.iter() {
+            T::Currency::transfer(
+                sender,
+                acc,
+                *amt_due,
+                ExistenceRequirement::KeepAlive,
+            )?;
+        }
+        let transferred_amt = transfers
+            .iter()
+            .fold(BalanceOf::<T>::zero(), |acc, (_, a)| acc + *a);
+        let remainder = amt - transferred_amt;
+        // transfer remainder to remainder recipient
+        T::Currency::transfer(
+            sender,
+            remainder_recipient,
+            remainder,
+            ExistenceRequirement::KeepAlive,
+        )?;
+        Ok((transfers, remainder))
+    }
+    /// Same as `donate` but the sender may be left below the existential
+    /// deposit and reaped, so the full balance of an account can be donated
+    pub fn donate_allow_death(
+        sender: &T::AccountId,
+        recipient: OrgRep<T::OrgId>,
+        remainder_recipient: &T::AccountId,
+        amt: BalanceOf<T>,
+    ) -> Result<(Vec<(T::AccountId, BalanceOf<T>)>, BalanceOf<T>), DispatchError>
+    {
+        let transfers = Self::donation_shares(recipient, amt)?;
+        // withdrawn at once so the sender is not reaped between transfers
+        let mut donation = T::Currency::withdraw(
+            sender,
+            amt,
+            WithdrawReason::Transfer.into(),
+            ExistenceRequirement::AllowDeath,
+        )?;
+        for (acc, amt_due) in transfers.iter() {
+            let (share, rest) = donation.split(*amt_due);
+            T::Currency::resolve_creating(acc, share);
+            donation = rest;
+        }
+        let remainder = donation.peek();
+        T::Currency::resolve_creating(remainder_recipient, donation);
+        Ok((transfers, remainder))
+    }
+    /// The amount due to each member, in proportion to org ownership or
+    /// equally among all members
+    fn donation_shares(
+        recipient: OrgRep<T::OrgId>,
+        amt: BalanceOf<T>,
+    ) -> Result<Vec<(T::AccountId, BalanceOf<T>)>, DispatchError> {
+        match recipient {
             OrgRep::Weighted(org_id) => {
                 // Get the membership set of the Org
                 let group = <org::Module<T>>::get_group(org_id)
                     .ok_or(Error::<T>::CannotDonateToOrgThatDNE)?;
-                // iterate through and pay the transfer
-                let mut transferred_amt = BalanceOf::<T>::zero();
+                // unvested shares are excluded from the payout
+                let issuance = <org::Module<T>>::vested_issuance(org_id);
                 group
                     .0
                     .into_iter()
-                    .map(|acc: T::AccountId| -> DispatchResult {
-                        let amt_due = Self::calculate_proportional_amount(
-                            amt,
-                            acc.clone(),
-                            org_id,
-                        )?;
-                        T::Currency::transfer(
-                            sender,
-                            &acc,
-                            amt_due,
-                            ExistenceRequirement::KeepAlive,
-                        )?;
-                        transferred_amt += amt_due;
-                        Ok(())
-                    })
-                    .collect::<DispatchResult>()?;
-                amt - transferred_amt
+                    .map(
+                        |acc: T::AccountId| -> Result<
+                            (T::AccountId, BalanceOf<T>),
+                            DispatchError,
+                        > {
+                            let amt_due = Self::calculate_proportional_amount(
+                                amt,
+                                acc.clone(),
+                                org_id,
+                                issuance,
+                            )?;
+                            Ok((acc, amt_due))
+                        },
+                    )
+                    .collect()
             }
             OrgRep::Equal(org_id) => {
                 // Get the membership set of the Org
@@ -164,45 +244,26 @@ impl<T: Trait> Module<T> {
                 // amount for each member if equal payment per member
                 let equal_payment =
                     Self::calculate_uniform_amount(amt, group.0.len())?;
-                // iterate through and pay the transfer
-                let mut transferred_amt = BalanceOf::<T>::zero();
-                group
+                Ok(group
                     .0
                     .into_iter()
-                    .map(|acc: T::AccountId| -> DispatchResult {
-                        T::Currency::transfer(
-                            sender,
-                            &acc,
-                            equal_payment,
-                            ExistenceRequirement::KeepAlive,
-                        )?;
-                        transferred_amt += equal_payment;
-                        Ok(())
-                    })
-                    .collect::<DispatchResult>()?;
-                amt - transferred_amt
+                    .map(|acc| (acc, equal_payment))
+                    .collect())
             }
-        };
-        // transfer remainder to remainder recipient
-        T::Currency::transfer(
-            sender,
-            remainder_recipient,
-            remainder,
-            ExistenceRequirement::KeepAlive,
-        )?;
-        let amt_transferred_to_org = amt - remainder;
-        Ok((amt_transferred_to_org, remainder))
+        }
     }
     fn calculate_proportional_amount(
         amount: BalanceOf<T>,
         account: T::AccountId,
         group: T::OrgId,
+        issuance: T::Shares,
     ) -> Result<BalanceOf<T>, DispatchError> {
-        let issuance = <org::Module<T>>::total_issuance(group);
-        let acc_ownership = <org::Module<T>>::members(group, &account)
-            .ok_or(Error::<T>::AccountHasNoOwnershipInOrg)?;
+        ensure!(
+            <org::Module<T>>::members(group, &account).is_some(),
+            Error::<T>::AccountHasNoOwnershipInOrg
+        );
         let ownership = Permill::from_rational_approximation(
-            acc_ownership.total(),
+            <org::Module<T>>::vested_shares(group, &account),
             issuance,
         );
         Ok(ownership.mul_floor(amount))
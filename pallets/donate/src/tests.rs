@@ -10,8 +10,12 @@ use frame_support::{
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::IdentityLookup,
+    traits::{
+        ConvertInto,
+        IdentityLookup,
+    },
     Perbill,
+    Permill,
 };
 use util::{
     organization::Organization,
@@ -35,6 +39,7 @@ impl_outer_event! {
         system<T>,
         pallet_balances<T>,
         org<T>,
+        vote<T>,
         donate<T>,
     }
 }
@@ -85,15 +90,36 @@ impl pallet_balances::Trait for Test {
     type AccountStore = System;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: u64 = 10;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32; // TODO: replace with utils_identity::Cid
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = vote::Module<Test>;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = ();
+}
+impl vote::Trait for Test {
+    type Event = TestEvent;
+    type VoteId = u64;
+    type Signal = u64;
+    type WeightInfo = ();
 }
 impl Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -175,3 +201,39 @@ fn check_donation_accuracy() {
         assert_eq!(Balances::total_balance(&3), 220);
     });
 }
+
+#[test]
+fn unvested_shares_are_excluded_from_donations() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::issue_vesting_shares(
+            Origin::signed(1),
+            1,
+            2,
+            6,
+            1,
+            100
+        ));
+        assert_ok!(Donate::make_prop_donation(Origin::signed(4), 1, 3, 60));
+        // 2 owns 7 of 12 shares but only 1 of the 6 vested shares, 1/6 * 60 = 10
+        assert_eq!(Balances::total_balance(&2), 108);
+        // 75 - 60 + 10 = 25
+        assert_eq!(Balances::total_balance(&4), 25);
+        assert_eq!(Balances::total_balance(&3), 210);
+    });
+}
+
+#[test]
+fn donation_may_drain_the_sender() {
+    new_test_ext().execute_with(|| {
+        let (transfers, remainder) =
+            Donate::donate_allow_death(&5, OrgRep::Equal(1), &3, 10).unwrap();
+        // 1/6 * 10 = 1 for each member and the remainder of 4 to 3
+        assert_eq!(transfers.len(), 6);
+        assert_eq!(remainder, 4);
+        // 5 is reaped and receives its own share
+        assert_eq!(Balances::total_balance(&5), 1);
+        assert_eq!(Balances::total_balance(&3), 205);
+        assert_eq!(Balances::total_balance(&1), 101);
+        assert_eq!(Balances::total_issuance(), 552);
+    });
+}
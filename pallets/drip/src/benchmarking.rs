@@ -0,0 +1,37 @@
+//! Drip pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn rate<T: Trait>() -> DripRate<T::BlockNumber, BalanceOf<T>> {
+    DripRate::new(10u32.into(), 10u32.into())
+}
+
+benchmarks! {
+    _ { }
+
+    start_drip {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let destination: T::AccountId = account("destination", 0, SEED);
+    }: _(RawOrigin::Signed(caller), destination, rate::<T>())
+    verify {
+        assert_eq!(OpenDripCounter::get(), 1);
+    }
+
+    cancel_drip {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let destination: T::AccountId = account("destination", 0, SEED);
+        <Module<T>>::start_drip(RawOrigin::Signed(caller.clone()).into(), destination, rate::<T>())?;
+        let id = <DripIdCounter<T>>::get();
+    }: _(RawOrigin::Signed(caller), id)
+    verify {
+        assert!(<Drips<T>>::get(id).is_none());
+    }
+}
@@ -8,6 +8,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -20,6 +23,7 @@ use frame_support::{
         Currency,
         ExistenceRequirement,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -74,6 +78,23 @@ pub trait Trait: system::Trait {
 
     /// Currency type
     type Currency: Currency<Self::AccountId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn start_drip() -> Weight;
+    fn cancel_drip() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn start_drip() -> Weight {
+        1_000_000_000
+    }
+    fn cancel_drip() -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -130,7 +151,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::start_drip()]
         fn start_drip(
             origin,
             destination: T::AccountId,
@@ -158,7 +179,7 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::cancel_drip()]
         fn cancel_drip(
             origin,
             id: T::DripId
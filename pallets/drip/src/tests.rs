@@ -85,6 +85,7 @@ impl Trait for Test {
     type Event = TestEvent;
     type DripId = u64;
     type Currency = Balances;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -0,0 +1,178 @@
+//! Grant pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use org::MAX_MEMBERS;
+use sp_std::prelude::*;
+use util::{
+    meta::VoteCall,
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+    vote::Threshold,
+};
+
+const SEED: u32 = 0;
+
+/// Registers a committee of `n` members which reviews applications and
+/// receives milestone payments as a team
+fn committee<T: Trait>(n: u32) -> Result<OrgRep<T::OrgId>, DispatchError> {
+    let members = (0..n)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    let org = <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        None,
+        T::IpfsReference::default(),
+    )?;
+    Ok(OrgRep::Equal(org))
+}
+
+/// Governance with `sudo` as the sudo and `org` as the review committee
+fn governance<T: Trait>(
+    sudo: &T::AccountId,
+    org: OrgRep<T::OrgId>,
+) -> GovernanceOf<T> {
+    ResolutionMetadata::new(
+        Some(sudo.clone()),
+        Some(VoteMetadata::Signal(VoteCall::new(
+            org,
+            Threshold::new(1u32.into(), None),
+            None,
+        ))),
+    )
+    .expect("sudo and vote are both set; qed")
+}
+
+/// Funds `sudo` and creates a foundation worth twice the minimum deposit,
+/// reviewed by a committee of `n` members
+fn foundation<T: Trait>(
+    sudo: &T::AccountId,
+    n: u32,
+) -> Result<(T::FoundationId, OrgRep<T::OrgId>), DispatchError> {
+    let amount = T::MinDeposit::get() + T::MinDeposit::get();
+    <T as donate::Trait>::Currency::make_free_balance_be(sudo, amount + amount);
+    let org = committee::<T>(n)?;
+    <Module<T>>::create_foundation(
+        RawOrigin::Signed(sudo.clone()).into(),
+        T::IpfsReference::default(),
+        amount,
+        governance::<T>(sudo, org),
+    )?;
+    Ok((<FoundationNonce<T>>::get(), org))
+}
+
+/// Creates a foundation and submits an application to be paid out to the committee
+fn application<T: Trait>(
+    sudo: &T::AccountId,
+    n: u32,
+) -> Result<(T::FoundationId, T::ApplicationId, RecipientOf<T>), DispatchError>
+{
+    let (foundation_id, org) = foundation::<T>(sudo, n)?;
+    let recipient = Recipient::new(sudo.clone(), Some(org));
+    <Module<T>>::submit_application(
+        RawOrigin::Signed(sudo.clone()).into(),
+        foundation_id,
+        T::IpfsReference::default(),
+        recipient.clone(),
+        T::MinDeposit::get(),
+    )?;
+    Ok((foundation_id, <ApplicationNonce<T>>::get(), recipient))
+}
+
+/// Submits a milestone for an approved application
+fn milestone<T: Trait>(
+    sudo: &T::AccountId,
+    n: u32,
+) -> Result<(T::ApplicationId, T::MilestoneId), DispatchError> {
+    let (foundation_id, application_id, recipient) = application::<T>(sudo, n)?;
+    <Module<T>>::approve_application(
+        RawOrigin::Signed(sudo.clone()).into(),
+        application_id,
+    )?;
+    <Module<T>>::submit_milestone(
+        RawOrigin::Signed(sudo.clone()).into(),
+        foundation_id,
+        application_id,
+        T::IpfsReference::default(),
+        recipient,
+        T::MinDeposit::get(),
+    )?;
+    Ok((application_id, <MilestoneNonce<T>>::get(application_id)))
+}
+
+benchmarks! {
+    _ { }
+
+    create_foundation {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinDeposit::get();
+        <T as donate::Trait>::Currency::make_free_balance_be(&caller, amount + amount);
+        let gov = governance::<T>(&caller, committee::<T>(1)?);
+    }: _(RawOrigin::Signed(caller), T::IpfsReference::default(), amount, gov)
+    verify {
+        assert!(<Foundations<T>>::get(<FoundationNonce<T>>::get()).is_some());
+    }
+
+    donate_to_foundation {
+        let sudo: T::AccountId = account("sudo", 0, SEED);
+        let (foundation_id, _) = foundation::<T>(&sudo, 1)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let amount = T::MinContribution::get();
+        <T as donate::Trait>::Currency::make_free_balance_be(&caller, amount + amount);
+    }: _(RawOrigin::Signed(caller), foundation_id, amount)
+
+    submit_application {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (foundation_id, _) = foundation::<T>(&caller, 1)?;
+        let recipient = Recipient::new(caller.clone(), None);
+    }: _(RawOrigin::Signed(caller), foundation_id, T::IpfsReference::default(), recipient, T::MinDeposit::get())
+
+    trigger_application_review {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (_, application_id, _) = application::<T>(&caller, m)?;
+    }: _(RawOrigin::Signed(caller), application_id)
+
+    approve_application {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (_, application_id, _) = application::<T>(&caller, 1)?;
+    }: _(RawOrigin::Signed(caller), application_id)
+
+    reject_application {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (_, application_id, _) = application::<T>(&caller, 1)?;
+    }: _(RawOrigin::Signed(caller), application_id)
+    verify {
+        assert!(<Applications<T>>::get(application_id).is_none());
+    }
+
+    submit_milestone {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (foundation_id, application_id, recipient) = application::<T>(&caller, 1)?;
+        <Module<T>>::approve_application(RawOrigin::Signed(caller.clone()).into(), application_id)?;
+    }: _(RawOrigin::Signed(caller), foundation_id, application_id, T::IpfsReference::default(), recipient, T::MinDeposit::get())
+
+    trigger_milestone_review {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (application_id, milestone_id) = milestone::<T>(&caller, m)?;
+    }: _(RawOrigin::Signed(caller), application_id, milestone_id)
+
+    approve_milestone {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (application_id, milestone_id) = milestone::<T>(&caller, m)?;
+    }: _(RawOrigin::Signed(caller), application_id, milestone_id)
+
+    reject_milestone {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let (application_id, milestone_id) = milestone::<T>(&caller, 1)?;
+    }: _(RawOrigin::Signed(caller), application_id, milestone_id)
+    verify {
+        assert!(<Milestones<T>>::get(application_id, milestone_id).is_none());
+    }
+}
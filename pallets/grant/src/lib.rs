@@ -7,6 +7,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -25,6 +28,7 @@ use frame_support::{
         WithdrawReason,
         WithdrawReasons,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -154,6 +158,55 @@ pub trait Trait:
 
     /// Minimum contribution to open foundation
     type MinContribution: Get<BalanceOf<Self>>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn create_foundation() -> Weight;
+    fn donate_to_foundation() -> Weight;
+    fn submit_application() -> Weight;
+    fn trigger_application_review(m: u32) -> Weight;
+    fn approve_application() -> Weight;
+    fn reject_application() -> Weight;
+    fn submit_milestone() -> Weight;
+    fn trigger_milestone_review(m: u32) -> Weight;
+    fn approve_milestone(m: u32) -> Weight;
+    fn reject_milestone() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn create_foundation() -> Weight {
+        1_000_000_000
+    }
+    fn donate_to_foundation() -> Weight {
+        1_000_000_000
+    }
+    fn submit_application() -> Weight {
+        1_000_000_000
+    }
+    fn trigger_application_review(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn approve_application() -> Weight {
+        1_000_000_000
+    }
+    fn reject_application() -> Weight {
+        1_000_000_000
+    }
+    fn submit_milestone() -> Weight {
+        1_000_000_000
+    }
+    fn trigger_milestone_review(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn approve_milestone(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn reject_milestone() -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -245,7 +298,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_foundation()]
         fn create_foundation(
             origin,
             info: T::IpfsReference,
@@ -269,7 +322,7 @@ decl_module! {
             Self::deposit_event(RawEvent::FoundationCreated(id, amount, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::donate_to_foundation()]
         fn donate_to_foundation(
             origin,
             id: T::FoundationId,
@@ -294,7 +347,7 @@ decl_module! {
             Self::deposit_event(RawEvent::FoundationDonation(giver, new_amount, id, total));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_application()]
         fn submit_application(
             origin,
             foundation_id: T::FoundationId,
@@ -310,7 +363,7 @@ decl_module! {
             Self::deposit_event(RawEvent::ApplicationSubmitted(foundation_id, id, recipient, amount_requested, submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_application_review(Self::application_member_count(*application_id))]
         fn trigger_application_review(
             origin,
             application_id: T::ApplicationId,
@@ -332,7 +385,7 @@ decl_module! {
             Self::deposit_event(RawEvent::ApplicationReviewTriggered(app.foundation_id(), application_id, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_application()]
         fn approve_application(
             origin,
             application_id: T::ApplicationId,
@@ -347,7 +400,7 @@ decl_module! {
             Self::deposit_event(RawEvent::ApplicationApproved(app.foundation_id(), application_id, app.submission_ref()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reject_application()]
         fn reject_application(
             origin,
             application_id: T::ApplicationId,
@@ -361,7 +414,7 @@ decl_module! {
             Self::deposit_event(RawEvent::ApplicationRejected(app.foundation_id(), application_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_milestone()]
         fn submit_milestone(
             origin,
             foundation_id: T::FoundationId,
@@ -380,7 +433,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneSubmitted(foundation_id, application_id, id, recipient, amount_requested, submission_ref));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::trigger_milestone_review(Self::milestone_member_count(*application_id, *milestone_id))]
         fn trigger_milestone_review(
             origin,
             application_id: T::ApplicationId,
@@ -403,7 +456,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneReviewTriggered(mile.base_foundation(), application_id, milestone_id, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::approve_milestone(Self::milestone_recipient_count(*application_id, *milestone_id))]
         fn approve_milestone(
             origin,
             application_id: T::ApplicationId,
@@ -419,7 +472,7 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestoneApproved(mile.base_foundation(), application_id, milestone_id, mile.submission()));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reject_milestone()]
         fn reject_milestone(
             origin,
             application_id: T::ApplicationId,
@@ -513,6 +566,40 @@ impl<T: Trait> Module<T> {
         <ApplicationNonce<T>>::put(id_counter);
         id_counter
     }
+    /// Number of members of the review committee of the foundation, 0 if
+    /// it has none
+    fn committee_member_count(id: T::FoundationId) -> u32 {
+        <Foundations<T>>::get(id)
+            .and_then(|f| f.gov().vote())
+            .map(|v| <org::Module<T>>::member_count(v.org().org()))
+            .unwrap_or_default()
+    }
+    /// Number of members of the committee reviewing the application
+    pub fn application_member_count(id: T::ApplicationId) -> u32 {
+        <Applications<T>>::get(id)
+            .map(|a| Self::committee_member_count(a.foundation_id()))
+            .unwrap_or_default()
+    }
+    /// Number of members of the committee reviewing the milestone
+    pub fn milestone_member_count(
+        application: T::ApplicationId,
+        milestone: T::MilestoneId,
+    ) -> u32 {
+        <Milestones<T>>::get(application, milestone)
+            .map(|m| Self::committee_member_count(m.base_foundation()))
+            .unwrap_or_default()
+    }
+    /// Number of members of the team paid for the milestone, 0 if it is
+    /// paid to an account
+    pub fn milestone_recipient_count(
+        application: T::ApplicationId,
+        milestone: T::MilestoneId,
+    ) -> u32 {
+        <Milestones<T>>::get(application, milestone)
+            .and_then(|m| m.recipient().org())
+            .map(|o| <org::Module<T>>::member_count(o.org()))
+            .unwrap_or_default()
+    }
     fn milestone_id_is_available(
         application: T::ApplicationId,
         id: T::MilestoneId,
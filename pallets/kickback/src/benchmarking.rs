@@ -0,0 +1,75 @@
+//! Kickback pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const MAX_PRESENT: u32 = 100;
+
+/// Posts an event supervised by `supervisor` with `n` seats
+fn kickback_event<T: Trait>(
+    supervisor: &T::AccountId,
+    n: u32,
+) -> Result<T::KickbackEventId, DispatchError> {
+    <Module<T>>::post_kickback_event(
+        RawOrigin::Signed(supervisor.clone()).into(),
+        T::IpfsReference::default(),
+        T::MinReservationReq::get(),
+        n,
+    )?;
+    Ok(<KickbackEventNonce<T>>::get())
+}
+
+/// Funds `who` and reserves a seat at `id`
+fn reserve<T: Trait>(
+    id: T::KickbackEventId,
+    who: &T::AccountId,
+) -> DispatchResult {
+    let req = T::MinReservationReq::get();
+    T::Currency::make_free_balance_be(who, req + req);
+    <Module<T>>::reserve_seat(RawOrigin::Signed(who.clone()).into(), id)
+}
+
+benchmarks! {
+    _ { }
+
+    post_kickback_event {
+        let caller: T::AccountId = account("caller", 0, SEED);
+    }: _(RawOrigin::Signed(caller), T::IpfsReference::default(), T::MinReservationReq::get(), MAX_PRESENT)
+    verify {
+        assert!(<KickbackEvents<T>>::get(<KickbackEventNonce<T>>::get()).is_some());
+    }
+
+    reserve_seat {
+        let supervisor: T::AccountId = account("supervisor", 0, SEED);
+        let id = kickback_event::<T>(&supervisor, MAX_PRESENT)?;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let req = T::MinReservationReq::get();
+        T::Currency::make_free_balance_be(&caller, req + req);
+    }: _(RawOrigin::Signed(caller.clone()), id)
+    verify {
+        assert!(<KickbackReservations<T>>::get(id, &caller).is_some());
+    }
+
+    publish_attendance_and_execute_redistribution {
+        let p in 1 .. MAX_PRESENT;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let id = kickback_event::<T>(&caller, p)?;
+        let mut present = (0..p)
+            .map(|i| account("attendee", i, SEED))
+            .collect::<Vec<T::AccountId>>();
+        for who in present.iter() {
+            reserve::<T>(id, who)?;
+        }
+        // attendance is binary searched so it must be sorted
+        present.sort();
+    }: _(RawOrigin::Signed(caller), id, present)
+    verify {
+        assert!(<KickbackEvents<T>>::get(id).is_none());
+    }
+}
@@ -7,6 +7,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -20,6 +23,7 @@ use frame_support::{
         ExistenceRequirement,
         Get,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::ensure_signed;
@@ -83,6 +87,27 @@ pub trait Trait: frame_system::Trait {
 
     /// Maximum attendance limit for posted events
     type MaxAttendance: Get<u32>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn post_kickback_event() -> Weight;
+    fn reserve_seat() -> Weight;
+    fn publish_attendance_and_execute_redistribution(p: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn post_kickback_event() -> Weight {
+        1_000_000_000
+    }
+    fn reserve_seat() -> Weight {
+        1_000_000_000
+    }
+    fn publish_attendance_and_execute_redistribution(_p: u32) -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -135,7 +160,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::post_kickback_event()]
         fn post_kickback_event(
             origin,
             info: T::IpfsReference,
@@ -151,7 +176,7 @@ decl_module! {
             Self::deposit_event(RawEvent::EventPosted(supervisor, reservation_req, id, info));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reserve_seat()]
         fn reserve_seat(
             origin,
             event_id: T::KickbackEventId,
@@ -171,7 +196,7 @@ decl_module! {
             Self::deposit_event(RawEvent::EventSeatReserved(event_id, reserver));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::publish_attendance_and_execute_redistribution(present.len() as u32)]
         pub fn publish_attendance_and_execute_redistribution(
             origin,
             id: T::KickbackEventId,
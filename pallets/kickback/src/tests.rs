@@ -94,6 +94,7 @@ impl Trait for Test {
     type EventPool = EventPool;
     type MinReservationReq = MinReservationReq;
     type MaxAttendance = MaxAttendance;
+    type WeightInfo = ();
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -0,0 +1,237 @@
+//! Org pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn accounts<T: Trait>(name: &'static str, n: u32) -> Vec<T::AccountId> {
+    (0..n).map(|i| account(name, i, SEED)).collect()
+}
+
+/// Registers a flat org supervised by `supervisor` with `n` members
+fn flat_org<T: Trait>(
+    supervisor: &T::AccountId,
+    n: u32,
+) -> Result<T::OrgId, DispatchError> {
+    <Module<T>>::register_organization(
+        OrganizationSource::Accounts(accounts::<T>("member", n)),
+        Some(supervisor.clone()),
+        T::IpfsReference::default(),
+    )
+}
+
+benchmarks! {
+    _ { }
+
+    register_flat_org {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let members = accounts::<T>("member", m);
+    }: _(RawOrigin::Signed(caller.clone()), Some(caller), None, T::IpfsReference::default(), members)
+
+    register_weighted_org {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let members = accounts::<T>("member", m)
+            .into_iter()
+            .map(|a| (a, 10u32.into()))
+            .collect::<Vec<(T::AccountId, T::Shares)>>();
+    }: _(RawOrigin::Signed(caller.clone()), Some(caller), None, T::IpfsReference::default(), members)
+
+    issue_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let who: T::AccountId = account("new", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone(), 10u32.into())
+    verify {
+        assert!(<Members<T>>::get(org, &who).is_some());
+    }
+
+    burn_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let who: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who.clone(), 1u32.into())
+    verify {
+        assert!(<Members<T>>::get(org, &who).is_none());
+    }
+
+    batch_issue_shares {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let new_accounts = accounts::<T>("new", m)
+            .into_iter()
+            .map(|a| (a, 10u32.into()))
+            .collect::<Vec<(T::AccountId, T::Shares)>>();
+    }: _(RawOrigin::Signed(caller), org, new_accounts)
+
+    batch_burn_shares {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, m)?;
+        let old_accounts = accounts::<T>("member", m)
+            .into_iter()
+            .map(|a| (a, 1u32.into()))
+            .collect::<Vec<(T::AccountId, T::Shares)>>();
+    }: _(RawOrigin::Signed(caller), org, old_accounts)
+    verify {
+        assert!(<TotalIssuance<T>>::get(org).is_zero());
+    }
+
+    lock_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let who: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who)
+
+    unlock_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let who: T::AccountId = account("member", 0, SEED);
+        <Module<T>>::lock_profile(org, &who)?;
+    }: _(RawOrigin::Signed(caller), org, who)
+
+    reserve_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let who: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, who)
+
+    unreserve_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let who: T::AccountId = account("member", 0, SEED);
+        <Module<T>>::reserve(org, &who, None)?;
+    }: _(RawOrigin::Signed(caller), org, who)
+
+    transfer_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let member: T::AccountId = account("member", 0, SEED);
+        let to: T::AccountId = account("new", 0, SEED);
+    }: _(RawOrigin::Signed(member.clone()), org, to.clone(), 1u32.into())
+    verify {
+        assert!(<Members<T>>::get(org, &member).is_none());
+        assert!(<Members<T>>::get(org, &to).is_some());
+    }
+
+    propose_supervisor {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, m)?;
+        let member: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(member.clone()), org, Some(member))
+    verify {
+        assert!(<SupervisorProposals<T>>::get(org).is_some());
+    }
+
+    poll_supervisor_proposal {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let member: T::AccountId = account("member", 0, SEED);
+        <Module<T>>::propose_supervisor(RawOrigin::Signed(member.clone()).into(), org, None)?;
+    }: _(RawOrigin::Signed(member), org)
+
+    request_membership {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let applicant: T::AccountId = account("applicant", 0, SEED);
+    }: _(RawOrigin::Signed(applicant.clone()), org, T::IpfsReference::default())
+    verify {
+        assert!(<MembershipRequests<T>>::get(org, &applicant).is_some());
+    }
+
+    approve_membership_request {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let applicant: T::AccountId = account("applicant", 0, SEED);
+        <Module<T>>::request_membership(RawOrigin::Signed(applicant.clone()).into(), org, T::IpfsReference::default())?;
+    }: _(RawOrigin::Signed(caller), org, applicant.clone())
+    verify {
+        assert!(<Members<T>>::get(org, &applicant).is_some());
+    }
+
+    trigger_membership_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, m)?;
+        let member: T::AccountId = account("member", 0, SEED);
+        let applicant: T::AccountId = account("applicant", 0, SEED);
+        <Module<T>>::request_membership(RawOrigin::Signed(applicant.clone()).into(), org, T::IpfsReference::default())?;
+    }: _(RawOrigin::Signed(member), org, applicant.clone())
+    verify {
+        assert!(<MembershipRequests<T>>::get(org, &applicant).and_then(|(_, vote)| vote).is_some());
+    }
+
+    poll_membership_request {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let member: T::AccountId = account("member", 0, SEED);
+        let applicant: T::AccountId = account("applicant", 0, SEED);
+        <Module<T>>::request_membership(RawOrigin::Signed(applicant.clone()).into(), org, T::IpfsReference::default())?;
+        <Module<T>>::trigger_membership_vote(RawOrigin::Signed(member.clone()).into(), org, applicant.clone())?;
+    }: _(RawOrigin::Signed(member), org, applicant)
+
+    invite_member {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let invitee: T::AccountId = account("invitee", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, invitee.clone(), 10u32.into())
+    verify {
+        assert!(<Invitations<T>>::get(org, &invitee).is_some());
+    }
+
+    accept_invitation {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let invitee: T::AccountId = account("invitee", 0, SEED);
+        <Module<T>>::invite_member(RawOrigin::Signed(caller).into(), org, invitee.clone(), 10u32.into())?;
+    }: _(RawOrigin::Signed(invitee.clone()), org)
+    verify {
+        assert!(<Members<T>>::get(org, &invitee).is_some());
+    }
+
+    issue_vesting_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let member: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(caller), org, member.clone(), 100u32.into(), 1u32.into(), 10u32.into())
+    verify {
+        assert!(<VestingSchedules<T>>::get(org, &member).is_some());
+    }
+
+    release_vested_shares {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let member: T::AccountId = account("member", 0, SEED);
+        <Module<T>>::issue_vesting_shares(RawOrigin::Signed(caller).into(), org, member.clone(), 100u32.into(), 1u32.into(), 10u32.into())?;
+        frame_system::Module::<T>::set_block_number(50u32.into());
+    }: _(RawOrigin::Signed(member.clone()), org)
+    verify {
+        assert!(<VestingSchedules<T>>::get(org, &member).is_some());
+    }
+
+    propose_constitution_amendment {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, m)?;
+        let member: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(member), org, T::IpfsReference::default())
+    verify {
+        assert!(<ConstitutionAmendments<T>>::get(org).is_some());
+    }
+
+    poll_constitution_amendment {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, 1)?;
+        let member: T::AccountId = account("member", 0, SEED);
+        <Module<T>>::propose_constitution_amendment(RawOrigin::Signed(member.clone()).into(), org, T::IpfsReference::default())?;
+    }: _(RawOrigin::Signed(member), org)
+}
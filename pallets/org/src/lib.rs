@@ -8,22 +8,32 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migration;
+
 use util::{
     organization::{
+        OrgRep,
         Organization,
         OrganizationSource,
     },
     share::{
         ShareProfile,
         SimpleShareGenesis,
+        VestingSchedule,
     },
     traits::{
         AccessGenesis,
+        CheckVoteExpiry,
         GenerateUniqueID,
         GetGroup,
+        GetVoteOutcome,
         GroupMembership,
         IDIsAvailable,
         LockProfile,
+        OpenVote,
         OrganizationSupervisorPermissions,
         RegisterOrganization,
         RemoveOrganization,
@@ -32,9 +42,17 @@ use util::{
         ShareIssuance,
         VerifyShape,
     },
+    vote::{
+        Threshold,
+        VoteOutcome,
+    },
 };
 
-use codec::Codec;
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use frame_support::{
     decl_error,
     decl_event,
@@ -45,6 +63,8 @@ use frame_support::{
         IterableStorageDoubleMap,
         IterableStorageMap,
     },
+    traits::Get,
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -58,18 +78,38 @@ use sp_runtime::{
         AtLeast32BitUnsigned,
         CheckedAdd,
         CheckedSub,
+        Convert,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
+    Permill,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
     prelude::*,
 };
 
+/// Largest org measured by the benchmarks of calls which iterate the members of an org
+pub const MAX_MEMBERS: u32 = 100;
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+/// Storage layout of the pallet, chains started before `MemberCount` are at `V0`
+pub enum Releases {
+    V0,
+    V1,
+}
+
+impl Default for Releases {
+    fn default() -> Releases {
+        Releases::V0
+    }
+}
+
 pub trait Trait: system::Trait {
     /// Overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -103,6 +143,140 @@ pub trait Trait: system::Trait {
         + CheckedSub
         + Zero
         + AtLeast32BitUnsigned;
+
+    /// The identifier of votes of the org's members
+    type OrgVoteId: Parameter
+        + Member
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// The votes in which members decide supervisor proposals and membership requests
+    type OrgVote: OpenVote<
+            OrgRep<Self::OrgId>,
+            Threshold<Self::Shares>,
+            Threshold<Permill>,
+            Self::BlockNumber,
+            Self::IpfsReference,
+            VoteIdentifier = Self::OrgVoteId,
+        > + GetVoteOutcome<Self::OrgVoteId, Outcome = VoteOutcome>
+        + CheckVoteExpiry<Self::OrgVoteId>;
+
+    /// Share of the org's ownership in favor required to replace the supervisor
+    type SupervisorVoteThreshold: Get<Permill>;
+
+    /// Share of the members in favor required to admit an applicant
+    type MembershipVoteThreshold: Get<Permill>;
+
+    /// Share of the org's ownership in favor required to amend the constitution
+    type ConstitutionVoteThreshold: Get<Permill>;
+
+    /// Blocks for which votes of the org's members remain open
+    type OrgVoteDuration: Get<Self::BlockNumber>;
+
+    /// Converts elapsed blocks into shares to compute the vested amount
+    type BlockNumberToShares: Convert<Self::BlockNumber, Self::Shares>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn register_flat_org(m: u32) -> Weight;
+    fn register_weighted_org(m: u32) -> Weight;
+    fn issue_shares() -> Weight;
+    fn burn_shares() -> Weight;
+    fn batch_issue_shares(m: u32) -> Weight;
+    fn batch_burn_shares(m: u32) -> Weight;
+    fn lock_shares() -> Weight;
+    fn unlock_shares() -> Weight;
+    fn reserve_shares() -> Weight;
+    fn unreserve_shares() -> Weight;
+    fn transfer_shares() -> Weight;
+    fn propose_supervisor(m: u32) -> Weight;
+    fn poll_supervisor_proposal() -> Weight;
+    fn request_membership() -> Weight;
+    fn approve_membership_request() -> Weight;
+    fn trigger_membership_vote(m: u32) -> Weight;
+    fn poll_membership_request() -> Weight;
+    fn invite_member() -> Weight;
+    fn accept_invitation() -> Weight;
+    fn issue_vesting_shares() -> Weight;
+    fn release_vested_shares() -> Weight;
+    fn propose_constitution_amendment(m: u32) -> Weight;
+    fn poll_constitution_amendment() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn register_flat_org(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn register_weighted_org(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn issue_shares() -> Weight {
+        1_000_000_000
+    }
+    fn burn_shares() -> Weight {
+        1_000_000_000
+    }
+    fn batch_issue_shares(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn batch_burn_shares(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn lock_shares() -> Weight {
+        1_000_000_000
+    }
+    fn unlock_shares() -> Weight {
+        1_000_000_000
+    }
+    fn reserve_shares() -> Weight {
+        1_000_000_000
+    }
+    fn unreserve_shares() -> Weight {
+        1_000_000_000
+    }
+    fn transfer_shares() -> Weight {
+        1_000_000_000
+    }
+    fn propose_supervisor(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn poll_supervisor_proposal() -> Weight {
+        1_000_000_000
+    }
+    fn request_membership() -> Weight {
+        1_000_000_000
+    }
+    fn approve_membership_request() -> Weight {
+        1_000_000_000
+    }
+    fn trigger_membership_vote(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn poll_membership_request() -> Weight {
+        1_000_000_000
+    }
+    fn invite_member() -> Weight {
+        1_000_000_000
+    }
+    fn accept_invitation() -> Weight {
+        1_000_000_000
+    }
+    fn issue_vesting_shares() -> Weight {
+        1_000_000_000
+    }
+    fn release_vested_shares() -> Weight {
+        1_000_000_000
+    }
+    fn propose_constitution_amendment(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn poll_constitution_amendment() -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -112,6 +286,7 @@ decl_event!(
         OrgId = <T as Trait>::OrgId,
         <T as Trait>::Shares,
         <T as Trait>::IpfsReference,
+        VoteId = <T as Trait>::OrgVoteId,
     {
         /// No shares issued but an organization was registered with flat membership with the last `u32` as the number of members
         NewFlatOrganizationRegistered(AccountId, OrgId, IpfsReference, u32),
@@ -141,6 +316,36 @@ decl_event!(
         SharesBatchIssued(OrgId, Shares),
         /// Organization ID, Total Shares Burned
         SharesBatchBurned(OrgId, Shares),
+        /// Organization ID, Sender AccountId, Recipient AccountId, Transferred Amount
+        SharesTransferred(OrgId, AccountId, AccountId, Shares),
+        /// Proposer, Organization ID, Proposed Supervisor (None to remove it), Vote ID
+        SupervisorProposed(AccountId, OrgId, Option<AccountId>, VoteId),
+        /// Organization ID, Old Supervisor, New Supervisor
+        SupervisorReplaced(OrgId, Option<AccountId>, Option<AccountId>),
+        /// Organization ID, Vote ID
+        SupervisorProposalRejected(OrgId, VoteId),
+        /// Applicant, Organization ID, Application
+        MembershipRequested(AccountId, OrgId, IpfsReference),
+        /// Trigger AccountId, Organization ID, Applicant, Vote ID
+        MembershipVoteTriggered(AccountId, OrgId, AccountId, VoteId),
+        /// Organization ID, Admitted Applicant
+        MembershipRequestApproved(OrgId, AccountId),
+        /// Organization ID, Rejected Applicant
+        MembershipRequestRejected(OrgId, AccountId),
+        /// Organization ID, Invitee, Shares issued on acceptance
+        MemberInvited(OrgId, AccountId, Shares),
+        /// Organization ID, New Member, Issued Amount
+        InvitationAccepted(OrgId, AccountId, Shares),
+        /// Organization ID, Recipient AccountId, Issued Amount that vests over time
+        VestingSharesIssued(OrgId, AccountId, Shares),
+        /// Organization ID, Member, Amount Vested since the last release
+        VestedSharesReleased(OrgId, AccountId, Shares),
+        /// Proposer, Organization ID, Proposed Constitution, Vote ID
+        ConstitutionAmendmentProposed(AccountId, OrgId, IpfsReference, VoteId),
+        /// Organization ID, Old Constitution, New Constitution
+        ConstitutionAmended(OrgId, IpfsReference, IpfsReference),
+        /// Organization ID, Vote ID
+        ConstitutionAmendmentRejected(OrgId, VoteId),
     }
 );
 
@@ -179,6 +384,42 @@ decl_error! {
         NotAuthorizedToBurnShares,
         OrganizationCannotBeRemovedIfInputIdIsAvailable,
         AccountHasNoOwnershipInOrg,
+        OrganizationMustBeRegisteredToTransferShares,
+        CannotTransferSharesToSelf,
+        CannotTransferZeroShares,
+        NotEnoughSharesToSatisfyTransferRequest,
+        CannotTransferLockedShares,
+        CannotTransferReservedShares,
+        OrganizationMustBeRegisteredToProposeSupervisor,
+        NotAuthorizedToProposeSupervisor,
+        SupervisorProposalAlreadyOpen,
+        NotAuthorizedToPollSupervisorProposal,
+        NoSupervisorProposalToPoll,
+        OrganizationMustBeRegisteredToRequestMembership,
+        AlreadyAMemberOfOrg,
+        MembershipRequestAlreadyPending,
+        MembershipRequestDNE,
+        NotAuthorizedToApproveMembershipRequest,
+        NotAuthorizedToTriggerMembershipVote,
+        MembershipVoteAlreadyTriggered,
+        NotAuthorizedToPollMembershipRequest,
+        NoMembershipVoteToPoll,
+        OrganizationMustBeRegisteredToInviteMember,
+        NotAuthorizedToInviteMember,
+        CannotInviteForZeroShares,
+        InvitationDNE,
+        OrganizationMustBeRegisteredToIssueVestingShares,
+        NotAuthorizedToIssueVestingShares,
+        CannotVestZeroShares,
+        VestingPerBlockMustBeNonZero,
+        MemberAlreadyHasVestingSchedule,
+        NoVestingScheduleToRelease,
+        CannotTransferUnvestedShares,
+        OrganizationMustBeRegisteredToProposeConstitutionAmendment,
+        NotAuthorizedToProposeConstitutionAmendment,
+        ConstitutionAmendmentAlreadyOpen,
+        NotAuthorizedToPollConstitutionAmendment,
+        NoConstitutionAmendmentToPoll,
     }
 }
 
@@ -202,6 +443,40 @@ decl_storage! {
         /// Total number of outstanding shares that express relative ownership in group
         pub TotalIssuance get(fn total_issuance): map
             hasher(opaque_blake2_256) T::OrgId => T::Shares;
+
+        /// Number of members of each org, which weighs calls iterating the members
+        pub MemberCount get(fn member_count): map
+            hasher(blake2_128_concat) T::OrgId => u32;
+
+        /// Storage layout version, bumped by each migration
+        StorageVersion get(fn storage_version) build(|_: &GenesisConfig<T>| Releases::V1): Releases;
+
+        /// Open proposal of each org to replace its supervisor, the vote deciding it and the proposed supervisor
+        pub SupervisorProposals get(fn supervisor_proposals): map
+            hasher(blake2_128_concat) T::OrgId => Option<(T::OrgVoteId, Option<T::AccountId>)>;
+
+        /// Pending requests to join an org, their application and the vote deciding them once triggered
+        pub MembershipRequests get(fn membership_requests): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<(T::IpfsReference, Option<T::OrgVoteId>)>;
+
+        /// Pending invitations to join an org and the shares issued once accepted
+        pub Invitations get(fn invitations): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::Shares>;
+
+        /// Vesting schedule of each member's shares, excluded from their vested ownership until they vest
+        pub VestingSchedules get(fn vesting_schedules): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<VestingSchedule<T::Shares, T::BlockNumber>>;
+
+        /// Open amendment of each org's constitution, the vote deciding it and the proposed constitution
+        pub ConstitutionAmendments get(fn constitution_amendments): map
+            hasher(blake2_128_concat) T::OrgId => Option<(T::OrgVoteId, T::IpfsReference)>;
+
+        /// Constitutions replaced by amendments, oldest first
+        pub ConstitutionHistory get(fn constitution_history): map
+            hasher(blake2_128_concat) T::OrgId => Vec<T::IpfsReference>;
     }
     add_extra_genesis {
         config(first_organization_supervisor): T::AccountId;
@@ -225,7 +500,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::register_flat_org(members.len() as u32)]
         fn register_flat_org(
             origin,
             sudo: Option<T::AccountId>,
@@ -243,7 +518,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewFlatOrganizationRegistered(caller, new_id, constitution, total));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::register_weighted_org(weighted_members.len() as u32)]
         fn register_weighted_org(
             origin,
             sudo: Option<T::AccountId>,
@@ -263,7 +538,7 @@ decl_module! {
             Ok(())
         }
         /// Share Issuance Runtime Methods
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::issue_shares()]
         fn issue_shares(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares) -> DispatchResult {
             let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -276,7 +551,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesIssued(organization, who, shares));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::burn_shares()]
         fn burn_shares(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares) -> DispatchResult {
             let burner = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -289,7 +564,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesBurned(organization, who, shares));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::batch_issue_shares(new_accounts.len() as u32)]
         fn batch_issue_shares(origin, organization: T::OrgId, new_accounts: Vec<(T::AccountId, T::Shares)>) -> DispatchResult {
             let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -303,7 +578,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesBatchIssued(organization, total_new_shares_minted));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::batch_burn_shares(old_accounts.len() as u32)]
         fn batch_burn_shares(origin, organization: T::OrgId, old_accounts: Vec<(T::AccountId, T::Shares)>) -> DispatchResult {
             let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -317,7 +592,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesBatchBurned(organization, total_new_shares_burned));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::lock_shares()]
         fn lock_shares(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
             let locker = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -331,7 +606,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesLocked(organization, who));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::unlock_shares()]
         fn unlock_shares(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
             let unlocker = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -345,7 +620,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesUnlocked(organization, who));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::reserve_shares()]
         fn reserve_shares(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
             let reserver = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -360,7 +635,7 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesReserved(organization, who, amount_reserved));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::unreserve_shares()]
         fn unreserve_shares(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
             let unreserver = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -374,10 +649,289 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesUnReserved(organization, who, amount_unreserved));
             Ok(())
         }
+        #[weight = <T as Trait>::WeightInfo::transfer_shares()]
+        fn transfer_shares(origin, organization: T::OrgId, to: T::AccountId, shares: T::Shares) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            // first check is that the organization exists
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToTransferShares);
+            ensure!(sender != to, Error::<T>::CannotTransferSharesToSelf);
+            ensure!(!shares.is_zero(), Error::<T>::CannotTransferZeroShares);
+
+            Self::transfer(organization, &sender, &to, shares)?;
+            Self::deposit_event(RawEvent::SharesTransferred(organization, sender, to, shares));
+            Ok(())
+        }
+        /// Opens a vote of the members on replacing the supervisor with `new_supervisor`
+        #[weight = <T as Trait>::WeightInfo::propose_supervisor(<MemberCount<T>>::get(organization))]
+        pub fn propose_supervisor(origin, organization: T::OrgId, new_supervisor: Option<T::AccountId>) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToProposeSupervisor);
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedToProposeSupervisor);
+            ensure!(<SupervisorProposals<T>>::get(organization).is_none(), Error::<T>::SupervisorProposalAlreadyOpen);
+
+            let vote_id = T::OrgVote::open_percent_vote(
+                None,
+                OrgRep::Weighted(organization),
+                Threshold::new(T::SupervisorVoteThreshold::get(), None),
+                Some(T::OrgVoteDuration::get()),
+            )?;
+            <SupervisorProposals<T>>::insert(organization, (vote_id, new_supervisor.clone()));
+            Self::deposit_event(RawEvent::SupervisorProposed(proposer, organization, new_supervisor, vote_id));
+            Ok(())
+        }
+        /// Replaces the supervisor if the vote on the open proposal was approved
+        #[weight = <T as Trait>::WeightInfo::poll_supervisor_proposal()]
+        pub fn poll_supervisor_proposal(origin, organization: T::OrgId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &caller), Error::<T>::NotAuthorizedToPollSupervisorProposal);
+            let (vote_id, new_supervisor) = <SupervisorProposals<T>>::get(organization)
+                .ok_or(Error::<T>::NoSupervisorProposalToPoll)?;
+
+            match Self::org_vote_outcome(vote_id)? {
+                VoteOutcome::Approved => {
+                    let old_supervisor = <OrganizationStates<T>>::get(organization)
+                        .ok_or(Error::<T>::OrgDNE)?
+                        .sudo();
+                    if let Some(who) = new_supervisor.clone() {
+                        Self::put_organization_supervisor(organization, who)?;
+                    } else {
+                        Self::clear_organization_supervisor(organization)?;
+                    }
+                    <SupervisorProposals<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::SupervisorReplaced(organization, old_supervisor, new_supervisor));
+                }
+                VoteOutcome::Rejected => {
+                    <SupervisorProposals<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::SupervisorProposalRejected(organization, vote_id));
+                }
+                // still voting
+                _ => (),
+            }
+            Ok(())
+        }
+        /// Applies to join the org, admitted by the supervisor or by a vote of the members
+        #[weight = <T as Trait>::WeightInfo::request_membership()]
+        pub fn request_membership(origin, organization: T::OrgId, application: T::IpfsReference) -> DispatchResult {
+            let applicant = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToRequestMembership);
+            ensure!(!Self::is_member_of_group(organization, &applicant), Error::<T>::AlreadyAMemberOfOrg);
+            ensure!(<MembershipRequests<T>>::get(organization, &applicant).is_none(), Error::<T>::MembershipRequestAlreadyPending);
+
+            <MembershipRequests<T>>::insert(organization, &applicant, (application.clone(), None::<T::OrgVoteId>));
+            Self::deposit_event(RawEvent::MembershipRequested(applicant, organization, application));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::approve_membership_request()]
+        fn approve_membership_request(origin, organization: T::OrgId, applicant: T::AccountId) -> DispatchResult {
+            let approver = ensure_signed(origin)?;
+            ensure!(Self::is_organization_supervisor(organization, &approver), Error::<T>::NotAuthorizedToApproveMembershipRequest);
+            ensure!(<MembershipRequests<T>>::get(organization, &applicant).is_some(), Error::<T>::MembershipRequestDNE);
+
+            Self::admit(organization, applicant)
+        }
+        /// Opens a vote of the members, with equal weight, on admitting the applicant
+        #[weight = <T as Trait>::WeightInfo::trigger_membership_vote(<MemberCount<T>>::get(organization))]
+        pub fn trigger_membership_vote(origin, organization: T::OrgId, applicant: T::AccountId) -> DispatchResult {
+            let trigger = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &trigger), Error::<T>::NotAuthorizedToTriggerMembershipVote);
+            let (application, vote) = <MembershipRequests<T>>::get(organization, &applicant)
+                .ok_or(Error::<T>::MembershipRequestDNE)?;
+            ensure!(vote.is_none(), Error::<T>::MembershipVoteAlreadyTriggered);
+
+            let vote_id = T::OrgVote::open_percent_vote(
+                Some(application.clone()),
+                OrgRep::Equal(organization),
+                Threshold::new(T::MembershipVoteThreshold::get(), None),
+                Some(T::OrgVoteDuration::get()),
+            )?;
+            <MembershipRequests<T>>::insert(organization, &applicant, (application, Some(vote_id)));
+            Self::deposit_event(RawEvent::MembershipVoteTriggered(trigger, organization, applicant, vote_id));
+            Ok(())
+        }
+        /// Admits or drops the applicant once the vote on their request is decided
+        #[weight = <T as Trait>::WeightInfo::poll_membership_request()]
+        pub fn poll_membership_request(origin, organization: T::OrgId, applicant: T::AccountId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &caller), Error::<T>::NotAuthorizedToPollMembershipRequest);
+            let (_, vote) = <MembershipRequests<T>>::get(organization, &applicant)
+                .ok_or(Error::<T>::MembershipRequestDNE)?;
+            let vote_id = vote.ok_or(Error::<T>::NoMembershipVoteToPoll)?;
+
+            match Self::org_vote_outcome(vote_id)? {
+                VoteOutcome::Approved => Self::admit(organization, applicant)?,
+                VoteOutcome::Rejected => {
+                    <MembershipRequests<T>>::remove(organization, &applicant);
+                    Self::deposit_event(RawEvent::MembershipRequestRejected(organization, applicant));
+                }
+                // still voting
+                _ => (),
+            }
+            Ok(())
+        }
+        /// Invites `who` to join the org, `shares` are issued once they accept
+        #[weight = <T as Trait>::WeightInfo::invite_member()]
+        fn invite_member(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares) -> DispatchResult {
+            let inviter = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToInviteMember);
+            ensure!(Self::is_organization_supervisor(organization, &inviter), Error::<T>::NotAuthorizedToInviteMember);
+            ensure!(!shares.is_zero(), Error::<T>::CannotInviteForZeroShares);
+
+            <Invitations<T>>::insert(organization, &who, shares);
+            Self::deposit_event(RawEvent::MemberInvited(organization, who, shares));
+            Ok(())
+        }
+        #[weight = <T as Trait>::WeightInfo::accept_invitation()]
+        fn accept_invitation(origin, organization: T::OrgId) -> DispatchResult {
+            let invitee = ensure_signed(origin)?;
+            let shares = <Invitations<T>>::take(organization, &invitee)
+                .ok_or(Error::<T>::InvitationDNE)?;
+
+            Self::issue(organization, invitee.clone(), shares, false)?;
+            Self::deposit_event(RawEvent::InvitationAccepted(organization, invitee, shares));
+            Ok(())
+        }
+        /// Issues `shares` that vest `per_block` once `cliff` blocks have passed, those accrued before the cliff vest at once
+        #[weight = <T as Trait>::WeightInfo::issue_vesting_shares()]
+        fn issue_vesting_shares(
+            origin,
+            organization: T::OrgId,
+            who: T::AccountId,
+            shares: T::Shares,
+            per_block: T::Shares,
+            cliff: T::BlockNumber,
+        ) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueVestingShares);
+            ensure!(Self::is_organization_supervisor(organization, &issuer), Error::<T>::NotAuthorizedToIssueVestingShares);
+            ensure!(!shares.is_zero(), Error::<T>::CannotVestZeroShares);
+            ensure!(!per_block.is_zero(), Error::<T>::VestingPerBlockMustBeNonZero);
+            ensure!(<VestingSchedules<T>>::get(organization, &who).is_none(), Error::<T>::MemberAlreadyHasVestingSchedule);
+
+            Self::issue(organization, who.clone(), shares, false)?;
+            let now = <frame_system::Module<T>>::block_number();
+            <VestingSchedules<T>>::insert(organization, &who, VestingSchedule::new(shares, per_block, now, now.saturating_add(cliff)));
+            Self::deposit_event(RawEvent::VestingSharesIssued(organization, who, shares));
+            Ok(())
+        }
+        /// Moves the caller's shares vested since the last release out of their schedule, which is removed once fully vested
+        #[weight = <T as Trait>::WeightInfo::release_vested_shares()]
+        fn release_vested_shares(origin, organization: T::OrgId) -> DispatchResult {
+            let member = ensure_signed(origin)?;
+            let schedule = <VestingSchedules<T>>::get(organization, &member)
+                .ok_or(Error::<T>::NoVestingScheduleToRelease)?;
+
+            let new_schedule = schedule.release::<T::BlockNumberToShares>(<frame_system::Module<T>>::block_number());
+            let released = schedule.unvested().saturating_sub(new_schedule.unvested());
+            Self::set_vesting_schedule(organization, &member, new_schedule);
+            Self::deposit_event(RawEvent::VestedSharesReleased(organization, member, released));
+            Ok(())
+        }
+        /// Opens a vote of the members on replacing the constitution with `new_constitution`
+        #[weight = <T as Trait>::WeightInfo::propose_constitution_amendment(<MemberCount<T>>::get(organization))]
+        pub fn propose_constitution_amendment(origin, organization: T::OrgId, new_constitution: T::IpfsReference) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToProposeConstitutionAmendment);
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedToProposeConstitutionAmendment);
+            ensure!(<ConstitutionAmendments<T>>::get(organization).is_none(), Error::<T>::ConstitutionAmendmentAlreadyOpen);
+
+            let vote_id = T::OrgVote::open_percent_vote(
+                Some(new_constitution.clone()),
+                OrgRep::Weighted(organization),
+                Threshold::new(T::ConstitutionVoteThreshold::get(), None),
+                Some(T::OrgVoteDuration::get()),
+            )?;
+            <ConstitutionAmendments<T>>::insert(organization, (vote_id, new_constitution.clone()));
+            Self::deposit_event(RawEvent::ConstitutionAmendmentProposed(proposer, organization, new_constitution, vote_id));
+            Ok(())
+        }
+        /// Replaces the constitution if the vote on the open amendment was approved, the old one is kept in the history
+        #[weight = <T as Trait>::WeightInfo::poll_constitution_amendment()]
+        pub fn poll_constitution_amendment(origin, organization: T::OrgId) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &caller), Error::<T>::NotAuthorizedToPollConstitutionAmendment);
+            let (vote_id, new_constitution) = <ConstitutionAmendments<T>>::get(organization)
+                .ok_or(Error::<T>::NoConstitutionAmendmentToPoll)?;
+
+            match Self::org_vote_outcome(vote_id)? {
+                VoteOutcome::Approved => {
+                    let old_org = <OrganizationStates<T>>::get(organization)
+                        .ok_or(Error::<T>::OrgDNE)?;
+                    let old_constitution = old_org.constitution();
+                    <OrganizationStates<T>>::insert(organization, old_org.put_constitution(new_constitution.clone()));
+                    <ConstitutionHistory<T>>::append(organization, old_constitution.clone());
+                    <ConstitutionAmendments<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::ConstitutionAmended(organization, old_constitution, new_constitution));
+                }
+                VoteOutcome::Rejected => {
+                    <ConstitutionAmendments<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::ConstitutionAmendmentRejected(organization, vote_id));
+                }
+                // still voting
+                _ => (),
+            }
+            Ok(())
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
+    /// Outcome of a vote of the org's members, votes that expired without approval are rejected
+    fn org_vote_outcome(
+        vote_id: T::OrgVoteId,
+    ) -> Result<VoteOutcome, DispatchError> {
+        let outcome = T::OrgVote::get_vote_outcome(vote_id)?;
+        if outcome != VoteOutcome::Approved && T::OrgVote::vote_expired(vote_id)
+        {
+            Ok(VoteOutcome::Rejected)
+        } else {
+            Ok(outcome)
+        }
+    }
+    /// Shares of `who` excluding those still unvested at the current block
+    pub fn vested_shares(
+        organization: T::OrgId,
+        who: &T::AccountId,
+    ) -> T::Shares {
+        let total = <Members<T>>::get(organization, who)
+            .map(|p| p.total())
+            .unwrap_or_else(Zero::zero);
+        let now = <frame_system::Module<T>>::block_number();
+        let unvested = <VestingSchedules<T>>::get(organization, who)
+            .map(|s| s.unvested_at::<T::BlockNumberToShares>(now))
+            .unwrap_or_else(Zero::zero);
+        total.saturating_sub(unvested)
+    }
+    /// Shares issued by the org excluding those still unvested at the current block
+    pub fn vested_issuance(organization: T::OrgId) -> T::Shares {
+        let now = <frame_system::Module<T>>::block_number();
+        let unvested = <VestingSchedules<T>>::iter_prefix(organization).fold(
+            T::Shares::zero(),
+            |unvested, (_, s)| {
+                unvested.saturating_add(
+                    s.unvested_at::<T::BlockNumberToShares>(now),
+                )
+            },
+        );
+        <TotalIssuance<T>>::get(organization).saturating_sub(unvested)
+    }
+    /// The membership of the org weighted by vested shares, members without any are left out
+    pub fn vested_membership_with_shape(
+        organization: T::OrgId,
+    ) -> Option<SimpleShareGenesis<T::AccountId, T::Shares>> {
+        if Self::id_is_available(organization) {
+            return None
+        }
+        Some(
+            <Members<T>>::iter_prefix(organization)
+                .map(|(account, _)| {
+                    let vested = Self::vested_shares(organization, &account);
+                    (account, vested)
+                })
+                .filter(|(_, vested)| !vested.is_zero())
+                .collect::<Vec<(T::AccountId, T::Shares)>>()
+                .into(),
+        )
+    }
     pub fn is_immediate_child(
         parent: Option<T::OrgId>,
         child: T::OrgId,
@@ -397,6 +951,79 @@ impl<T: Trait> Module<T> {
             Some(ret)
         }
     }
+    /// Moves shares between member profiles without changing the total issuance
+    fn transfer(
+        organization: T::OrgId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        let from_profile = <Members<T>>::get(organization, from)
+            .ok_or(Error::<T>::NotEnoughSharesToSatisfyTransferRequest)?;
+        ensure!(
+            from_profile.is_unlocked(),
+            Error::<T>::CannotTransferLockedShares
+        );
+        ensure!(
+            from_profile.times_reserved() == 0,
+            Error::<T>::CannotTransferReservedShares
+        );
+        ensure!(
+            from_profile.total() >= amount,
+            Error::<T>::NotEnoughSharesToSatisfyTransferRequest
+        );
+        ensure!(
+            Self::vested_shares(organization, from) >= amount,
+            Error::<T>::CannotTransferUnvestedShares
+        );
+        let to_profile = if let Some(existing_profile) =
+            <Members<T>>::get(organization, to)
+        {
+            existing_profile.add_shares(amount)
+        } else {
+            <MemberCount<T>>::mutate(organization, |n| {
+                *n = n.saturating_add(1)
+            });
+            ShareProfile::new_shares(amount)
+        };
+        let from_profile = from_profile.subtract_shares(amount);
+        if from_profile.is_zero() {
+            // leave the group
+            <Members<T>>::remove(organization, from);
+            <MemberCount<T>>::mutate(organization, |n| {
+                *n = n.saturating_sub(1)
+            });
+        } else {
+            <Members<T>>::insert(organization, from, from_profile);
+        }
+        <Members<T>>::insert(organization, to, to_profile);
+        Ok(())
+    }
+    /// Issues a single share to an applicant whose request was approved
+    fn admit(
+        organization: T::OrgId,
+        applicant: T::AccountId,
+    ) -> DispatchResult {
+        Self::issue(organization, applicant.clone(), 1u32.into(), false)?;
+        <MembershipRequests<T>>::remove(organization, &applicant);
+        Self::deposit_event(RawEvent::MembershipRequestApproved(
+            organization,
+            applicant,
+        ));
+        Ok(())
+    }
+    /// Fully vested schedules are removed from storage
+    fn set_vesting_schedule(
+        organization: T::OrgId,
+        who: &T::AccountId,
+        schedule: VestingSchedule<T::Shares, T::BlockNumber>,
+    ) {
+        if schedule.is_vested() {
+            <VestingSchedules<T>>::remove(organization, who);
+        } else {
+            <VestingSchedules<T>>::insert(organization, who, schedule);
+        }
+    }
 }
 
 impl<T: Trait> GroupMembership<T::OrgId, T::AccountId> for Module<T> {
@@ -529,6 +1156,12 @@ impl<T: Trait> RemoveOrganization<T::OrgId> for Module<T> {
             Error::<T>::OrganizationCannotBeRemovedIfInputIdIsAvailable
         );
         <OrganizationStates<T>>::remove(id);
+        <SupervisorProposals<T>>::remove(id);
+        <MembershipRequests<T>>::remove_prefix(id);
+        <Invitations<T>>::remove_prefix(id);
+        <VestingSchedules<T>>::remove_prefix(id);
+        <ConstitutionAmendments<T>>::remove(id);
+        <ConstitutionHistory<T>>::remove(id);
         let new_org_count = <OrganizationCounter>::get().saturating_sub(1u32);
         <OrganizationCounter>::put(new_org_count);
         Ok(())
@@ -542,6 +1175,12 @@ impl<T: Trait> RemoveOrganization<T::OrgId> for Module<T> {
             .filter(|(_, org)| (*org).parent() == Some(id))
             .map(|(child_id, _)| -> DispatchResult {
                 <OrganizationStates<T>>::remove(child_id);
+                <SupervisorProposals<T>>::remove(child_id);
+                <MembershipRequests<T>>::remove_prefix(child_id);
+                <Invitations<T>>::remove_prefix(child_id);
+                <VestingSchedules<T>>::remove_prefix(child_id);
+                <ConstitutionAmendments<T>>::remove(child_id);
+                <ConstitutionHistory<T>>::remove(child_id);
                 let new_org_count =
                     <OrganizationCounter>::get().saturating_sub(1u32);
                 <OrganizationCounter>::put(new_org_count);
@@ -614,6 +1253,9 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         {
             existing_profile.add_shares(amount)
         } else {
+            <MemberCount<T>>::mutate(organization, |n| {
+                *n = n.saturating_add(1)
+            });
             ShareProfile::new_shares(amount)
         };
         if !batch {
@@ -650,9 +1292,22 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
             <TotalIssuance<T>>::insert(organization, new_issuance);
         }
         let new_profile = old_profile.subtract_shares(amt_to_burn);
+        // burned shares are taken from the unvested shares first
+        if let Some(schedule) =
+            <VestingSchedules<T>>::get(organization, &old_owner)
+        {
+            let new_schedule = schedule.burn::<T::BlockNumberToShares>(
+                amt_to_burn,
+                <frame_system::Module<T>>::block_number(),
+            );
+            Self::set_vesting_schedule(organization, &old_owner, new_schedule);
+        }
         if new_profile.is_zero() {
             // leave the group
             <Members<T>>::remove(organization, old_owner);
+            <MemberCount<T>>::mutate(organization, |n| {
+                *n = n.saturating_sub(1)
+            });
         } else {
             <Members<T>>::insert(organization, old_owner, new_profile);
         }
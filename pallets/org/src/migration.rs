@@ -0,0 +1,25 @@
+//! Migration of the org storage
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+/// Counts the members of every org into `MemberCount`. Runs once, for chains
+/// at `Releases::V0`.
+pub struct CountMembers<T>(PhantomData<T>);
+
+impl<T: Trait> OnRuntimeUpgrade for CountMembers<T> {
+    fn on_runtime_upgrade() -> Weight {
+        if <StorageVersion>::get() != Releases::V0 {
+            return <T as frame_system::Trait>::DbWeight::get().reads(1)
+        }
+        let mut members = 0 as Weight;
+        <Members<T>>::iter().for_each(|(org, _, _)| {
+            <MemberCount<T>>::mutate(org, |n| *n = n.saturating_add(1));
+            members += 1;
+        });
+        <StorageVersion>::put(Releases::V1);
+        <T as frame_system::Trait>::DbWeight::get()
+            .reads_writes(1 + 2 * members, 1 + members)
+    }
+}
@@ -1,19 +1,28 @@
 #![cfg(test)]
 
-use super::*;
+use super::{
+    migration::CountMembers,
+    *,
+};
 use frame_support::{
+    assert_noop,
     assert_ok,
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::OnRuntimeUpgrade,
     weights::Weight,
 };
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::IdentityLookup,
+    traits::{
+        ConvertInto,
+        IdentityLookup,
+    },
     Perbill,
 };
+use std::cell::RefCell;
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -68,16 +77,70 @@ impl frame_system::Trait for TestRuntime {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+thread_local! {
+    static VOTE_OUTCOME: RefCell<VoteOutcome> = RefCell::new(VoteOutcome::Voting);
+}
+/// Stands in for the vote module, every vote has the outcome in `VOTE_OUTCOME`
+pub struct MockVote;
+impl OpenVote<OrgRep<u64>, Threshold<u64>, Threshold<Permill>, u64, u32>
+    for MockVote
+{
+    type VoteIdentifier = u64;
+    fn open_vote(
+        _topic: Option<u32>,
+        _organization: OrgRep<u64>,
+        _threshold: Threshold<u64>,
+        _duration: Option<u64>,
+    ) -> Result<u64, DispatchError> {
+        Ok(1)
+    }
+    fn open_percent_vote(
+        _topic: Option<u32>,
+        _organization: OrgRep<u64>,
+        _threshold: Threshold<Permill>,
+        _duration: Option<u64>,
+    ) -> Result<u64, DispatchError> {
+        Ok(1)
+    }
+}
+impl CheckVoteExpiry<u64> for MockVote {
+    fn vote_expired(_vote_id: u64) -> bool {
+        false
+    }
+}
+impl GetVoteOutcome<u64> for MockVote {
+    type Outcome = VoteOutcome;
+    fn get_vote_outcome(_vote_id: u64) -> Result<VoteOutcome, DispatchError> {
+        Ok(VOTE_OUTCOME.with(|o| *o.borrow()))
+    }
+}
+fn set_vote_outcome(outcome: VoteOutcome) {
+    VOTE_OUTCOME.with(|o| *o.borrow_mut() = outcome);
+}
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: u64 = 10;
+}
 impl Trait for TestRuntime {
     type Event = TestEvent;
     type IpfsReference = u32; // TODO: replace with utils_identity::Cid
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = MockVote;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = ();
 }
 pub type System = system::Module<TestRuntime>;
 pub type Org = Module<TestRuntime>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u32> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u32, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -298,3 +361,314 @@ fn share_burn() {
         assert_eq!(post_shares, 6);
     });
 }
+
+#[test]
+fn share_transfer() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 9));
+        let pre_issuance = Org::total_issuance(1);
+        assert_noop!(
+            Org::transfer_shares(two.clone(), 1, 3, 2),
+            Error::<TestRuntime>::NotEnoughSharesToSatisfyTransferRequest
+        );
+        assert_noop!(
+            Org::transfer_shares(one.clone(), 1, 1, 2),
+            Error::<TestRuntime>::CannotTransferSharesToSelf
+        );
+        // transfers create the profile of new members
+        assert_ok!(Org::transfer_shares(one.clone(), 1, 7, 4));
+        assert_eq!(get_last_event(), RawEvent::SharesTransferred(1, 1, 7, 4));
+        assert_eq!(Org::members(1, 1).unwrap().total(), 6);
+        assert_eq!(Org::members(1, 7).unwrap().total(), 4);
+        // the sender leaves the group once it transfers all of its shares
+        assert_ok!(Org::transfer_shares(two.clone(), 1, 7, 1));
+        assert!(Org::members(1, 2).is_none());
+        assert_eq!(Org::members(1, 7).unwrap().total(), 5);
+        assert_eq!(Org::total_issuance(1), pre_issuance);
+        // locked and reserved shares cannot move
+        assert_ok!(Org::lock_shares(one.clone(), 1, 1));
+        assert_noop!(
+            Org::transfer_shares(one.clone(), 1, 3, 1),
+            Error::<TestRuntime>::CannotTransferLockedShares
+        );
+        assert_ok!(Org::unlock_shares(one.clone(), 1, 1));
+        assert_ok!(Org::reserve_shares(one.clone(), 1, 1));
+        assert_noop!(
+            Org::transfer_shares(one, 1, 3, 1),
+            Error::<TestRuntime>::CannotTransferReservedShares
+        );
+    });
+}
+
+#[test]
+fn supervisor_replaced_by_vote() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::propose_supervisor(Origin::signed(7), 1, Some(7)),
+            Error::<TestRuntime>::NotAuthorizedToProposeSupervisor
+        );
+        assert_noop!(
+            Org::poll_supervisor_proposal(Origin::signed(2), 1),
+            Error::<TestRuntime>::NoSupervisorProposalToPoll
+        );
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, Some(2)));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorProposed(2, 1, Some(2), 1)
+        );
+        assert_noop!(
+            Org::propose_supervisor(Origin::signed(3), 1, None),
+            Error::<TestRuntime>::SupervisorProposalAlreadyOpen
+        );
+        // nothing changes while the vote is open
+        assert_ok!(Org::poll_supervisor_proposal(Origin::signed(3), 1));
+        assert!(Org::is_organization_supervisor(1, &1));
+        set_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_supervisor_proposal(Origin::signed(3), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorReplaced(1, Some(1), Some(2))
+        );
+        assert!(Org::is_organization_supervisor(1, &2));
+        assert!(!Org::is_organization_supervisor(1, &1));
+        assert!(Org::supervisor_proposals(1).is_none());
+        // rejected proposals leave the supervisor in place
+        set_vote_outcome(VoteOutcome::Rejected);
+        assert_ok!(Org::propose_supervisor(Origin::signed(3), 1, None));
+        assert_ok!(Org::poll_supervisor_proposal(Origin::signed(3), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorProposalRejected(1, 1)
+        );
+        assert!(Org::is_organization_supervisor(1, &2));
+        // the supervisor may be removed entirely
+        set_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::propose_supervisor(Origin::signed(3), 1, None));
+        assert_ok!(Org::poll_supervisor_proposal(Origin::signed(4), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorReplaced(1, Some(2), None)
+        );
+        assert!(Org::organization_states(1).unwrap().sudo().is_none());
+    });
+}
+
+#[test]
+fn membership_requests_and_invitations() {
+    new_test_ext().execute_with(|| {
+        let pre_issuance = Org::total_issuance(1);
+        assert_noop!(
+            Org::request_membership(Origin::signed(2), 1, 10),
+            Error::<TestRuntime>::AlreadyAMemberOfOrg
+        );
+        assert_ok!(Org::request_membership(Origin::signed(7), 1, 10));
+        assert_eq!(get_last_event(), RawEvent::MembershipRequested(7, 1, 10));
+        assert_noop!(
+            Org::request_membership(Origin::signed(7), 1, 11),
+            Error::<TestRuntime>::MembershipRequestAlreadyPending
+        );
+        assert_noop!(
+            Org::approve_membership_request(Origin::signed(2), 1, 7),
+            Error::<TestRuntime>::NotAuthorizedToApproveMembershipRequest
+        );
+        // the supervisor admits the applicant with a single share
+        assert_ok!(Org::approve_membership_request(Origin::signed(1), 1, 7));
+        assert_eq!(get_last_event(), RawEvent::MembershipRequestApproved(1, 7));
+        assert!(Org::is_member_of_group(1, &7));
+        assert_eq!(Org::total_issuance(1), pre_issuance + 1);
+        assert!(Org::membership_requests(1, 7).is_none());
+        // the members vote on the next applicant
+        assert_ok!(Org::request_membership(Origin::signed(8), 1, 12));
+        assert_noop!(
+            Org::poll_membership_request(Origin::signed(2), 1, 8),
+            Error::<TestRuntime>::NoMembershipVoteToPoll
+        );
+        assert_ok!(Org::trigger_membership_vote(Origin::signed(2), 1, 8));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipVoteTriggered(2, 1, 8, 1)
+        );
+        assert_noop!(
+            Org::trigger_membership_vote(Origin::signed(3), 1, 8),
+            Error::<TestRuntime>::MembershipVoteAlreadyTriggered
+        );
+        // nothing changes while the vote is open
+        assert_ok!(Org::poll_membership_request(Origin::signed(3), 1, 8));
+        assert!(!Org::is_member_of_group(1, &8));
+        set_vote_outcome(VoteOutcome::Rejected);
+        assert_ok!(Org::poll_membership_request(Origin::signed(3), 1, 8));
+        assert_eq!(get_last_event(), RawEvent::MembershipRequestRejected(1, 8));
+        assert!(!Org::is_member_of_group(1, &8));
+        assert!(Org::membership_requests(1, 8).is_none());
+        // invitations issue shares only once accepted
+        assert_noop!(
+            Org::invite_member(Origin::signed(2), 1, 9, 5),
+            Error::<TestRuntime>::NotAuthorizedToInviteMember
+        );
+        assert_noop!(
+            Org::invite_member(Origin::signed(1), 1, 9, 0),
+            Error::<TestRuntime>::CannotInviteForZeroShares
+        );
+        assert_ok!(Org::invite_member(Origin::signed(1), 1, 9, 5));
+        assert_eq!(get_last_event(), RawEvent::MemberInvited(1, 9, 5));
+        assert!(!Org::is_member_of_group(1, &9));
+        assert_noop!(
+            Org::accept_invitation(Origin::signed(8), 1),
+            Error::<TestRuntime>::InvitationDNE
+        );
+        assert_ok!(Org::accept_invitation(Origin::signed(9), 1));
+        assert_eq!(get_last_event(), RawEvent::InvitationAccepted(1, 9, 5));
+        assert_eq!(Org::members(1, 9).unwrap().total(), 5);
+        assert_eq!(Org::total_issuance(1), pre_issuance + 6);
+        assert!(Org::invitations(1, 9).is_none());
+    });
+}
+
+#[test]
+fn share_vesting() {
+    new_test_ext().execute_with(|| {
+        let pre_issuance = Org::total_issuance(1);
+        assert_noop!(
+            Org::issue_vesting_shares(Origin::signed(2), 1, 2, 10, 1, 5),
+            Error::<TestRuntime>::NotAuthorizedToIssueVestingShares
+        );
+        assert_noop!(
+            Org::issue_vesting_shares(Origin::signed(1), 1, 2, 10, 0, 5),
+            Error::<TestRuntime>::VestingPerBlockMustBeNonZero
+        );
+        assert_ok!(Org::issue_vesting_shares(
+            Origin::signed(1),
+            1,
+            2,
+            10,
+            1,
+            5
+        ));
+        assert_eq!(get_last_event(), RawEvent::VestingSharesIssued(1, 2, 10));
+        assert_noop!(
+            Org::issue_vesting_shares(Origin::signed(1), 1, 2, 10, 1, 5),
+            Error::<TestRuntime>::MemberAlreadyHasVestingSchedule
+        );
+        assert_eq!(Org::members(1, 2).unwrap().total(), 11);
+        assert_eq!(Org::vested_shares(1, &2), 1);
+        assert_eq!(Org::vested_issuance(1), pre_issuance);
+        let shape = Org::vested_membership_with_shape(1).unwrap();
+        assert!(shape.account_ownership().contains(&(2, 1)));
+        assert_eq!(shape.total(), pre_issuance);
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(2), 1, 3, 2),
+            Error::<TestRuntime>::CannotTransferUnvestedShares
+        );
+        // nothing vests before the cliff
+        System::set_block_number(5);
+        assert_ok!(Org::release_vested_shares(Origin::signed(2), 1));
+        assert_eq!(get_last_event(), RawEvent::VestedSharesReleased(1, 2, 0));
+        assert_eq!(Org::vested_shares(1, &2), 1);
+        // shares accrued since issuance vest at once after the cliff
+        System::set_block_number(8);
+        assert_eq!(Org::vested_shares(1, &2), 8);
+        assert_eq!(Org::vested_issuance(1), pre_issuance + 7);
+        assert_ok!(Org::release_vested_shares(Origin::signed(2), 1));
+        assert_eq!(get_last_event(), RawEvent::VestedSharesReleased(1, 2, 7));
+        assert_eq!(Org::vested_shares(1, &2), 8);
+        assert_eq!(Org::vesting_schedules(1, 2).unwrap().unvested(), 3);
+        // vested shares are counted without a release
+        System::set_block_number(20);
+        assert_eq!(Org::vested_shares(1, &2), 11);
+        assert_eq!(Org::vested_issuance(1), pre_issuance + 10);
+        assert_ok!(Org::transfer_shares(Origin::signed(2), 1, 3, 10));
+        assert_ok!(Org::release_vested_shares(Origin::signed(2), 1));
+        assert!(Org::vesting_schedules(1, 2).is_none());
+        assert_noop!(
+            Org::release_vested_shares(Origin::signed(2), 1),
+            Error::<TestRuntime>::NoVestingScheduleToRelease
+        );
+        // burning shares takes them from the unvested shares first
+        assert_ok!(Org::issue_vesting_shares(Origin::signed(1), 1, 4, 5, 1, 5));
+        assert_ok!(Org::burn_shares(Origin::signed(1), 1, 4, 4));
+        assert_eq!(Org::vesting_schedules(1, 4).unwrap().unvested(), 1);
+        assert_eq!(Org::vested_shares(1, &4), 1);
+    });
+}
+
+#[test]
+fn constitution_amended_by_vote() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::propose_constitution_amendment(Origin::signed(7), 1, 1739),
+            Error::<TestRuntime>::NotAuthorizedToProposeConstitutionAmendment
+        );
+        assert_noop!(
+            Org::poll_constitution_amendment(Origin::signed(2), 1),
+            Error::<TestRuntime>::NoConstitutionAmendmentToPoll
+        );
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(2),
+            1,
+            1739
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConstitutionAmendmentProposed(2, 1, 1739, 1)
+        );
+        assert_noop!(
+            Org::propose_constitution_amendment(Origin::signed(3), 1, 1740),
+            Error::<TestRuntime>::ConstitutionAmendmentAlreadyOpen
+        );
+        // nothing changes while the vote is open
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(3), 1));
+        assert_eq!(Org::organization_states(1).unwrap().constitution(), 1738);
+        set_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(3), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConstitutionAmended(1, 1738, 1739)
+        );
+        assert_eq!(Org::organization_states(1).unwrap().constitution(), 1739);
+        assert!(Org::constitution_amendments(1).is_none());
+        // rejected amendments leave the constitution in place
+        set_vote_outcome(VoteOutcome::Rejected);
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(3),
+            1,
+            1740
+        ));
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(4), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConstitutionAmendmentRejected(1, 1)
+        );
+        assert_eq!(Org::organization_states(1).unwrap().constitution(), 1739);
+        set_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(3),
+            1,
+            1741
+        ));
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(4), 1));
+        assert_eq!(Org::constitution_history(1), vec![1738, 1739]);
+    });
+}
+
+#[test]
+fn member_count_tracks_members() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Org::member_count(1), 6);
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 10));
+        assert_eq!(Org::member_count(1), 7);
+        assert_ok!(Org::issue_shares(Origin::signed(1), 1, 7, 10));
+        assert_eq!(Org::member_count(1), 7);
+        assert_ok!(Org::transfer_shares(Origin::signed(7), 1, 8, 20));
+        assert_eq!(Org::member_count(1), 7);
+        assert_ok!(Org::burn_shares(Origin::signed(1), 1, 8, 20));
+        assert_eq!(Org::member_count(1), 6);
+        // orgs registered before the count was kept are counted on upgrade
+        <MemberCount<TestRuntime>>::remove(1);
+        <StorageVersion>::put(Releases::V0);
+        CountMembers::<TestRuntime>::on_runtime_upgrade();
+        assert_eq!(Org::member_count(1), 6);
+        assert_eq!(Org::storage_version(), Releases::V1);
+    });
+}
@@ -0,0 +1,170 @@
+#![allow(clippy::string_lit_as_bytes)]
+#![allow(clippy::redundant_closure_call)]
+#![allow(clippy::type_complexity)]
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Reputation ledger of bounty hunters and depositers
+//! - counts the outcomes reported by the bounty module for every account
+//! - scores hunters for approvals and rejections and depositers for bounties paid out,
+//!   scaled by the share of their bounties that paid out
+//! - records the amounts earned by hunters and paid out by depositers in the native asset
+//! - keeps a bounded leaderboard of the highest scores
+
+#[cfg(test)]
+mod tests;
+
+use frame_support::{
+    decl_event,
+    decl_module,
+    decl_storage,
+    traits::Get,
+    Parameter,
+};
+use sp_runtime::traits::{
+    AtLeast32Bit,
+    Member,
+};
+use sp_std::prelude::*;
+pub use util::reputation::ReputationRecord;
+use util::traits::{
+    GetReputation,
+    RecordBountyOutcome,
+};
+
+pub trait Trait: frame_system::Trait {
+    /// The overarching event type
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The native balance in which bounty payouts are recorded
+    type Balance: Parameter + Member + AtLeast32Bit + Default + Copy;
+
+    /// Score added for every submission paid in full
+    type ApprovalScore: Get<u32>;
+
+    /// Score removed for every rejected submission
+    type RejectionPenalty: Get<u32>;
+
+    /// Score added for every posted bounty that paid out, scaled by the share
+    /// of posted bounties that paid out
+    type PayoutScore: Get<u32>;
+
+    /// Maximum number of accounts on the leaderboard
+    type LeaderboardSize: Get<u32>;
+}
+
+decl_event!(
+    pub enum Event<T>
+    where
+        <T as frame_system::Trait>::AccountId,
+    {
+        /// Account, New Score
+        ScoreUpdated(AccountId, u32),
+    }
+);
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Reputation {
+        /// Outcome counters and score of every account with a bounty outcome
+        pub Reputations get(fn reputations): map
+            hasher(blake2_128_concat) T::AccountId => ReputationRecord<T::Balance>;
+        /// Accounts with the highest nonzero scores in descending order, an
+        /// account which falls off only returns once its score changes again
+        pub Leaderboard get(fn leaderboard): Vec<(T::AccountId, u32)>;
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn deposit_event() = default;
+
+        const ApprovalScore: u32 = T::ApprovalScore::get();
+        const RejectionPenalty: u32 = T::RejectionPenalty::get();
+        const PayoutScore: u32 = T::PayoutScore::get();
+        const LeaderboardSize: u32 = T::LeaderboardSize::get();
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Accounts on the leaderboard with the highest scores, at most `limit`
+    pub fn top_accounts(
+        limit: u32,
+    ) -> Vec<(T::AccountId, ReputationRecord<T::Balance>)> {
+        <Leaderboard<T>>::get()
+            .into_iter()
+            .take(limit as usize)
+            .map(|(who, _)| {
+                let record = <Reputations<T>>::get(&who);
+                (who, record)
+            })
+            .collect()
+    }
+    fn score_of(record: &ReputationRecord<T::Balance>) -> u32 {
+        let payouts = record
+            .paid_ratio()
+            .mul_floor(record.paid().saturating_mul(T::PayoutScore::get()));
+        record
+            .approved()
+            .saturating_mul(T::ApprovalScore::get())
+            .saturating_add(payouts)
+            .saturating_sub(
+                record.rejected().saturating_mul(T::RejectionPenalty::get()),
+            )
+    }
+    /// Applies `update` to the record of `who` and rescores it
+    fn update(
+        who: &T::AccountId,
+        update: impl Fn(
+            &ReputationRecord<T::Balance>,
+        ) -> ReputationRecord<T::Balance>,
+    ) {
+        let old = <Reputations<T>>::get(who);
+        let new = update(&old);
+        let score = Self::score_of(&new);
+        <Reputations<T>>::insert(who, new.set_score(score));
+        if score != old.score() {
+            Self::rank(who, score);
+            Self::deposit_event(RawEvent::ScoreUpdated(who.clone(), score));
+        }
+    }
+    /// Moves `who` to its place on the leaderboard for `score`, behind the
+    /// accounts with the same score, and drops the lowest beyond the size
+    fn rank(who: &T::AccountId, score: u32) {
+        <Leaderboard<T>>::mutate(|board| {
+            board.retain(|(account, _)| account != who);
+            if score > 0 {
+                let place = board
+                    .iter()
+                    .position(|(_, s)| *s < score)
+                    .unwrap_or(board.len());
+                board.insert(place, (who.clone(), score));
+            }
+            board.truncate(T::LeaderboardSize::get() as usize);
+        });
+    }
+}
+
+impl<T: Trait> RecordBountyOutcome<T::AccountId, T::Balance> for Module<T> {
+    fn bounty_posted(depositer: &T::AccountId) {
+        Self::update(depositer, |r| r.post_bounty());
+    }
+    fn payment_executed(
+        depositer: &T::AccountId,
+        submitter: &T::AccountId,
+        amount: T::Balance,
+        first_payout: bool,
+    ) {
+        Self::update(depositer, |r| r.pay_out(amount, first_payout));
+        Self::update(submitter, |r| r.earn(amount));
+    }
+    fn submission_approved(submitter: &T::AccountId) {
+        Self::update(submitter, |r| r.approve());
+    }
+    fn submission_rejected(submitter: &T::AccountId) {
+        Self::update(submitter, |r| r.reject());
+    }
+}
+
+impl<T: Trait> GetReputation<T::AccountId> for Module<T> {
+    fn score(who: &T::AccountId) -> u32 {
+        <Reputations<T>>::get(who).score()
+    }
+}
@@ -0,0 +1,196 @@
+use super::*;
+use frame_support::{
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    weights::Weight,
+};
+use frame_system::{self as system,};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+    Permill,
+};
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod reputation {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        reputation<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ApprovalScore: u32 = 10;
+    pub const RejectionPenalty: u32 = 5;
+    pub const PayoutScore: u32 = 3;
+    pub const LeaderboardSize: u32 = 2;
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type Balance = u64;
+    type ApprovalScore = ApprovalScore;
+    type RejectionPenalty = RejectionPenalty;
+    type PayoutScore = PayoutScore;
+    type LeaderboardSize = LeaderboardSize;
+}
+pub type System = system::Module<Test>;
+pub type Reputation = Module<Test>;
+
+fn get_last_event() -> RawEvent<u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::reputation(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn genesis_config_works() {
+    new_test_ext().execute_with(|| {
+        assert!(System::events().is_empty());
+        assert_eq!(Reputation::score(&1), 0);
+    });
+}
+
+#[test]
+fn hunter_reputation_works() {
+    new_test_ext().execute_with(|| {
+        Reputation::payment_executed(&1, &2, 15, true);
+        Reputation::submission_approved(&2);
+        assert_eq!(RawEvent::ScoreUpdated(2, 10), get_last_event());
+        Reputation::submission_rejected(&2);
+        assert_eq!(RawEvent::ScoreUpdated(2, 5), get_last_event());
+        let record = Reputation::reputations(2);
+        assert_eq!(record.approved(), 1);
+        assert_eq!(record.earned(), 15);
+        assert_eq!(record.rejected(), 1);
+        assert_eq!(Reputation::score(&2), 5);
+        // scores do not go below zero
+        Reputation::submission_rejected(&3);
+        assert_eq!(Reputation::score(&3), 0);
+    });
+}
+
+#[test]
+fn depositer_reputation_works() {
+    new_test_ext().execute_with(|| {
+        Reputation::bounty_posted(&1);
+        Reputation::bounty_posted(&1);
+        assert_eq!(Reputation::reputations(1).paid_ratio(), Permill::zero());
+        Reputation::payment_executed(&1, &2, 10, true);
+        Reputation::payment_executed(&1, &3, 5, false);
+        let record = Reputation::reputations(1);
+        assert_eq!(record.posted(), 2);
+        assert_eq!(record.paid(), 1);
+        assert_eq!(record.paid_out(), 15);
+        assert_eq!(record.paid_ratio(), Permill::from_percent(50));
+        // one of two bounties paid out
+        assert_eq!(Reputation::score(&1), 1);
+        Reputation::bounty_posted(&1);
+        Reputation::payment_executed(&1, &2, 0, true);
+        Reputation::bounty_posted(&1);
+        Reputation::payment_executed(&1, &3, 0, true);
+        // three of four bounties paid out
+        let record = Reputation::reputations(1);
+        assert_eq!((record.posted(), record.paid()), (4, 3));
+        assert_eq!(Reputation::score(&1), 6);
+    });
+}
+
+#[test]
+fn top_accounts_are_sorted_by_score() {
+    new_test_ext().execute_with(|| {
+        Reputation::submission_approved(&1);
+        Reputation::submission_approved(&2);
+        Reputation::submission_approved(&2);
+        Reputation::bounty_posted(&3);
+        let top = Reputation::top_accounts(2)
+            .into_iter()
+            .map(|(who, r)| (who, r.score()))
+            .collect::<Vec<_>>();
+        assert_eq!(top, vec![(2, 20), (1, 10)]);
+    });
+}
+
+#[test]
+fn leaderboard_is_bounded() {
+    new_test_ext().execute_with(|| {
+        Reputation::submission_approved(&1);
+        Reputation::submission_approved(&2);
+        // ties rank behind the accounts scored first
+        assert_eq!(Reputation::leaderboard(), vec![(1, 10), (2, 10)]);
+        Reputation::submission_approved(&3);
+        Reputation::submission_approved(&3);
+        assert_eq!(Reputation::leaderboard(), vec![(3, 20), (1, 10)]);
+        // accounts without a score leave the leaderboard
+        Reputation::submission_rejected(&1);
+        Reputation::submission_rejected(&1);
+        assert_eq!(Reputation::leaderboard(), vec![(3, 20)]);
+        assert_eq!(Reputation::top_accounts(5).len(), 1);
+    });
+}
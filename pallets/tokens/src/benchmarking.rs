@@ -0,0 +1,25 @@
+//! Tokens pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+    _ { }
+
+    transfer {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let dest: T::AccountId = account("dest", 0, SEED);
+        let currency_id = T::CurrencyId::default();
+        <Module<T> as MultiCurrency<T::AccountId>>::deposit(currency_id, &caller, 1_000u32.into())?;
+    }: _(RawOrigin::Signed(caller), dest.clone(), currency_id, 100u32.into())
+    verify {
+        assert_eq!(<Module<T> as MultiCurrency<T::AccountId>>::free_balance(currency_id, &dest), 100u32.into());
+    }
+}
@@ -0,0 +1,297 @@
+#![recursion_limit = "256"]
+#![allow(clippy::string_lit_as_bytes)]
+#![allow(clippy::redundant_closure_call)]
+#![allow(clippy::type_complexity)]
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Balances for many assets, the native currency is one of them
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use frame_support::{
+    decl_error,
+    decl_event,
+    decl_module,
+    decl_storage,
+    traits::{
+        Currency,
+        ExistenceRequirement,
+        Get,
+        WithdrawReasons,
+    },
+    weights::Weight,
+    Parameter,
+};
+use frame_system::{
+    self as system,
+    ensure_signed,
+};
+use sp_runtime::{
+    traits::{
+        CheckedAdd,
+        CheckedSub,
+        MaybeSerializeDeserialize,
+        Member,
+        Zero,
+    },
+    DispatchResult,
+};
+use sp_std::{
+    fmt::Debug,
+    prelude::*,
+};
+pub use util::traits::MultiCurrency;
+
+pub type BalanceOf<T> = <<T as Trait>::NativeCurrency as Currency<
+    <T as system::Trait>::AccountId,
+>>::Balance;
+
+pub trait Trait: system::Trait {
+    /// Overarching event type
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+    /// The identifier for assets
+    type CurrencyId: Parameter
+        + Member
+        + Copy
+        + Default
+        + Ord
+        + MaybeSerializeDeserialize
+        + Debug;
+
+    /// The native currency, every other asset shares its balance type
+    type NativeCurrency: Currency<Self::AccountId>;
+
+    /// The identifier under which the native currency is used
+    type NativeCurrencyId: Get<Self::CurrencyId>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn transfer() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn transfer() -> Weight {
+        1_000_000_000
+    }
+}
+
+decl_event!(
+    pub enum Event<T>
+    where
+        <T as Trait>::CurrencyId,
+        <T as frame_system::Trait>::AccountId,
+        Balance = BalanceOf<T>,
+    {
+        /// Asset, Source, Destination, Amount
+        Transferred(CurrencyId, AccountId, AccountId, Balance),
+    }
+);
+
+decl_error! {
+    pub enum Error for Module<T: Trait> {
+        BalanceTooLow,
+        BalanceOverflow,
+        TotalIssuanceOverflow,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as Tokens {
+        /// The total issuance of every non-native asset
+        pub TotalIssuance get(fn total_issuance): map
+            hasher(blake2_128_concat) T::CurrencyId => BalanceOf<T>;
+
+        /// The free balance of every account for every non-native asset
+        pub Accounts get(fn accounts): double_map
+            hasher(blake2_128_concat) T::CurrencyId,
+            hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
+    }
+    add_extra_genesis {
+        config(endowed_accounts): Vec<(T::AccountId, T::CurrencyId, BalanceOf<T>)>;
+
+        build(|config: &GenesisConfig<T>| {
+            config.endowed_accounts.iter().for_each(|(who, currency_id, amount)| {
+                <Module<T> as MultiCurrency<T::AccountId>>::deposit(*currency_id, who, *amount)
+                    .expect("endowed accounts config set up failed");
+            });
+        });
+    }
+}
+
+decl_module! {
+    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        type Error = Error<T>;
+        fn deposit_event() = default;
+
+        #[weight = <T as Trait>::WeightInfo::transfer()]
+        fn transfer(
+            origin,
+            dest: T::AccountId,
+            currency_id: T::CurrencyId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+            <Self as MultiCurrency<T::AccountId>>::transfer(currency_id, &source, &dest, amount)?;
+            Self::deposit_event(RawEvent::Transferred(currency_id, source, dest, amount));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    fn is_native(currency_id: T::CurrencyId) -> bool {
+        currency_id == T::NativeCurrencyId::get()
+    }
+    /// Zero balances are removed from storage
+    fn set_balance(
+        currency_id: T::CurrencyId,
+        who: &T::AccountId,
+        balance: BalanceOf<T>,
+    ) {
+        if balance.is_zero() {
+            <Accounts<T>>::remove(currency_id, who);
+        } else {
+            <Accounts<T>>::insert(currency_id, who, balance);
+        }
+    }
+}
+
+impl<T: Trait> MultiCurrency<T::AccountId> for Module<T> {
+    type CurrencyId = T::CurrencyId;
+    type Balance = BalanceOf<T>;
+
+    fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::minimum_balance()
+        } else {
+            Zero::zero()
+        }
+    }
+
+    fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::total_issuance()
+        } else {
+            <TotalIssuance<T>>::get(currency_id)
+        }
+    }
+
+    fn free_balance(
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+    ) -> Self::Balance {
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::free_balance(who)
+        } else {
+            <Accounts<T>>::get(currency_id, who)
+        }
+    }
+
+    fn ensure_can_withdraw(
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if amount.is_zero() {
+            return Ok(())
+        }
+        let new_balance = Self::free_balance(currency_id, who)
+            .checked_sub(&amount)
+            .ok_or(Error::<T>::BalanceTooLow)?;
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::ensure_can_withdraw(
+                who,
+                amount,
+                WithdrawReasons::all(),
+                new_balance,
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    fn transfer(
+        currency_id: Self::CurrencyId,
+        from: &T::AccountId,
+        to: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if amount.is_zero() || from == to {
+            return Ok(())
+        }
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::transfer(
+                from,
+                to,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )
+        } else {
+            let from_balance = <Accounts<T>>::get(currency_id, from)
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::BalanceTooLow)?;
+            let to_balance = <Accounts<T>>::get(currency_id, to)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::BalanceOverflow)?;
+            Self::set_balance(currency_id, from, from_balance);
+            Self::set_balance(currency_id, to, to_balance);
+            Ok(())
+        }
+    }
+
+    fn deposit(
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if amount.is_zero() {
+            return Ok(())
+        }
+        if Self::is_native(currency_id) {
+            T::NativeCurrency::deposit_creating(who, amount);
+        } else {
+            let issuance = <TotalIssuance<T>>::get(currency_id)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::TotalIssuanceOverflow)?;
+            let balance = <Accounts<T>>::get(currency_id, who)
+                .checked_add(&amount)
+                .ok_or(Error::<T>::BalanceOverflow)?;
+            <TotalIssuance<T>>::insert(currency_id, issuance);
+            Self::set_balance(currency_id, who, balance);
+        }
+        Ok(())
+    }
+
+    fn withdraw(
+        currency_id: Self::CurrencyId,
+        who: &T::AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult {
+        if amount.is_zero() {
+            return Ok(())
+        }
+        if Self::is_native(currency_id) {
+            // dropping the imbalance burns it
+            T::NativeCurrency::withdraw(
+                who,
+                amount,
+                WithdrawReasons::all(),
+                ExistenceRequirement::AllowDeath,
+            )?;
+        } else {
+            let balance = <Accounts<T>>::get(currency_id, who)
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::BalanceTooLow)?;
+            <TotalIssuance<T>>::mutate(currency_id, |t| *t -= amount);
+            Self::set_balance(currency_id, who, balance);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use super::*;
+use frame_support::{
+    assert_noop,
+    assert_ok,
+    impl_outer_event,
+    impl_outer_origin,
+    parameter_types,
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::Header,
+    traits::IdentityLookup,
+    Perbill,
+};
+
+// type aliases
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+impl_outer_origin! {
+    pub enum Origin for Test where system = frame_system {}
+}
+
+mod tokens {
+    pub use super::super::*;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        system<T>,
+        pallet_balances<T>,
+        tokens<T>,
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const MaximumBlockWeight: Weight = 1024;
+    pub const MaximumBlockLength: u32 = 2 * 1024;
+    pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+    type Origin = Origin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Call = ();
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = TestEvent;
+    type BlockHashCount = BlockHashCount;
+    type MaximumBlockWeight = MaximumBlockWeight;
+    type MaximumExtrinsicWeight = MaximumBlockWeight;
+    type DbWeight = ();
+    type BlockExecutionWeight = ();
+    type ExtrinsicBaseWeight = ();
+    type AvailableBlockRatio = AvailableBlockRatio;
+    type MaximumBlockLength = MaximumBlockLength;
+    type Version = ();
+    type ModuleToIndex = ();
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type BaseCallFilter = ();
+    type SystemWeightInfo = ();
+}
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+parameter_types! {
+    pub const NativeCurrencyId: u32 = 0;
+}
+impl Trait for Test {
+    type Event = TestEvent;
+    type CurrencyId = u32;
+    type NativeCurrency = Balances;
+    type NativeCurrencyId = NativeCurrencyId;
+    type WeightInfo = ();
+}
+pub type System = system::Module<Test>;
+pub type Balances = pallet_balances::Module<Test>;
+pub type Tokens = Module<Test>;
+
+fn get_last_event() -> RawEvent<u32, u64, u64> {
+    System::events()
+        .into_iter()
+        .map(|r| r.event)
+        .filter_map(|e| {
+            if let TestEvent::tokens(inner) = e {
+                Some(inner)
+            } else {
+                None
+            }
+        })
+        .last()
+        .unwrap()
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 100), (3, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        endowed_accounts: vec![(1, 1, 100), (2, 1, 50), (1, 2, 10)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+#[test]
+fn genesis_config_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Tokens::free_balance(1, &1), 100);
+        assert_eq!(Tokens::free_balance(1, &2), 50);
+        assert_eq!(Tokens::free_balance(2, &1), 10);
+        assert_eq!(Tokens::total_issuance(1), 150);
+        assert_eq!(Tokens::free_balance(0, &1), 100);
+    });
+}
+
+#[test]
+fn transfer_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Tokens::transfer(Origin::signed(2), 3, 1, 51),
+            Error::<Test>::BalanceTooLow
+        );
+        assert_ok!(Tokens::transfer(Origin::signed(2), 3, 1, 50));
+        assert_eq!(get_last_event(), RawEvent::Transferred(1, 2, 3, 50));
+        assert_eq!(Tokens::free_balance(1, &2), 0);
+        assert!(!<Accounts<Test>>::contains_key(1, 2));
+        assert_eq!(Tokens::free_balance(1, &3), 50);
+        assert_eq!(Tokens::total_issuance(1), 150);
+        // the native currency is transferred through balances
+        assert_ok!(Tokens::transfer(Origin::signed(1), 3, 0, 40));
+        assert_eq!(Balances::free_balance(&1), 60);
+        assert_eq!(Balances::free_balance(&3), 140);
+        assert!(!<Accounts<Test>>::contains_key(0, 3));
+    });
+}
+
+#[test]
+fn deposit_and_withdraw_update_issuance() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<Tokens as MultiCurrency<u64>>::deposit(2, &3, 15));
+        assert_eq!(Tokens::total_issuance(2), 25);
+        assert_noop!(
+            <Tokens as MultiCurrency<u64>>::withdraw(2, &3, 16),
+            Error::<Test>::BalanceTooLow
+        );
+        assert_ok!(<Tokens as MultiCurrency<u64>>::withdraw(2, &3, 15));
+        assert_eq!(Tokens::total_issuance(2), 10);
+        assert_eq!(Tokens::free_balance(2, &3), 0);
+        assert_ok!(<Tokens as MultiCurrency<u64>>::withdraw(0, &3, 50));
+        assert_eq!(Balances::total_issuance(), 250);
+    });
+}
@@ -0,0 +1,52 @@
+//! Vote direct pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const MAX_VOTERS: u32 = 100;
+
+/// `n` voters with one unit of signal each
+fn voters<T: Trait>(n: u32) -> SimpleShareGenesis<T::AccountId, T::Signal> {
+    (0..n)
+        .map(|i| (account("voter", i, SEED), 1u32.into()))
+        .collect::<Vec<(T::AccountId, T::Signal)>>()
+        .into()
+}
+
+benchmarks! {
+    _ { }
+
+    create_signal_vote {
+        let m in 1 .. MAX_VOTERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let threshold = Threshold::new(1u32.into(), None);
+    }: _(RawOrigin::Signed(caller), Some(T::IpfsReference::default()), voters::<T>(m), threshold, None)
+    verify {
+        assert_eq!(<OpenVoteCounter>::get(), 1);
+    }
+
+    create_percent_vote {
+        let m in 1 .. MAX_VOTERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let threshold = Threshold::new(Permill::from_percent(51), None);
+    }: _(RawOrigin::Signed(caller), Some(T::IpfsReference::default()), voters::<T>(m), threshold, None)
+    verify {
+        assert_eq!(<OpenVoteCounter>::get(), 1);
+    }
+
+    submit_vote {
+        let vote_id = <Module<T>>::open_vote(
+            None,
+            voters::<T>(MAX_VOTERS),
+            Threshold::new(MAX_VOTERS.into(), None),
+            None,
+        )?;
+        let voter: T::AccountId = account("voter", 0, SEED);
+    }: _(RawOrigin::Signed(voter), vote_id, VoterView::InFavor, Some(T::IpfsReference::default()))
+}
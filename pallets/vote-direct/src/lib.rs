@@ -9,6 +9,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -16,6 +19,7 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -75,7 +79,7 @@ pub trait Trait: frame_system::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
     /// Cid type
-    type IpfsReference: Parameter + Copy;
+    type IpfsReference: Parameter + Copy + Default;
 
     /// The vote identifier
     type VoteId: Parameter
@@ -102,6 +106,27 @@ pub trait Trait: frame_system::Trait {
         + PartialOrd
         + CheckedSub
         + Zero;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn create_signal_vote(m: u32) -> Weight;
+    fn create_percent_vote(m: u32) -> Weight;
+    fn submit_vote() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn create_signal_vote(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn create_percent_vote(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn submit_vote() -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -130,7 +155,7 @@ decl_error! {
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Vote {
+    trait Store for Module<T: Trait> as VoteDirect {
         /// The nonce for unique vote id generation
         VoteIdCounter get(fn vote_id_counter): T::VoteId;
 
@@ -157,7 +182,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_signal_vote(src.account_ownership().len() as u32)]
         pub fn create_signal_vote(
             origin,
             topic: Option<T::IpfsReference>,
@@ -177,7 +202,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_percent_vote(src.account_ownership().len() as u32)]
         pub fn create_percent_vote(
             origin,
             topic: Option<T::IpfsReference>,
@@ -197,7 +222,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_vote()]
         pub fn submit_vote(
             origin,
             vote_id: T::VoteId,
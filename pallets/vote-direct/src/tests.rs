@@ -61,6 +61,7 @@ impl Trait for Test {
     type IpfsReference = u32;
     type VoteId = u64;
     type Signal = u64;
+    type WeightInfo = ();
 }
 
 mod vote {
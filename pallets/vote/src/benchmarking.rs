@@ -0,0 +1,67 @@
+//! Vote pallet benchmarking
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+use org::MAX_MEMBERS;
+use sp_std::prelude::*;
+use util::{
+    organization::OrganizationSource,
+    traits::RegisterOrganization,
+};
+
+const SEED: u32 = 0;
+
+/// Registers a flat org supervised by `supervisor` with `n` members
+fn flat_org<T: Trait>(
+    supervisor: &T::AccountId,
+    n: u32,
+) -> Result<T::OrgId, DispatchError> {
+    let members = (0..n)
+        .map(|i| account("member", i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    <org::Module<T>>::register_organization(
+        OrganizationSource::Accounts(members),
+        Some(supervisor.clone()),
+        T::IpfsReference::default(),
+    )
+}
+
+benchmarks! {
+    _ { }
+
+    create_signal_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, m)?;
+        let threshold = Threshold::new(1u32.into(), None);
+    }: _(RawOrigin::Signed(caller), Some(T::IpfsReference::default()), OrgRep::Equal(org), threshold, None)
+    verify {
+        assert_eq!(<OpenVoteCounter>::get(), 1);
+    }
+
+    create_percent_vote {
+        let m in 1 .. MAX_MEMBERS;
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, m)?;
+        let threshold = Threshold::new(Permill::from_percent(51), None);
+    }: _(RawOrigin::Signed(caller), Some(T::IpfsReference::default()), OrgRep::Equal(org), threshold, None)
+    verify {
+        assert_eq!(<OpenVoteCounter>::get(), 1);
+    }
+
+    submit_vote {
+        let caller: T::AccountId = account("caller", 0, SEED);
+        let org = flat_org::<T>(&caller, MAX_MEMBERS)?;
+        let vote_id = <Module<T>>::open_vote(
+            None,
+            OrgRep::Equal(org),
+            Threshold::new(MAX_MEMBERS.into(), None),
+            None,
+        )?;
+        let voter: T::AccountId = account("member", 0, SEED);
+    }: _(RawOrigin::Signed(voter), vote_id, VoterView::InFavor, Some(T::IpfsReference::default()))
+}
@@ -10,6 +10,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use codec::Codec;
 use frame_support::{
     decl_error,
@@ -17,6 +20,7 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -45,6 +49,7 @@ use util::{
         AccessGenesis,
         Apply,
         ApplyVote,
+        CheckVoteExpiry,
         CheckVoteStatus,
         GenerateUniqueID,
         GetGroup,
@@ -97,6 +102,27 @@ pub trait Trait: frame_system::Trait + org::Trait {
         + CheckedSub
         + Zero
         + From<Self::Shares>;
+
+    /// Weight information for extrinsics in this pallet
+    type WeightInfo: WeightInfo;
+}
+
+pub trait WeightInfo {
+    fn create_signal_vote(m: u32) -> Weight;
+    fn create_percent_vote(m: u32) -> Weight;
+    fn submit_vote() -> Weight;
+}
+
+impl WeightInfo for () {
+    fn create_signal_vote(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn create_percent_vote(_m: u32) -> Weight {
+        1_000_000_000
+    }
+    fn submit_vote() -> Weight {
+        1_000_000_000
+    }
 }
 
 decl_event!(
@@ -155,7 +181,7 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_signal_vote(<org::Module<T>>::member_count(organization.org()))]
         pub fn create_signal_vote(
             origin,
             topic: Option<T::IpfsReference>,
@@ -178,7 +204,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::create_percent_vote(<org::Module<T>>::member_count(organization.org()))]
         pub fn create_percent_vote(
             origin,
             topic: Option<T::IpfsReference>,
@@ -201,7 +227,7 @@ decl_module! {
             Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = <T as Trait>::WeightInfo::submit_vote()]
         pub fn submit_vote(
             origin,
             vote_id: T::VoteId,
@@ -272,6 +298,16 @@ impl<T: Trait> GetVoteOutcome<T::VoteId> for Module<T> {
     }
 }
 
+impl<T: Trait> CheckVoteExpiry<T::VoteId> for Module<T> {
+    fn vote_expired(vote_id: T::VoteId) -> bool {
+        if let Some(state) = <VoteStates<T>>::get(vote_id) {
+            Self::check_vote_expired(&state)
+        } else {
+            true
+        }
+    }
+}
+
 impl<T: Trait>
     OpenVote<
         OrgRep<T::OrgId>,
@@ -410,13 +446,13 @@ impl<T: Trait> MintableSignal<T::AccountId, T::OrgId, T::VoteId, T::Signal>
         <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
         Ok(total_minted)
     }
-    /// Mints signal based on weighted membership of the group
+    /// Mints signal based on weighted membership of the group, unvested shares do not mint signal
     fn batch_mint_signal(
         vote_id: T::VoteId,
         organization: T::OrgId,
     ) -> Result<T::Signal, DispatchError> {
         let new_vote_group =
-            <org::Module<T>>::get_membership_with_shape(organization)
+            <org::Module<T>>::vested_membership_with_shape(organization)
                 .ok_or(Error::<T>::CannotMintSignalBecauseMembershipShapeDNE)?;
         // total issuance
         let total_minted: T::Signal = new_vote_group.total().into();
@@ -10,9 +10,14 @@ use frame_support::{
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
-    traits::IdentityLookup,
+    traits::{
+        ConvertInto,
+        IdentityLookup,
+    },
     Perbill,
+    Permill,
 };
+use util::traits::GroupMembership;
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -56,16 +61,31 @@ impl frame_system::Trait for Test {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+parameter_types! {
+    pub const SupervisorVoteThreshold: Permill = Permill::from_percent(51);
+    pub const MembershipVoteThreshold: Permill = Permill::from_percent(51);
+    pub const ConstitutionVoteThreshold: Permill = Permill::from_percent(67);
+    pub const OrgVoteDuration: u64 = 10;
+}
 impl org::Trait for Test {
     type Event = TestEvent;
     type IpfsReference = u32;
     type OrgId = u64;
     type Shares = u64;
+    type OrgVoteId = u64;
+    type OrgVote = Module<Test>;
+    type SupervisorVoteThreshold = SupervisorVoteThreshold;
+    type MembershipVoteThreshold = MembershipVoteThreshold;
+    type ConstitutionVoteThreshold = ConstitutionVoteThreshold;
+    type OrgVoteDuration = OrgVoteDuration;
+    type BlockNumberToShares = ConvertInto;
+    type WeightInfo = ();
 }
 impl Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type WeightInfo = ();
 }
 
 mod vote {
@@ -80,7 +100,7 @@ impl_outer_event! {
     }
 }
 pub type System = system::Module<Test>;
-// pub type Organization = org::Module<Test>;
+pub type Org = org::Module<Test>;
 pub type VoteThreshold = Module<Test>;
 
 fn get_last_event() -> RawEvent<u64, u64> {
@@ -290,3 +310,79 @@ fn changing_votes_upholds_invariants() {
         assert_eq!(outcome_almost_passed, VoteOutcome::Approved);
     });
 }
+
+#[test]
+fn expired_supervisor_proposal_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::propose_supervisor(Origin::signed(2), 1, Some(2)));
+        let (vote_id, _) = Org::supervisor_proposals(1).unwrap();
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(3),
+            vote_id,
+            VoterView::Against,
+            None
+        ));
+        // the vote is not approved and stays open until it expires
+        assert_ok!(Org::poll_supervisor_proposal(Origin::signed(3), 1));
+        assert!(Org::supervisor_proposals(1).is_some());
+        System::set_block_number(12);
+        assert_ok!(Org::poll_supervisor_proposal(Origin::signed(3), 1));
+        assert!(Org::supervisor_proposals(1).is_none());
+        assert!(Org::is_organization_supervisor(1, &1));
+        // another proposal may be opened once the failed one is rejected
+        assert_ok!(Org::propose_supervisor(Origin::signed(3), 1, None));
+    });
+}
+
+#[test]
+fn expired_membership_vote_drops_request() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::request_membership(Origin::signed(7), 1, 10));
+        assert_ok!(Org::trigger_membership_vote(Origin::signed(2), 1, 7));
+        let (_, vote_id) = Org::membership_requests(1, 7).unwrap();
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(3),
+            vote_id.unwrap(),
+            VoterView::Against,
+            None
+        ));
+        assert_ok!(Org::poll_membership_request(Origin::signed(3), 1, 7));
+        assert!(Org::membership_requests(1, 7).is_some());
+        System::set_block_number(12);
+        assert_ok!(Org::poll_membership_request(Origin::signed(3), 1, 7));
+        assert!(Org::membership_requests(1, 7).is_none());
+        assert!(!Org::is_member_of_group(1, &7));
+        // the applicant may apply again once the failed request is dropped
+        assert_ok!(Org::request_membership(Origin::signed(7), 1, 11));
+    });
+}
+
+#[test]
+fn expired_constitution_amendment_is_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(2),
+            1,
+            1739
+        ));
+        let (vote_id, _) = Org::constitution_amendments(1).unwrap();
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(3),
+            vote_id,
+            VoterView::Against,
+            None
+        ));
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(3), 1));
+        assert!(Org::constitution_amendments(1).is_some());
+        System::set_block_number(12);
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(3), 1));
+        assert!(Org::constitution_amendments(1).is_none());
+        assert!(Org::constitution_history(1).is_empty());
+        // another amendment may be proposed once the failed one is rejected
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(3),
+            1,
+            1740
+        ));
+    });
+}
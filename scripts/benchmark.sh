@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+# Script to regenerate the runtime weights from the pallet benchmarks
+
+set -e
+
+# 1. build the node with the benchmarks
+cd "$(dirname "$0")/.."
+cargo build --release --features runtime-benchmarks -p test-node
+# 2. write the weights of each pallet to bin/runtime/src/weights/<pallet>.rs
+cd bin/runtime/src/weights
+for pallet in tokens org vote court drip donate bank bounty grant vote_direct bounty2; do
+    ../../../../target/release/test-node benchmark \
+        --chain dev \
+        --execution wasm \
+        --wasm-execution compiled \
+        --pallet "$pallet" \
+        --extrinsic '*' \
+        --steps 50 \
+        --repeat 20 \
+        --output
+done
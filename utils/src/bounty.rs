@@ -80,23 +80,47 @@ impl<
 }
 
 #[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
-pub struct BountyInformation<IpfsReference, AccountId, Currency> {
+pub struct BountyInformation<
+    IpfsReference,
+    AccountId,
+    Governance,
+    AssetId,
+    Currency,
+    BlockNumber,
+> {
     // Storage cid
     info: IpfsReference,
     // Whoever posts the bounty
     depositer: AccountId,
+    // Reviews submissions instead of the depositer if set
+    gov: Option<Governance>,
+    // Asset in which the bounty is funded and paid out
+    asset: AssetId,
     // Total amount
     total: Currency,
+    // Block after which remaining funds are refunded to contributors
+    expiry: Option<BlockNumber>,
 }
 
 impl<
         IpfsReference: Clone,
         AccountId: Clone,
+        Governance: Clone,
+        AssetId: Copy,
         Currency: Copy
             + PartialOrd
             + sp_std::ops::Sub<Output = Currency>
             + sp_std::ops::Add<Output = Currency>,
-    > BountyInformation<IpfsReference, AccountId, Currency>
+        BlockNumber: Copy,
+    >
+    BountyInformation<
+        IpfsReference,
+        AccountId,
+        Governance,
+        AssetId,
+        Currency,
+        BlockNumber,
+    >
 {
     pub fn info(&self) -> IpfsReference {
         self.info.clone()
@@ -104,9 +128,18 @@ impl<
     pub fn depositer(&self) -> AccountId {
         self.depositer.clone()
     }
+    pub fn gov(&self) -> Option<Governance> {
+        self.gov.clone()
+    }
+    pub fn asset(&self) -> AssetId {
+        self.asset
+    }
     pub fn total(&self) -> Currency {
         self.total
     }
+    pub fn expiry(&self) -> Option<BlockNumber> {
+        self.expiry
+    }
     pub fn add_total(&self, c: Currency) -> Self {
         BountyInformation {
             total: self.total + c,
@@ -121,26 +154,98 @@ impl<
     }
 }
 
+/// Optional settings of a posted bounty, the default bounty is funded in the
+/// native asset, never expires and is reviewed by its depositer
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+pub struct BountyOptions<
+    AssetId,
+    BlockNumber,
+    Governance,
+    Arbitration,
+    Currency,
+    OrgId,
+> {
+    // Asset in which the bounty is funded and paid out
+    pub asset: Option<AssetId>,
+    // Block after which remaining funds are refunded to contributors
+    pub expiry: Option<BlockNumber>,
+    // Reviews submissions instead of the depositer
+    pub governance: Option<Governance>,
+    // Court in which submitters may dispute their submissions
+    pub arbitration: Option<Arbitration>,
+    // Ordered milestone amounts, paid out one by one
+    pub milestones: Option<Vec<Currency>>,
+    // Org whose members are the only ones eligible to work on the bounty
+    pub eligible: Option<OrgId>,
+    // Minimum reputation score required to submit
+    pub min_reputation: Option<u32>,
+    // Blocks after which unreviewed submissions are approved
+    pub review_window: Option<BlockNumber>,
+    // Tags under which the bounty is listed
+    pub tags: Vec<Vec<u8>>,
+}
+
+impl<AssetId, BlockNumber, Governance, Arbitration, Currency, OrgId> Default
+    for BountyOptions<
+        AssetId,
+        BlockNumber,
+        Governance,
+        Arbitration,
+        Currency,
+        OrgId,
+    >
+{
+    fn default() -> Self {
+        BountyOptions {
+            asset: None,
+            expiry: None,
+            governance: None,
+            arbitration: None,
+            milestones: None,
+            eligible: None,
+            min_reputation: None,
+            review_window: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 /// All variants hold identifiers which point to larger objects in runtime storage maps
-pub enum SubmissionState {
+pub enum SubmissionState<VoteId> {
     SubmittedAwaitingResponse,
+    // wraps a vote_id for the bounty's governing org
+    UnderReviewByAcceptanceCommittee(VoteId),
     ApprovedAndExecuted,
+    Rejected,
+    Withdrawn,
 }
 
-impl Default for SubmissionState {
-    fn default() -> SubmissionState {
+impl<VoteId> Default for SubmissionState<VoteId> {
+    fn default() -> SubmissionState<VoteId> {
         SubmissionState::SubmittedAwaitingResponse
     }
 }
 
-impl SubmissionState {
+impl<VoteId: Copy> SubmissionState<VoteId> {
     pub fn awaiting_review(&self) -> bool {
         matches!(self, SubmissionState::SubmittedAwaitingResponse)
     }
+    pub fn under_review(&self) -> Option<VoteId> {
+        match self {
+            SubmissionState::UnderReviewByAcceptanceCommittee(v) => Some(*v),
+            _ => None,
+        }
+    }
     pub fn approved(&self) -> bool {
         matches!(self, SubmissionState::ApprovedAndExecuted)
     }
+    pub fn rejected(&self) -> bool {
+        matches!(self, SubmissionState::Rejected)
+    }
+    pub fn withdrawn(&self) -> bool {
+        matches!(self, SubmissionState::Withdrawn)
+    }
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
@@ -188,6 +293,8 @@ pub struct BountySubmission<BountyId, IpfsReference, AccountId, Currency, State>
     submitter: AccountId,
     /// Total amount
     amount: Currency,
+    /// Deposit reserved from the submitter
+    deposit: Currency,
     /// State of the application
     state: State,
 }
@@ -205,6 +312,7 @@ impl<
         submission_ref: IpfsReference,
         submitter: AccountId,
         amount: Currency,
+        deposit: Currency,
     ) -> BountySubmission<BountyId, IpfsReference, AccountId, Currency, State>
     {
         BountySubmission {
@@ -212,6 +320,7 @@ impl<
             submission_ref,
             submitter,
             amount,
+            deposit,
             state: State::default(),
         }
     }
@@ -230,6 +339,9 @@ impl<
     pub fn amount(&self) -> Currency {
         self.amount
     }
+    pub fn deposit(&self) -> Currency {
+        self.deposit
+    }
     pub fn pay_out_amount(&self, c: Currency) -> Self {
         let new_amount = self.amount() - c;
         BountySubmission {
@@ -240,4 +352,10 @@ impl<
     pub fn state(&self) -> State {
         self.state
     }
+    pub fn set_state(&self, s: State) -> Self {
+        BountySubmission {
+            state: s,
+            ..self.clone()
+        }
+    }
 }
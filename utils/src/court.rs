@@ -61,6 +61,8 @@ pub enum DisputeState<VoteId> {
     DisputeRaisedAndAccepted(VoteId),
     /// Outcome and time last checked and outcome updated
     DisputeRaisedAndRejected(VoteId),
+    /// Dispute not raised before its expiry, the locked funds are released
+    DisputeExpired,
 }
 
 impl<VoteId> Default for DisputeState<VoteId> {
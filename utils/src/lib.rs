@@ -16,6 +16,7 @@ pub mod grant;
 pub mod kickback;
 pub mod meta;
 pub mod organization;
+pub mod reputation;
 pub mod share;
 pub mod traits;
 pub mod vote;
@@ -48,6 +48,9 @@ impl<
         Hash: Clone,
     > Organization<AccountId, Id, Hash>
 {
+    pub fn sudo(&self) -> Option<AccountId> {
+        self.sudo.clone()
+    }
     pub fn parent(&self) -> Option<Id> {
         self.parent_id
     }
@@ -82,6 +85,13 @@ impl<
             constitution: self.constitution.clone(),
         }
     }
+    pub fn put_constitution(&self, new_constitution: Hash) -> Self {
+        Organization {
+            sudo: self.sudo.clone(),
+            parent_id: self.parent_id,
+            constitution: new_constitution,
+        }
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
@@ -0,0 +1,94 @@
+use codec::{
+    Decode,
+    Encode,
+};
+use sp_runtime::{
+    traits::Saturating,
+    Permill,
+    RuntimeDebug,
+};
+
+/// Counters of bounty outcomes for one account and the score derived from them,
+/// amounts are only recorded for bounties paid out in the native asset
+#[derive(PartialEq, Eq, Default, Clone, Encode, Decode, RuntimeDebug)]
+pub struct ReputationRecord<Balance> {
+    /// Submissions paid in full
+    approved: u32,
+    /// Amount paid for the account's submissions
+    earned: Balance,
+    /// Submissions rejected by a reviewer, a vote or the court
+    rejected: u32,
+    /// Bounties posted by the account
+    posted: u32,
+    /// Bounties posted by the account that paid out at least once
+    paid: u32,
+    /// Amount paid out of the bounties posted by the account
+    paid_out: Balance,
+    score: u32,
+}
+
+impl<Balance: Copy + Saturating> ReputationRecord<Balance> {
+    pub fn approved(&self) -> u32 {
+        self.approved
+    }
+    pub fn earned(&self) -> Balance {
+        self.earned
+    }
+    pub fn rejected(&self) -> u32 {
+        self.rejected
+    }
+    pub fn posted(&self) -> u32 {
+        self.posted
+    }
+    pub fn paid(&self) -> u32 {
+        self.paid
+    }
+    pub fn paid_out(&self) -> Balance {
+        self.paid_out
+    }
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+    /// Share of the posted bounties that paid out at least once
+    pub fn paid_ratio(&self) -> Permill {
+        Permill::from_rational_approximation(self.paid, self.posted.max(1))
+    }
+    pub fn post_bounty(&self) -> Self {
+        Self {
+            posted: self.posted.saturating_add(1),
+            ..self.clone()
+        }
+    }
+    /// `first_payout` counts the bounty as paid
+    pub fn pay_out(&self, amount: Balance, first_payout: bool) -> Self {
+        Self {
+            paid: self.paid.saturating_add(first_payout as u32),
+            paid_out: self.paid_out.saturating_add(amount),
+            ..self.clone()
+        }
+    }
+    pub fn earn(&self, amount: Balance) -> Self {
+        Self {
+            earned: self.earned.saturating_add(amount),
+            ..self.clone()
+        }
+    }
+    pub fn approve(&self) -> Self {
+        Self {
+            approved: self.approved.saturating_add(1),
+            ..self.clone()
+        }
+    }
+    pub fn reject(&self) -> Self {
+        Self {
+            rejected: self.rejected.saturating_add(1),
+            ..self.clone()
+        }
+    }
+    pub fn set_score(&self, score: u32) -> Self {
+        Self {
+            score,
+            ..self.clone()
+        }
+    }
+}
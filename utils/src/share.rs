@@ -9,7 +9,11 @@ use codec::{
 };
 use frame_support::Parameter;
 use sp_runtime::{
-    traits::Zero,
+    traits::{
+        AtLeast32BitUnsigned,
+        Convert,
+        Zero,
+    },
     RuntimeDebug,
 };
 use sp_std::prelude::*;
@@ -127,6 +131,77 @@ impl<Shares: Copy + sp_std::ops::AddAssign + Zero> AccessProfile<Shares>
     }
 }
 
+#[derive(new, PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// Shares issued to a member that vest linearly after a cliff
+pub struct VestingSchedule<Shares, BlockNumber> {
+    /// Shares unvested at `start`, updated on release
+    unvested: Shares,
+    /// Shares vesting every block after `start`
+    per_block: Shares,
+    /// Block from which shares vest
+    start: BlockNumber,
+    /// Block before which no shares vest, those accrued since `start` vest at once
+    cliff: BlockNumber,
+}
+
+impl<
+        Shares: AtLeast32BitUnsigned + Copy,
+        BlockNumber: AtLeast32BitUnsigned + Copy,
+    > VestingSchedule<Shares, BlockNumber>
+{
+    pub fn unvested(&self) -> Shares {
+        self.unvested
+    }
+    pub fn per_block(&self) -> Shares {
+        self.per_block
+    }
+    pub fn cliff(&self) -> BlockNumber {
+        self.cliff
+    }
+    /// Shares that remain unvested at block `n`
+    pub fn unvested_at<BlockNumberToShares: Convert<BlockNumber, Shares>>(
+        &self,
+        n: BlockNumber,
+    ) -> Shares {
+        if n < self.cliff {
+            return self.unvested
+        }
+        let vested_blocks =
+            BlockNumberToShares::convert(n.saturating_sub(self.start));
+        self.unvested
+            .saturating_sub(vested_blocks.saturating_mul(self.per_block))
+    }
+    /// Moves the shares vested by block `n` out of the schedule
+    pub fn release<BlockNumberToShares: Convert<BlockNumber, Shares>>(
+        self,
+        n: BlockNumber,
+    ) -> Self {
+        if n < self.cliff {
+            return self
+        }
+        Self {
+            unvested: self.unvested_at::<BlockNumberToShares>(n),
+            start: n,
+            ..self
+        }
+    }
+    /// Takes `amount` burned shares from those unvested at block `n` first
+    pub fn burn<BlockNumberToShares: Convert<BlockNumber, Shares>>(
+        self,
+        amount: Shares,
+        n: BlockNumber,
+    ) -> Self {
+        let released = self.release::<BlockNumberToShares>(n);
+        Self {
+            unvested: released.unvested.saturating_sub(amount),
+            ..released
+        }
+    }
+    pub fn is_vested(&self) -> bool {
+        self.unvested.is_zero()
+    }
+}
+
 #[derive(PartialEq, Eq, Default, Clone, Encode, Decode, RuntimeDebug)]
 /// The account ownership for the share genesis
 pub struct SimpleShareGenesis<AccountId, Shares> {
@@ -1,8 +1,16 @@
+use codec::FullCodec;
 use sp_runtime::{
+    traits::{
+        AtLeast32BitUnsigned,
+        MaybeSerializeDeserialize,
+    },
     DispatchError,
     DispatchResult,
 };
-use sp_std::prelude::*;
+use sp_std::{
+    fmt::Debug,
+    prelude::*,
+};
 
 pub type Result<T> = sp_std::result::Result<T, DispatchError>;
 
@@ -152,6 +160,12 @@ pub trait GetVoteOutcome<VoteId> {
     fn get_vote_outcome(vote_id: VoteId) -> Result<Self::Outcome>;
 }
 
+/// Checks whether the vote associated with `vote_id` stopped accepting votes
+pub trait CheckVoteExpiry<VoteId> {
+    /// True once the vote expired or if it does not exist
+    fn vote_expired(vote_id: VoteId) -> bool;
+}
+
 /// Open a new vote for the organization, share_id and a custom threshold requirement
 pub trait OpenVote<OrgId, Signal, Percent, BlockNumber, Hash> {
     type VoteIdentifier;
@@ -252,6 +266,25 @@ pub trait RegisterDisputeType<AccountId, Currency, VoteMetadata, BlockNumber> {
     ) -> Result<Self::DisputeIdentifier>;
 }
 
+/// Disputes whose locker pays out the accepted outcome itself, the court
+/// only releases the locked funds
+pub trait ResolveDispute<DisputeId> {
+    type Outcome;
+
+    /// Stops the court from transferring the locked funds to the raiser
+    fn resolve_by_locker(dispute_id: DisputeId);
+    /// Releases the locked funds once the dispute vote passes, fails or
+    /// expires, or the dispute expires before it is raised, and returns
+    /// the state of the dispute
+    fn resolve_dispute(dispute_id: DisputeId) -> Result<Self::Outcome>;
+}
+
+pub trait GetDisputeOutcome<DisputeId> {
+    type Outcome;
+
+    fn get_dispute_outcome(dispute_id: DisputeId) -> Result<Self::Outcome>;
+}
+
 // ~~~~~~~~ Bank Module ~~~~~~~~
 
 pub trait BankPermissions<BankId, OrgId, AccountId> {
@@ -295,3 +328,72 @@ pub trait SpendGovernance<BankId, Currency, AccountId> {
     fn poll_spend_proposal(spend_id: Self::SpendId)
         -> Result<Self::SpendState>;
 }
+
+// ====== Multi Asset Currency ======
+
+/// Balances of many assets, modeled after `orml_traits::MultiCurrency`
+pub trait MultiCurrency<AccountId> {
+    type CurrencyId: FullCodec
+        + Eq
+        + PartialEq
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug;
+    type Balance: AtLeast32BitUnsigned
+        + FullCodec
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + Default;
+    fn minimum_balance(currency_id: Self::CurrencyId) -> Self::Balance;
+    fn total_issuance(currency_id: Self::CurrencyId) -> Self::Balance;
+    fn free_balance(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+    ) -> Self::Balance;
+    fn ensure_can_withdraw(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+    fn transfer(
+        currency_id: Self::CurrencyId,
+        from: &AccountId,
+        to: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+    // mints `amount` of the asset into the account
+    fn deposit(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+    // burns `amount` of the asset from the account
+    fn withdraw(
+        currency_id: Self::CurrencyId,
+        who: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+}
+
+// ====== Reputation ======
+
+/// Outcomes of bounties credited to the reputation of hunters and depositers
+pub trait RecordBountyOutcome<AccountId, Balance> {
+    fn bounty_posted(depositer: &AccountId);
+    /// `amount` is the payment in the native asset, zero for other assets,
+    /// `first_payout` if no submission was paid out of the bounty before
+    fn payment_executed(
+        depositer: &AccountId,
+        submitter: &AccountId,
+        amount: Balance,
+        first_payout: bool,
+    );
+    /// The submission was paid in full
+    fn submission_approved(submitter: &AccountId);
+    fn submission_rejected(submitter: &AccountId);
+}
+
+pub trait GetReputation<AccountId> {
+    fn score(who: &AccountId) -> u32;
+}